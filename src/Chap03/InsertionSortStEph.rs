@@ -6,32 +6,125 @@ use vstd::prelude::*;
 verus! {
 
 pub mod InsertionSortStEph {
-    use verus_builtin::SpecOrd;
+    use core::cmp::Ordering;
+    use vstd::prelude::*;
+    #[cfg(verus_keep_ghost)]
+    use vstd::std_specs::cmp::OrdSpec;
+    use crate::vstdplus::clone_plus::clone_plus::ClonePlus;
+    use crate::vstdplus::total_order::total_order::{obeys_total_order, ord_le, axiom_obeys_total_order};
+    #[cfg(verus_keep_ghost)]
+    use crate::vstdplus::feq::feq::*;
+    use vstd::seq_lib::lemma_multiset_commutative;
     pub type T<S> = [S];
 
+    broadcast use {group_feq_axioms, vstd::seq_lib::group_to_multiset_ensures};
+
+    pub open spec fn sorted_by_ord<T: Ord>(s: Seq<T>) -> bool {
+        forall|a: int, b: int| #![trigger s[a], s[b]] 0 <= a < b < s.len() ==> ord_le(s[a], s[b])
+    }
+
+    /// Swapping two adjacent elements leaves the multiset unchanged.
+    pub proof fn lemma_swap_adjacent_to_multiset<T>(s: Seq<T>, k: int)
+        requires 0 <= k < s.len() - 1,
+        ensures s.update(k, s[k + 1]).update(k + 1, s[k]).to_multiset() == s.to_multiset(),
+    {
+        let t = s.update(k, s[k + 1]).update(k + 1, s[k]);
+        let pre = s.subrange(0, k);
+        let post = s.subrange(k + 2, s.len() as int);
+        let mid_s = Seq::<T>::empty().push(s[k]).push(s[k + 1]);
+        let mid_t = Seq::<T>::empty().push(s[k + 1]).push(s[k]);
+        assert(s =~= pre + mid_s + post);
+        assert(t =~= pre + mid_t + post);
+        lemma_multiset_commutative(pre, mid_s);
+        lemma_multiset_commutative(pre + mid_s, post);
+        lemma_multiset_commutative(pre, mid_t);
+        lemma_multiset_commutative(pre + mid_t, post);
+        assert(mid_s.to_multiset() =~= mid_t.to_multiset());
+    }
+
     pub trait InsertionSortStTrait<T: Ord + Clone> {
         /// APAS: Work O(n²), Span O(n log n)
-        fn insSort(slice: &mut [T]);
+        fn insSort(slice: &mut [T])
+            requires
+                obeys_total_order::<T>(),
+                obeys_feq_clone::<T>(),
+            ensures
+                slice@.len() == old(slice)@.len(),
+                slice@.to_multiset() == old(slice)@.to_multiset(),
+                sorted_by_ord(slice@);
     }
 
     impl<T: Ord + Clone> InsertionSortStTrait<T> for T {
         fn insSort(slice: &mut [T]) {
-            for i in 1..slice.len() {
-                let key = slice[i].clone();
+            proof { axiom_obeys_total_order::<T>(); }
+            let n = slice.len();
+            if n <= 1 {
+                return;
+            }
+            let mut i: usize = 1;
+            while i < n
+                invariant
+                    obeys_feq_clone::<T>(),
+                    n == slice@.len(),
+                    n == old(slice)@.len(),
+                    slice@.to_multiset() == old(slice)@.to_multiset(),
+                    1 <= i <= n,
+                    forall|a: int, b: int| #![trigger slice@[a], slice@[b]]
+                        0 <= a < b < i ==> ord_le(slice@[a], slice@[b]),
+                decreases n - i,
+            {
+                let key = slice[i].clone_plus();
+                proof {
+                    assert(key == slice@[i as int]);
+                    assert(slice@.update(i as int, key) =~= slice@);
+                }
                 let mut j = i;
-                while j > 0 && slice[j - 1] > key
+                #[verifier::loop_isolation(false)]
+                while j > 0
                     invariant
-                        j <= i,
+                        j <= i < n,
+                        n == slice@.len(),
+                        // Putting key back in the hole gives a permutation of the input.
+                        slice@.update(j as int, key).to_multiset() == old(slice)@.to_multiset(),
+                        // Sorted over 0..=i apart from the hole at j.
+                        forall|a: int, b: int| #![trigger slice@[a], slice@[b]]
+                            0 <= a < b <= i && a != j && b != j ==> ord_le(slice@[a], slice@[b]),
+                        // Everything shifted past the hole is above key.
+                        forall|b: int| #![trigger slice@[b]]
+                            j < b <= i ==> key.cmp_spec(&slice@[b]) == Ordering::Less,
                     decreases j,
                 {
-                    slice[j] = slice[j - 1].clone();
-                    j -= 1;
+                    match slice[j - 1].cmp(&key) {
+                        Ordering::Greater => {
+                            let ghost before = slice@.update(j as int, key);
+                            let moved = slice[j - 1].clone_plus();
+                            slice[j] = moved;
+                            j -= 1;
+                            proof {
+                                lemma_swap_adjacent_to_multiset(before, j as int);
+                                assert(slice@.update(j as int, key) =~=
+                                    before.update(j as int, before[j + 1]).update(j + 1, before[j as int]));
+                            }
+                        },
+                        _ => break,
+                    }
+                }
+                proof {
+                    assert(j == 0 || ord_le(slice@[j - 1], key));
                 }
                 slice[j] = key;
+                proof {
+                    assert forall|a: int, b: int| #![trigger slice@[a], slice@[b]]
+                        0 <= a < b <= i implies ord_le(slice@[a], slice@[b]) by {
+                        if b == j as int && a < j as int - 1 {
+                            assert(ord_le(slice@[a], slice@[j - 1]));
+                        }
+                    }
+                }
+                i += 1;
             }
         }
     }
 }
 
 } // verus!
-
@@ -1,5 +1,5 @@
 //! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
-//! Chapter 5.5 ephemeral Mapping (Function) built on `RelationStEph<A,B>`.
+//! Chapter 5.5 ephemeral Mapping (Function) built on `HashMapWithViewPlus<A, Pair<A,B>>`.
 
 pub mod MappingStEph {
 
@@ -24,6 +24,7 @@ verus! {
     #[cfg(verus_keep_ghost)]
     use vstd::std_specs::cmp::PartialEqSpec;
     use crate::vstdplus::clone_plus::clone_plus::ClonePlus;
+    use crate::vstdplus::hash_map_with_view_plus::hash_map_with_view_plus::*;
     use crate::Chap05::RelationStEph::RelationStEph::*;
     use crate::Chap05::SetStEph::SetStEph::*;
     use crate::Types::Types::*;
@@ -53,22 +54,309 @@ verus! {
         forall |q: (X, Y)| #![auto] s.contains(q) && q.0 == p.0 ==> q.1 == p.1
     }
 
+    /// The map denoted by a set of pairs; the intended reading when the set is functional.
+    pub open spec fn map_of_set<X, Y>(s: Set<(X, Y)>) -> Map<X, Y> {
+        Map::new(
+            |x: X| exists |y: Y| s.contains((x, y)),
+            |x: X| choose |y: Y| s.contains((x, y))
+        )
+    }
+
+    /// Each key of m is stored with the pair it is the first component of.
+    pub open spec fn keyed_by_first<X: View, Y: View>(m: Map<X::V, Pair<X, Y>>) -> bool {
+        forall |x: X::V| #[trigger] m.contains_key(x) ==> m[x]@.0 == x
+    }
+
+    pub open spec fn pairs_view<X: View, Y: View>(m: Map<X::V, Pair<X, Y>>) -> Map<X::V, Y::V> {
+        m.map_values(|p: Pair<X, Y>| p@.1)
+    }
+
+    pub proof fn lemma_map_of_set_insert<X, Y>(s: Set<(X, Y)>, x: X, y: Y)
+        requires is_functional_set(s.insert((x, y))),
+        ensures map_of_set(s.insert((x, y))) == map_of_set(s).insert(x, y),
+    {
+        let s2 = s.insert((x, y));
+        assert(s2.contains((x, y)));
+        assert forall |k: X| #[trigger] map_of_set(s2).contains_key(k) implies
+            map_of_set(s).insert(x, y).contains_key(k) && map_of_set(s2)[k] == map_of_set(s).insert(x, y)[k] by {
+            let y2 = map_of_set(s2)[k];
+            assert(s2.contains((k, y2)));
+            if k != x {
+                assert(s.contains((k, y2)));
+                let y1 = map_of_set(s)[k];
+                assert(s.contains((k, y1)));
+                assert(s2.contains((k, y1)));
+            }
+        }
+        assert forall |k: X| map_of_set(s).insert(x, y).contains_key(k) implies #[trigger] map_of_set(s2).contains_key(k) by {
+            if k != x {
+                let w = choose |w: Y| s.contains((k, w));
+                assert(s2.contains((k, w)));
+            }
+        }
+        assert(map_of_set(s2) =~= map_of_set(s).insert(x, y));
+    }
+
+    pub open spec fn map_range<X, Y>(m: Map<X, Y>) -> Set<Y> {
+        Set::new(|y: Y| exists |x: X| #[trigger] m.contains_key(x) && m[x] == y)
+    }
+
+    pub open spec fn is_injective_view<X, Y>(m: Map<X, Y>) -> bool {
+        forall |x1: X, x2: X| #![trigger m[x1], m[x2]]
+            m.contains_key(x1) && m.contains_key(x2) && m[x1] == m[x2] ==> x1 == x2
+    }
+
+    /// Every element of ys is the image of some key.
+    pub open spec fn is_surjective_onto_view<X, Y>(m: Map<X, Y>, ys: Set<Y>) -> bool {
+        ys.subset_of(map_range(m))
+    }
+
+    /// m is a one to one correspondence between its domain and ys.
+    pub open spec fn is_bijection_onto_view<X, Y>(m: Map<X, Y>, ys: Set<Y>) -> bool {
+        is_injective_view(m) && map_range(m) == ys
+    }
+
+    pub open spec fn inverse_view<X, Y>(m: Map<X, Y>) -> Map<Y, X> {
+        Map::new(
+            |y: Y| map_range(m).contains(y),
+            |y: Y| choose |x: X| #[trigger] m.contains_key(x) && m[x] == y
+        )
+    }
+
+    /// g ∘ f, defined where the range of f meets the domain of g.
+    pub open spec fn compose_view<X, Y, Z>(f: Map<X, Y>, g: Map<Y, Z>) -> Map<X, Z> {
+        Map::new(|x: X| f.contains_key(x) && g.contains_key(f[x]), |x: X| g[f[x]])
+    }
+
+    pub open spec fn tabulate_view<X, Y>(domain: Set<X>, f: spec_fn(X) -> Y) -> Map<X, Y> {
+        Map::new(|x: X| domain.contains(x), |x: X| f(x))
+    }
+
+    /// Keys of either map; shared keys resolve to f(m1[x], m2[x]).
+    pub open spec fn union_with_view<X, Y>(m1: Map<X, Y>, m2: Map<X, Y>, f: spec_fn(Y, Y) -> Y) -> Map<X, Y> {
+        Map::new(
+            |x: X| m1.contains_key(x) || m2.contains_key(x),
+            |x: X| if m1.contains_key(x) && m2.contains_key(x) { f(m1[x], m2[x]) }
+                   else if m1.contains_key(x) { m1[x] }
+                   else { m2[x] })
+    }
+
+    /// Keys of both maps, resolved to f(m1[x], m2[x]).
+    pub open spec fn intersect_with_view<X, Y>(m1: Map<X, Y>, m2: Map<X, Y>, f: spec_fn(Y, Y) -> Y) -> Map<X, Y> {
+        Map::new(|x: X| m1.contains_key(x) && m2.contains_key(x), |x: X| f(m1[x], m2[x]))
+    }
+
+    /// Domain subtraction: m1 without the keys of m2.
+    pub open spec fn difference_view<X, Y>(m1: Map<X, Y>, m2: Map<X, Y>) -> Map<X, Y> {
+        Map::new(|x: X| m1.contains_key(x) && !m2.contains_key(x), |x: X| m1[x])
+    }
+
+    pub open spec fn filter_view<X, Y>(m: Map<X, Y>, pred: spec_fn(X, Y) -> bool) -> Map<X, Y> {
+        Map::new(|x: X| m.contains_key(x) && pred(x, m[x]), |x: X| m[x])
+    }
+
+    /// entries lists the entries of m: each is m's entry at its key, and every key of m has one.
+    pub open spec fn spec_enumerates<K: View, V>(entries: Seq<(K, V)>, m: Map<K::V, V>) -> bool {
+        &&& forall |i: int| #![trigger entries[i]] 0 <= i < entries.len() ==>
+                m.contains_key(entries[i].0@) && m[entries[i].0@] == entries[i].1
+        &&& forall |k: K::V| #[trigger] m.contains_key(k) ==>
+                exists |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i].0@ == k
+    }
+
+    /// The keys of the first n entries.
+    pub open spec fn spec_seen_keys<K: View, V>(entries: Seq<(K, V)>, n: int) -> Set<K::V> {
+        Set::new(|x: K::V| exists |i: int| #![trigger entries[i]] 0 <= i < n && entries[i].0@ == x)
+    }
+
+    pub proof fn lemma_seen_keys_next<K: View, V>(entries: Seq<(K, V)>, n: int)
+        requires 0 <= n < entries.len()
+        ensures spec_seen_keys(entries, n + 1) == spec_seen_keys(entries, n).insert(entries[n].0@)
+    {
+        assert(spec_seen_keys(entries, n + 1) =~= spec_seen_keys(entries, n).insert(entries[n].0@));
+    }
+
+    /// Once every entry is seen, the seen keys are the keys of m.
+    pub proof fn lemma_seen_keys_all<K: View, V>(entries: Seq<(K, V)>, m: Map<K::V, V>)
+        requires spec_enumerates(entries, m)
+        ensures spec_seen_keys(entries, entries.len() as int) == m.dom()
+    {
+        assert forall |x: K::V| #[trigger] m.contains_key(x) implies spec_seen_keys(entries, entries.len() as int).contains(x) by {
+            let i = choose |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i].0@ == x;
+        }
+        assert(spec_seen_keys(entries, entries.len() as int) =~= m.dom());
+    }
+
+    /// Left fold of a nonempty sequence, seeded with its first element.
+    pub open spec fn reduce_seq<V>(s: Seq<V>, f: spec_fn(V, V) -> V) -> V {
+        s.drop_first().fold_left(s[0], f)
+    }
+
+    /// f(f(a, b), c) == f(a, f(b, c)).
+    pub open spec fn spec_associative<V>(f: spec_fn(V, V) -> V) -> bool {
+        forall |a: V, b: V, c: V| #[trigger] f(f(a, b), c) == f(a, f(b, c))
+    }
+
+    /// f(a, b) == f(b, a).
+    pub open spec fn spec_commutative<V>(f: spec_fn(V, V) -> V) -> bool {
+        forall |a: V, b: V| #[trigger] f(a, b) == f(b, a)
+    }
+
+    /// The fold of a nonempty finite set with f. It folds one enumeration of s, which is
+    /// well defined when f is associative and commutative (`lemma_reduce_seq_permutation`).
+    pub open spec fn reduce_set<V>(s: Set<V>, f: spec_fn(V, V) -> V) -> V {
+        reduce_seq(choose |ys: Seq<V>| ys.no_duplicates() && ys.to_set() == s, f)
+    }
+
+    pub proof fn lemma_reduce_seq_push<V>(s: Seq<V>, v: V, f: spec_fn(V, V) -> V)
+        requires s.len() > 0,
+        ensures reduce_seq(s.push(v), f) == f(reduce_seq(s, f), v),
+    {
+        assert(s.push(v)[0] == s[0]);
+        assert(s.push(v).drop_first() =~= s.drop_first().push(v));
+        assert(s.drop_first().push(v).drop_last() =~= s.drop_first());
+    }
+
+    pub proof fn lemma_reduce_seq_singleton<V>(v: V, f: spec_fn(V, V) -> V)
+        ensures reduce_seq(seq![v], f) == v,
+    {
+        assert(seq![v].drop_first() =~= Seq::<V>::empty());
+    }
+
+    /// Under an associative and commutative f, any element can be folded in last.
+    pub proof fn lemma_reduce_seq_remove<V>(s: Seq<V>, i: int, f: spec_fn(V, V) -> V)
+        requires spec_associative(f), spec_commutative(f), s.len() >= 2, 0 <= i < s.len(),
+        ensures reduce_seq(s, f) == f(reduce_seq(s.remove(i), f), s[i]),
+        decreases s.len(),
+    {
+        let p = s.drop_last();
+        let l = s.last();
+        assert(s =~= p.push(l));
+        lemma_reduce_seq_push(p, l, f);
+        if i == s.len() - 1 {
+            assert(s.remove(i) =~= p);
+        } else if p.len() == 1 {
+            assert(p =~= seq![s[0]]);
+            assert(s.remove(i) =~= seq![l]);
+            lemma_reduce_seq_singleton(s[0], f);
+            lemma_reduce_seq_singleton(l, f);
+            assert(f(s[0], l) == f(l, s[0]));
+        } else {
+            lemma_reduce_seq_remove(p, i, f);
+            let rest = reduce_seq(p.remove(i), f);
+            let a = s[i];
+            assert(p[i] == a);
+            assert(s.remove(i) =~= p.remove(i).push(l));
+            lemma_reduce_seq_push(p.remove(i), l, f);
+            assert(f(f(rest, a), l) == f(rest, f(a, l)));
+            assert(f(a, l) == f(l, a));
+            assert(f(rest, f(l, a)) == f(f(rest, l), a));
+        }
+    }
+
+    /// Under an associative and commutative f, every enumeration of a set folds to the same value.
+    pub proof fn lemma_reduce_seq_permutation<V>(s: Seq<V>, t: Seq<V>, f: spec_fn(V, V) -> V)
+        requires
+            spec_associative(f),
+            spec_commutative(f),
+            s.len() > 0,
+            s.no_duplicates(),
+            t.no_duplicates(),
+            s.to_set() == t.to_set(),
+        ensures reduce_seq(s, f) == reduce_seq(t, f),
+        decreases s.len(),
+    {
+        let n = s.len() as int;
+        let v = s.last();
+        assert(s[n - 1] == v);
+        assert(s.to_set().contains(v));
+        let j = choose |j: int| 0 <= j < t.len() && t[j] == v;
+        let s1 = s.drop_last();
+        let t1 = t.remove(j);
+        assert forall |e: V| s1.to_set().contains(e) <==> t1.to_set().contains(e) by {
+            if s1.contains(e) {
+                let k = choose |k: int| 0 <= k < s1.len() && s1[k] == e;
+                assert(s[k] == e && k != n - 1);
+                assert(t.to_set().contains(e));
+                let k2 = choose |k2: int| 0 <= k2 < t.len() && t[k2] == e;
+                assert(k2 != j);
+                if k2 < j { assert(t1[k2] == e); } else { assert(t1[k2 - 1] == e); }
+            }
+            if t1.contains(e) {
+                let k = choose |k: int| 0 <= k < t1.len() && t1[k] == e;
+                let k2 = if k < j { k } else { k + 1 };
+                assert(t[k2] == e && k2 != j);
+                assert(s.to_set().contains(e));
+                let k3 = choose |k3: int| 0 <= k3 < s.len() && s[k3] == e;
+                assert(k3 != n - 1);
+                assert(s1[k3] == e);
+            }
+        }
+        assert(s1.to_set() =~= t1.to_set());
+        assert(s =~= s1.push(v));
+        assert forall |a: int, b: int| 0 <= a < s1.len() && 0 <= b < s1.len() && a != b implies s1[a] != s1[b] by {
+            assert(s1[a] == s[a] && s1[b] == s[b]);
+        }
+        assert forall |a: int, b: int| 0 <= a < t1.len() && 0 <= b < t1.len() && a != b implies t1[a] != t1[b] by {
+            let a2 = if a < j { a } else { a + 1 };
+            let b2 = if b < j { b } else { b + 1 };
+            assert(t1[a] == t[a2] && t1[b] == t[b2]);
+        }
+        if n == 1 {
+            if t1.len() > 0 { assert(t1.to_set().contains(t1[0])); }
+            assert(t =~= seq![v]);
+        } else {
+            assert(s1.to_set().contains(s1[0]));
+            assert(t1.len() > 0);
+            lemma_reduce_seq_push(s1, v, f);
+            lemma_reduce_seq_remove(t, j, f);
+            lemma_reduce_seq_permutation(s1, t1, f);
+        }
+    }
+
+    /// reduce_set of a set is the fold of any of its enumerations.
+    pub proof fn lemma_reduce_set_is_reduce_seq<V>(ys: Seq<V>, f: spec_fn(V, V) -> V)
+        requires spec_associative(f), spec_commutative(f), ys.len() > 0, ys.no_duplicates(),
+        ensures reduce_set(ys.to_set(), f) == reduce_seq(ys, f),
+    {
+        assert(ys.no_duplicates() && ys.to_set() == ys.to_set());
+        let zs = choose |zs: Seq<V>| zs.no_duplicates() && zs.to_set() == ys.to_set();
+        assert(zs.no_duplicates() && zs.to_set() == ys.to_set());
+        assert(ys.to_set().contains(ys[0]));
+        if zs.len() == 0 { assert(zs.to_set() =~= Set::<V>::empty()); }
+        lemma_reduce_seq_permutation(zs, ys, f);
+    }
+
+    /// Witness that a set of pairs is not a function: key is related to both first and second.
+    pub struct NonFunctional<X, Y> {
+        pub key: X,
+        pub first: Y,
+        pub second: Y,
+    }
+
+    pub open spec fn is_non_functional_witness<X: View, Y: View>(s: Set<(X::V, Y::V)>, e: NonFunctional<X, Y>) -> bool {
+        &&& s.contains((e.key@, e.first@))
+        &&& s.contains((e.key@, e.second@))
+        &&& e.first@ != e.second@
+    }
+
     #[verifier::reject_recursive_types(A)]
     #[verifier::reject_recursive_types(B)]
     pub struct MappingStEph<A: StT + Hash, B: StT + Hash> {
-        pub mapping: RelationStEph<A, B>,
+        pub mapping: HashMapWithViewPlus<A, Pair<A, B>>,
     }
 
-    // Iterator wrapper to hide RelationStEphIter<X, Y>
+    // Iterator wrapper to hide HashMapWithViewPlusIter<X, Pair<X, Y>>
     #[verifier::reject_recursive_types(X)]
     #[verifier::reject_recursive_types(Y)]
     pub struct MappingStEphIter<'a, X: StT + Hash, Y: StT + Hash> {
-        pub inner: RelationStEphIter<'a, X, Y>,
+        pub inner: HashMapWithViewPlusIter<'a, X, Pair<X, Y>>,
     }
 
     impl<'a, X: StT + Hash, Y: StT + Hash> View for MappingStEphIter<'a, X, Y> {
         type V = (int, Seq<Pair<X, Y>>);
-        open spec fn view(&self) -> (int, Seq<Pair<X, Y>>) { self.inner@ }
+        open spec fn view(&self) -> (int, Seq<Pair<X, Y>>) {
+            (self.inner@.0, self.inner@.1.map(|i: int, e: (X, Pair<X, Y>)| e.1))
+        }
     }
 
     impl<'a, X: StT + Hash, Y: StT + Hash> MappingStEphIter<'a, X, Y> {
@@ -90,7 +378,10 @@ verus! {
                 }
             })
         {
-            self.inner.next()
+            match self.inner.next() {
+                Some((_k, p)) => Some(p),
+                None => None,
+            }
         }
     }
 
@@ -111,10 +402,12 @@ verus! {
             requires valid_key_type_Pair::<X, Y>()
             ensures functional == is_functional_set_at(s@, p@);
 
+        /// APAS: Work Θ(|s|), Span Θ(|s|)
         fn is_functional_SetStEph(s: &SetStEph<Pair<X, Y>>) -> (functional: bool)
             requires valid_key_type_Pair::<X, Y>()
             ensures functional == is_functional_set(s@);
 
+        /// APAS: Work Θ(|r|), Span Θ(|r|)
         fn is_functional_RelationStEph(r: &RelationStEph<X, Y>) -> (functional: bool)
             requires valid_key_type_Pair::<X, Y>()
             ensures functional == is_functional_relation(*r);
@@ -127,27 +420,193 @@ verus! {
 
         fn FromVec(v: Vec<Pair<X, Y>>) -> (mapping: Self)
             requires valid_key_type_Pair::<X, Y>(), is_functional_seq(v@)
-            ensures mapping.is_functional();
+            ensures
+                mapping@ == map_of_set(v@.map(|i: int, p: Pair<X, Y>| p@).to_set()),
+                mapping.is_functional();
 
+        /// APAS: Work Θ(|r|), Span Θ(|r|)
         fn FromRelation(r: &RelationStEph<X, Y>) -> (mapping: Self)
             requires valid_key_type_Pair::<X, Y>(), is_functional_relation(*r)
-            ensures mapping.is_functional();
+            ensures
+                mapping@ == map_of_set(r@),
+                mapping.is_functional();
+
+        /// FromVec for unchecked input: Err names a key with two different values.
+        /// APAS: Work Θ(|v|), Span Θ(|v|)
+        fn try_from_vec(v: Vec<Pair<X, Y>>) -> (mapping: std::result::Result<Self, NonFunctional<X, Y>>)
+            requires valid_key_type_Pair::<X, Y>()
+            ensures
+                match mapping {
+                    Ok(m) => is_functional_seq(v@)
+                        && m@ == map_of_set(v@.map(|i: int, p: Pair<X, Y>| p@).to_set())
+                        && m.is_functional(),
+                    Err(e) => is_non_functional_witness(v@.map(|i: int, p: Pair<X, Y>| p@).to_set(), e),
+                };
 
+        /// FromRelation for unchecked input: Err names a key with two different values.
+        /// APAS: Work Θ(|r|), Span Θ(|r|)
+        fn try_from_relation(r: &RelationStEph<X, Y>) -> (mapping: std::result::Result<Self, NonFunctional<X, Y>>)
+            requires valid_key_type_Pair::<X, Y>()
+            ensures
+                match mapping {
+                    Ok(m) => is_functional_relation(*r) && m@ == map_of_set(r@) && m.is_functional(),
+                    Err(e) => is_non_functional_witness(r@, e),
+                };
+
+        /// APAS: Work Θ(|m|), Span Θ(1)
+        fn FromHashMap(m: HashMapWithViewPlus<X, Y>) -> (mapping: Self)
+            requires valid_key_type_Pair::<X, Y>()
+            ensures
+                mapping@ == m@.map_values(|y: Y| y@),
+                mapping.is_functional();
+
+        /// APAS: Work Θ(1), Span Θ(1)
         fn size(&self) -> N
             requires self.is_functional();
 
+        /// APAS: Work Θ(|m|), Span Θ(|m|)
         fn domain(&self) -> (domain: SetStEph<X>)
             requires valid_key_type_Pair::<X, Y>(), self.is_functional()
             ensures domain@ == self@.dom();
 
+        /// APAS: Work Θ(|m|), Span Θ(|m|)
         fn range(&self) -> (range: SetStEph<Y>)
             requires valid_key_type_Pair::<X, Y>(), self.is_functional()
             ensures range@ =~= Set::<Y::V>::new(|y: Y::V| exists |x: X::V| #![auto] self@.dom().contains(x) && self@[x] == y);
 
+        /// APAS: Work Θ(1), Span Θ(1)
         fn mem(&self, p: &Pair<X, Y>) -> (contains: B)
             requires valid_key_type_Pair::<X, Y>(), self.is_functional()
             ensures contains == (self@.dom().contains(p@.0) && self@[p@.0] == p@.1);
 
+        /// APAS: Work Θ(|m|), Span Θ(|m|)
+        fn is_injective(&self) -> (injective: bool)
+            requires valid_key_type_Pair::<X, Y>(), self.is_functional()
+            ensures injective == is_injective_view(self@);
+
+        /// APAS: Work Θ(|m| + |ys|), Span Θ(|m| + |ys|)
+        fn is_surjective_onto(&self, ys: &SetStEph<Y>) -> (surjective: bool)
+            requires valid_key_type_Pair::<X, Y>(), self.is_functional()
+            ensures surjective == is_surjective_onto_view(self@, ys@);
+
+        /// APAS: Work Θ(|m| + |ys|), Span Θ(|m| + |ys|)
+        fn is_bijection_onto(&self, ys: &SetStEph<Y>) -> (bijection: bool)
+            requires valid_key_type_Pair::<X, Y>(), self.is_functional()
+            ensures bijection == is_bijection_onto_view(self@, ys@);
+
+        /// The inverse mapping, when self is injective.
+        /// APAS: Work Θ(|m|), Span Θ(|m|)
+        fn inverse(&self) -> (inverse: Option<MappingStEph<Y, X>>)
+            requires valid_key_type_Pair::<X, Y>(), self.is_functional()
+            ensures
+                match inverse {
+                    Some(inv) => {
+                        &&& is_injective_view(self@)
+                        &&& inv.is_functional()
+                        &&& inv@ == inverse_view(self@)
+                        &&& inv@.dom() == map_range(self@)
+                        &&& forall |x: X::V| #[trigger] self@.contains_key(x) ==> inv@[self@[x]] == x
+                    },
+                    None => !is_injective_view(self@),
+                };
+
+        /// g ∘ self, on the keys whose image is in the domain of g.
+        /// APAS: Work Θ(|m|), Span Θ(|m|)
+        fn compose<Z: StT + Hash>(&self, g: &MappingStEph<Y, Z>) -> (composed: MappingStEph<X, Z>)
+            requires
+                valid_key_type_Pair::<X, Y>(),
+                valid_key_type_Pair::<Y, Z>(),
+                self.is_functional(),
+                g.is_functional(),
+            ensures
+                composed.is_functional(),
+                composed@ == compose_view(self@, g@);
+
+        /// The mapping x -> f(x) over domain.
+        /// APAS: Work Θ(|domain|), Span Θ(|domain|)
+        fn tabulate<F: Fn(&X) -> Y>(domain: &SetStEph<X>, f: &F, spec_f: Ghost<spec_fn(X::V) -> Y::V>) -> (mapping: Self)
+            requires
+                valid_key_type_Pair::<X, Y>(),
+                forall |x: &X| #[trigger] f.requires((x,)),
+                forall |x: X, y: Y| #[trigger] f.ensures((&x,), y) ==> y@ == spec_f@(x@),
+            ensures
+                mapping.is_functional(),
+                mapping@ == tabulate_view(domain@, spec_f@);
+
+        /// Keys of either mapping, combining the values of shared keys.
+        /// APAS: Work Θ(|m| + |other|), Span Θ(|m| + |other|)
+        fn union_with<F: Fn(&Y, &Y) -> Y>(&self, other: &Self, combine: &F, spec_combine: Ghost<spec_fn(Y::V, Y::V) -> Y::V>)
+            -> (union: Self)
+            requires
+                valid_key_type_Pair::<X, Y>(),
+                self.is_functional(),
+                other.is_functional(),
+                forall |a: &Y, b: &Y| #[trigger] combine.requires((a, b)),
+                forall |a: Y, b: Y, r: Y| #[trigger] combine.ensures((&a, &b), r) ==> r@ == spec_combine@(a@, b@),
+            ensures
+                union.is_functional(),
+                union@ == union_with_view(self@, other@, spec_combine@);
+
+        /// Keys of both mappings, combining their values.
+        /// APAS: Work Θ(|m|), Span Θ(|m|)
+        fn intersect_with<F: Fn(&Y, &Y) -> Y>(&self, other: &Self, combine: &F, spec_combine: Ghost<spec_fn(Y::V, Y::V) -> Y::V>)
+            -> (intersection: Self)
+            requires
+                valid_key_type_Pair::<X, Y>(),
+                self.is_functional(),
+                other.is_functional(),
+                forall |a: &Y, b: &Y| #[trigger] combine.requires((a, b)),
+                forall |a: Y, b: Y, r: Y| #[trigger] combine.ensures((&a, &b), r) ==> r@ == spec_combine@(a@, b@),
+            ensures
+                intersection.is_functional(),
+                intersection@ == intersect_with_view(self@, other@, spec_combine@);
+
+        /// Domain subtraction: the entries whose key is not in the domain of other.
+        /// APAS: Work Θ(|m|), Span Θ(|m|)
+        fn difference(&self, other: &Self) -> (difference: Self)
+            requires
+                valid_key_type_Pair::<X, Y>(),
+                self.is_functional(),
+                other.is_functional(),
+            ensures
+                difference.is_functional(),
+                difference@ == difference_view(self@, other@);
+
+        /// The entries satisfying pred.
+        /// APAS: Work Θ(|m|), Span Θ(|m|)
+        fn filter<F: Fn(&X, &Y) -> B>(&self, pred: &F, spec_pred: Ghost<spec_fn(X::V, Y::V) -> bool>) -> (filtered: Self)
+            requires
+                valid_key_type_Pair::<X, Y>(),
+                self.is_functional(),
+                forall |x: &X, y: &Y| #[trigger] pred.requires((x, y)),
+                forall |x: X, y: Y, keep: B| #[trigger] pred.ensures((&x, &y), keep) ==> keep == spec_pred@(x@, y@),
+            ensures
+                filtered.is_functional(),
+                filtered@ == filter_view(self@, spec_pred@);
+
+        /// Apply f to every value; the domain is unchanged.
+        /// APAS: Work Θ(|m|), Span Θ(|m|)
+        fn map_values<Z: StT + Hash, F: Fn(&Y) -> Z>(&self, f: &F, spec_f: Ghost<spec_fn(Y::V) -> Z::V>) -> (mapped: MappingStEph<X, Z>)
+            requires
+                valid_key_type_Pair::<X, Y>(),
+                valid_key_type_Pair::<X, Z>(),
+                self.is_functional(),
+                forall |y: &Y| #[trigger] f.requires((y,)),
+                forall |y: Y, z: Z| #[trigger] f.ensures((&y,), z) ==> z@ == spec_f@(y@),
+            ensures
+                mapped.is_functional(),
+                mapped@ == self@.map_values(spec_f@);
+
+        /// Domain restriction to keys.
+        /// APAS: Work Θ(|m|), Span Θ(|m|)
+        fn restrict(&self, keys: &SetStEph<X>) -> (restricted: Self)
+            requires
+                valid_key_type_Pair::<X, Y>(),
+                self.is_functional(),
+            ensures
+                restricted.is_functional(),
+                restricted@ == self@.restrict(keys@);
+
         fn iter<'a>(&'a self) -> (it: MappingStEphIter<'a, X, Y>)
             requires valid_key_type_Pair::<X, Y>(), self.is_functional()
             ensures
@@ -160,25 +619,194 @@ verus! {
     impl<A: StT + Hash, B: StT + Hash> View for MappingStEph<A, B> {
         type V = Map<A::V, B::V>;
         
-        open spec fn view(&self) -> Self::V {
-            Map::new(
-                |x: A::V| exists |y: B::V| self.mapping@.contains((x, y)),
-                |x: A::V| choose |y: B::V| self.mapping@.contains((x, y))
-            )
-        }
+        open spec fn view(&self) -> Self::V { pairs_view(self.mapping@) }
     }
 
     impl<A: StT + Hash, B: StT + Hash> Clone for MappingStEph<A, B> {
         fn clone(&self) -> (clone: Self)
             ensures clone@ == self@, self.is_functional() ==> clone.is_functional()
-        { MappingStEph { mapping: self.mapping.clone() } }
+        {
+            let mapping = self.mapping.clone();
+            proof {
+                axiom_Pair_feq::<A, B>();
+                assert forall |k: A::V| #[trigger] mapping@.contains_key(k) implies mapping@[k] == self.mapping@[k] by {
+                    axiom_cloned_implies_eq(&self.mapping@[k], mapping@[k]);
+                }
+                assert(mapping@ =~= self.mapping@);
+            }
+            MappingStEph { mapping }
+        }
+    }
+
+    /// Index m by value, y -> Pair(y, x); None at the first value seen twice.
+    /// APAS: Work Θ(|m|), Span Θ(|m|)
+    fn invert<X: StT + Hash, Y: StT + Hash>(m: &MappingStEph<X, Y>) -> (index: Option<HashMapWithViewPlus<Y, Pair<Y, X>>>)
+        requires valid_key_type_Pair::<X, Y>(), m.is_functional()
+        ensures
+            match index {
+                Some(inv) => is_injective_view(m@) && keyed_by_first(inv@) && pairs_view(inv@) == inverse_view(m@),
+                None => !is_injective_view(m@),
+            },
+    {
+        let mut inv = HashMapWithViewPlus::<Y, Pair<Y, X>>::new();
+        let mut it = m.mapping.iter();
+        let ghost entries = it@.1;
+
+        #[verifier::loop_isolation(false)]
+        loop
+            invariant
+                valid_key_type_Pair::<X, Y>(),
+                m.is_functional(),
+                it@.0 <= entries.len(),
+                it@.1 == entries,
+                forall |i: int| #![trigger entries[i]] 0 <= i < entries.len() ==>
+                    m.mapping@.contains_key(entries[i].0@) && m.mapping@[entries[i].0@] == entries[i].1,
+                forall |k: X::V| #[trigger] m.mapping@.contains_key(k) ==>
+                    exists |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i].0@ == k,
+                forall |i: int, j: int| #![trigger entries[i], entries[j]]
+                    0 <= i < entries.len() && 0 <= j < entries.len() && entries[i].0@ == entries[j].0@ ==> i == j,
+                keyed_by_first(inv@),
+                // inv inverts the entries seen so far, which so far have distinct values.
+                forall |i: int| #![trigger entries[i]] 0 <= i < it@.0 ==>
+                    inv@.contains_key(m@[entries[i].0@]) && inv@[m@[entries[i].0@]]@.1 == entries[i].0@,
+                forall |y: Y::V| #[trigger] inv@.contains_key(y) ==>
+                    exists |i: int| #![trigger entries[i]] 0 <= i < it@.0 && m@[entries[i].0@] == y,
+                forall |i: int, j: int| #![trigger entries[i], entries[j]]
+                    0 <= i < it@.0 && 0 <= j < it@.0 && m@[entries[i].0@] == m@[entries[j].0@] ==> i == j,
+            decreases entries.len() - it@.0,
+        {
+            let ghost old_index = it@.0;
+            match it.next() {
+                Some((k, p)) => {
+                    proof { assert(p@.0 == k@ && m@[k@] == p@.1); }
+                    match inv.get(&p.1) {
+                        Some(_) => {
+                            proof {
+                                // An earlier key already maps to this value.
+                                let j = choose |j: int| #![trigger entries[j]] 0 <= j < old_index && m@[entries[j].0@] == p@.1;
+                                assert(entries[j].0@ != k@);
+                            }
+                            return None;
+                        },
+                        None => {
+                            let ghost old_inv = inv@;
+                            let _ = inv.insert(p.1.clone_plus(), Pair(p.1.clone_plus(), p.0.clone_plus()));
+                            proof {
+                                assert forall |j: int| #![trigger entries[j]] 0 <= j < old_index implies m@[entries[j].0@] != p@.1 by {
+                                    assert(old_inv.contains_key(m@[entries[j].0@]));
+                                }
+                            }
+                        },
+                    }
+                },
+                None => {
+                    proof {
+                        assert forall |x1: X::V, x2: X::V| #![trigger m@[x1], m@[x2]]
+                            m@.contains_key(x1) && m@.contains_key(x2) && m@[x1] == m@[x2] implies x1 == x2 by {
+                            let i = choose |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i].0@ == x1;
+                            let j = choose |j: int| #![trigger entries[j]] 0 <= j < entries.len() && entries[j].0@ == x2;
+                        }
+                        assert forall |y: Y::V| #[trigger] pairs_view(inv@).contains_key(y) implies
+                            inverse_view(m@).contains_key(y) && pairs_view(inv@)[y] == inverse_view(m@)[y] by {
+                            let i = choose |i: int| #![trigger entries[i]] 0 <= i < entries.len() && m@[entries[i].0@] == y;
+                            assert(m@.contains_key(entries[i].0@));
+                            let x = inverse_view(m@)[y];
+                            assert(m@.contains_key(x) && m@[x] == y);
+                        }
+                        assert forall |y: Y::V| #[trigger] inverse_view(m@).contains_key(y) implies pairs_view(inv@).contains_key(y) by {
+                            let x = choose |x: X::V| #[trigger] m@.contains_key(x) && m@[x] == y;
+                            let i = choose |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i].0@ == x;
+                        }
+                        assert(pairs_view(inv@) =~= inverse_view(m@));
+                    }
+                    return Some(inv);
+                }
+            }
+        }
+    }
+
+    /// One pass over s keyed by first component; Err at the first key seen with two values.
+    /// APAS: Work Θ(|s|), Span Θ(|s|)
+    fn index_pairs<X: StT + Hash, Y: StT + Hash>(s: &SetStEph<Pair<X, Y>>)
+        -> (index: std::result::Result<HashMapWithViewPlus<X, Pair<X, Y>>, NonFunctional<X, Y>>)
+        requires valid_key_type_Pair::<X, Y>()
+        ensures
+            match index {
+                Ok(m) => is_functional_set(s@) && keyed_by_first(m@) && pairs_view(m@) == map_of_set(s@),
+                Err(e) => is_non_functional_witness(s@, e) && !is_functional_set(s@),
+            },
+    {
+        let mut m = HashMapWithViewPlus::<X, Pair<X, Y>>::new();
+        let mut it = s.iter();
+        let ghost pairs_seq = it@.1;
+        proof { assert(pairs_view(m@) =~= map_of_set(Set::<(X::V, Y::V)>::empty())); }
+
+        #[verifier::loop_isolation(false)]
+        loop
+            invariant
+                valid_key_type_Pair::<X, Y>(),
+                it@.0 <= pairs_seq.len(),
+                it@.1 == pairs_seq,
+                pairs_seq.map(|i: int, p: Pair<X, Y>| p@).to_set() == s@,
+                is_functional_set(pairs_seq.take(it@.0).map(|i: int, p: Pair<X, Y>| p@).to_set()),
+                keyed_by_first(m@),
+                pairs_view(m@) == map_of_set(pairs_seq.take(it@.0).map(|i: int, p: Pair<X, Y>| p@).to_set()),
+            decreases pairs_seq.len() - it@.0,
+        {
+            let ghost old_index = it@.0;
+            let ghost prefix = pairs_seq.take(old_index).map(|i: int, p: Pair<X, Y>| p@).to_set();
+            match it.next() {
+                Some(pair) => {
+                    proof {
+                        lemma_take_one_more_extends_the_seq_set_with_view(pairs_seq, old_index);
+                        lemma_seq_index_in_map_to_set(pairs_seq, old_index);
+                    }
+                    let Pair(x, y) = pair;
+                    match m.get(x) {
+                        Some(q) => {
+                            if !feq(&q.1, y) {
+                                proof {
+                                    // q is the value of x in the prefix, which is also in s.
+                                    assert(pairs_view(m@)[x@] == q@.1);
+                                    assert(prefix.contains((x@, q@.1)));
+                                    lemma_map_to_set_contains_index(pairs_seq.take(old_index), (x@, q@.1));
+                                    let k = choose |k: int| #![auto] 0 <= k < old_index && (x@, q@.1) == pairs_seq.take(old_index)[k]@;
+                                    lemma_seq_index_in_map_to_set(pairs_seq, k);
+                                    assert(s@.contains((x@, q@.1)) && s@.contains((x@, y@)));
+                                }
+                                return Err(NonFunctional { key: x.clone_plus(), first: q.1.clone_plus(), second: y.clone_plus() });
+                            }
+                            proof {
+                                assert(pairs_view(m@)[x@] == y@);
+                                assert(prefix.contains((x@, y@)));
+                                assert(prefix.insert((x@, y@)) =~= prefix);
+                            }
+                        },
+                        None => {
+                            let _ = m.insert(x.clone_plus(), pair.clone_plus());
+                            proof {
+                                assert forall |y2: Y::V| !prefix.contains((x@, y2)) by {
+                                    if prefix.contains((x@, y2)) { assert(map_of_set(prefix).contains_key(x@)); }
+                                }
+                                lemma_map_of_set_insert(prefix, x@, y@);
+                                assert(pairs_view(m@) =~= map_of_set(prefix).insert(x@, y@));
+                            }
+                        },
+                    }
+                },
+                None => {
+                    proof { lemma_take_full_to_set_with_view(pairs_seq); }
+                    return Ok(m);
+                }
+            }
+        }
     }
 
     impl<X: StT + Hash, Y: StT + Hash> 
         MappingStEphTrait<X, Y> for MappingStEph<X, Y> {
 
         open spec fn is_functional(&self) -> bool {
-            is_functional_set(self.mapping@)
+            keyed_by_first(self.mapping@)
         }
 
         fn is_functional_vec_at(v: &Vec<Pair<X, Y>>, p: &Pair<X, Y>) -> (functional: bool) {
@@ -268,85 +896,796 @@ verus! {
         }
 
         fn is_functional_SetStEph(s: &SetStEph<Pair<X, Y>>) -> (functional: bool) {
-            let mut outer_iter = s.iter();
-            let ghost the_seq = outer_iter@.1;
+            match index_pairs(s) {
+                Ok(_) => true,
+                Err(_) => false,
+            }
+        }
+
+        fn is_functional_RelationStEph(r: &RelationStEph<X, Y>) -> (functional: bool) {
+            Self::is_functional_SetStEph(&r.pairs)
+        }
+
+        fn empty() -> MappingStEph<X, Y> {
+            let mapping = HashMapWithViewPlus::new();
+            proof { assert(pairs_view(mapping@) =~= Map::<X::V, Y::V>::empty()); }
+            MappingStEph { mapping }
+        }
+
+        fn FromVec(v: Vec<Pair<X, Y>>) -> MappingStEph<X, Y> {
+            match Self::try_from_vec(v) {
+                Ok(mapping) => mapping,
+                Err(_) => unreached(),
+            }
+        }
+
+        fn FromRelation(r: &RelationStEph<X, Y>) -> MappingStEph<X, Y> {
+            match Self::try_from_relation(r) {
+                Ok(mapping) => mapping,
+                Err(_) => unreached(),
+            }
+        }
+
+        fn try_from_vec(v: Vec<Pair<X, Y>>) -> std::result::Result<MappingStEph<X, Y>, NonFunctional<X, Y>> {
+            let pairs = SetStEph::FromVec(v);
+            match index_pairs(&pairs) {
+                Ok(mapping) => Ok(MappingStEph { mapping }),
+                Err(e) => Err(e),
+            }
+        }
+
+        fn try_from_relation(r: &RelationStEph<X, Y>) -> std::result::Result<MappingStEph<X, Y>, NonFunctional<X, Y>> {
+            match index_pairs(&r.pairs) {
+                Ok(mapping) => Ok(MappingStEph { mapping }),
+                Err(e) => Err(e),
+            }
+        }
+
+        fn FromHashMap(m: HashMapWithViewPlus<X, Y>) -> MappingStEph<X, Y> {
+            let mut mapping = HashMapWithViewPlus::<X, Pair<X, Y>>::new();
+            let mut it = m.iter();
+            let ghost entries = it@.1;
+
+            #[verifier::loop_isolation(false)]
             loop
                 invariant
                     valid_key_type_Pair::<X, Y>(),
-                    outer_iter@.1 == the_seq,
-                    the_seq.map(|i: int, pair: Pair<X,Y>| pair@).to_set() == s@,
-                    0 <= outer_iter@.0 <= the_seq.len(),
-                    forall |k: int| #![auto] 0 <= k < outer_iter@.0 ==> is_functional_set_at(s@, the_seq[k]@),
-                decreases the_seq.len() - outer_iter@.0,
+                    it@.0 <= entries.len(),
+                    it@.1 == entries,
+                    forall |i: int| #![trigger entries[i]] 0 <= i < entries.len() ==>
+                        m@.contains_key(entries[i].0@) && m@[entries[i].0@] == entries[i].1,
+                    forall |k: X::V| #[trigger] m@.contains_key(k) ==>
+                        exists |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i].0@ == k,
+                    keyed_by_first(mapping@),
+                    pairs_view(mapping@) == Map::new(
+                        |k: X::V| exists |i: int| #![trigger entries[i]] 0 <= i < it@.0 && entries[i].0@ == k,
+                        |k: X::V| m@[k]@),
+                decreases entries.len() - it@.0,
+            {
+                let ghost old_index = it@.0;
+                match it.next() {
+                    Some((k, v)) => {
+                        let _ = mapping.insert(k.clone_plus(), Pair(k.clone_plus(), v.clone_plus()));
+                        proof {
+                            assert(pairs_view(mapping@) =~= Map::new(
+                                |k: X::V| exists |i: int| #![trigger entries[i]] 0 <= i < old_index + 1 && entries[i].0@ == k,
+                                |k: X::V| m@[k]@));
+                        }
+                    },
+                    None => {
+                        let mapping = MappingStEph { mapping };
+                        proof {
+                            assert(mapping@ =~= m@.map_values(|y: Y| y@));
+                        }
+                        return mapping;
+                    }
+                }
+            }
+        }
+
+        fn size(&self) -> N { self.mapping.len() }
+
+        fn mem(&self, p: &Pair<X, Y>) -> B {
+            match self.mapping.get(&p.0) {
+                Some(q) => feq(&q.1, &p.1),
+                None => false,
+            }
+        }
+
+        fn domain(&self) -> SetStEph<X> {
+            let mut out = SetStEph::<X>::empty();
+            let mut it = self.mapping.iter();
+            let ghost entries = it@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, Y>(),
+                    it@.0 <= entries.len(),
+                    it@.1 == entries,
+                    spec_enumerates(entries, self.mapping@),
+                    out@ == spec_seen_keys(entries, it@.0),
+                decreases entries.len() - it@.0,
             {
-                match outer_iter.next() {
+                let ghost old_index = it@.0;
+                match it.next() {
+                    Some((k, _p)) => {
+                        let _ = out.insert(k.clone_plus());
+                        proof { lemma_seen_keys_next(entries, old_index); }
+                    },
                     None => {
                         proof {
-                            assert forall |x: X::V, y1: Y::V, y2: Y::V|
-                                #![trigger s@.contains((x, y1)), s@.contains((x, y2))]
-                                s@.contains((x, y1)) && s@.contains((x, y2)) implies y1 == y2 by {
-                                if s@.contains((x, y1)) && s@.contains((x, y2)) {
-                                    let mapped = the_seq.map(|i: int, pair: Pair<X,Y>| pair@);
-                                    let i1 = choose |i: int| #![auto] 0 <= i < mapped.len() && mapped[i] == (x, y1);
-                                    assert(is_functional_set_at(s@, the_seq[i1]@)); // for documentation
-                                }
-                            }
+                            lemma_seen_keys_all(entries, self.mapping@);
+                            assert(out@ =~= self@.dom());
                         }
-                        return true;
+                        return out;
                     }
-                    Some(p) => {
-                        if !Self::is_functional_SetStEph_at(s, p) {
-                            proof {
-                                let idx = outer_iter@.0 - 1;
-                                let mapped = the_seq.map(|i: int, pair: Pair<X,Y>| pair@);
-                                assert(mapped[idx] == p@);
-                                assert(mapped.to_set().contains(p@));
+                }
+            }
+        }
+
+        fn range(&self) -> SetStEph<Y> {
+            let mut out = SetStEph::<Y>::empty();
+            let mut it = self.mapping.iter();
+            let ghost entries = it@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, Y>(),
+                    it@.0 <= entries.len(),
+                    it@.1 == entries,
+                    spec_enumerates(entries, self.mapping@),
+                    out@ == Set::<Y::V>::new(|y: Y::V| exists |i: int| #![trigger entries[i]] 0 <= i < it@.0 && entries[i].1@.1 == y),
+                decreases entries.len() - it@.0,
+            {
+                let ghost old_index = it@.0;
+                match it.next() {
+                    Some((_k, p)) => {
+                        let _ = out.insert(p.1.clone_plus());
+                        proof {
+                            assert(out@ =~= Set::<Y::V>::new(|y: Y::V|
+                                exists |i: int| #![trigger entries[i]] 0 <= i < old_index + 1 && entries[i].1@.1 == y));
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |y: Y::V| out@.contains(y) implies
+                                (exists |x: X::V| #![auto] self@.dom().contains(x) && self@[x] == y) by {
+                                let i = choose |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i].1@.1 == y;
+                                assert(self@[entries[i].0@] == y);
+                            }
+                            assert forall |y: Y::V| (exists |x: X::V| #![auto] self@.dom().contains(x) && self@[x] == y) implies
+                                out@.contains(y) by {
+                                let x = choose |x: X::V| #![auto] self@.dom().contains(x) && self@[x] == y;
+                                let i = choose |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i].0@ == x;
                             }
-                            return false;
                         }
+                        return out;
                     }
                 }
             }
         }
 
-        fn is_functional_RelationStEph(r: &RelationStEph<X, Y>) -> (functional: bool) {
-            Self::is_functional_SetStEph(&r.pairs)
+        fn is_injective(&self) -> B {
+            match invert(self) {
+                Some(_) => true,
+                None => false,
+            }
         }
 
-        fn empty() -> MappingStEph<X, Y> {
-            MappingStEph { mapping: RelationStEph::empty() }
+        fn is_surjective_onto(&self, ys: &SetStEph<Y>) -> B {
+            let range = self.range();
+            proof { assert(range@ =~= map_range(self@)); }
+            ys.subset_of(&range)
         }
 
-        fn FromVec(v: Vec<Pair<X, Y>>) -> MappingStEph<X, Y> {
-            let pairs = SetStEph::FromVec(v);
-            MappingStEph { mapping: RelationStEph::FromSet(pairs) }
+        fn is_bijection_onto(&self, ys: &SetStEph<Y>) -> B {
+            if !self.is_injective() {
+                return false;
+            }
+            let range = self.range();
+            proof { assert(range@ =~= map_range(self@)); }
+            let onto = ys.subset_of(&range);
+            let into = range.subset_of(ys);
+            proof {
+                if onto && into { assert(map_range(self@) =~= ys@); }
+            }
+            onto && into
         }
 
-        fn FromRelation(r: &RelationStEph<X, Y>) -> MappingStEph<X, Y> {
-            MappingStEph { mapping: r.clone() }
+        fn inverse(&self) -> Option<MappingStEph<Y, X>> {
+            match invert(self) {
+                Some(mapping) => {
+                    let inv = MappingStEph { mapping };
+                    proof {
+                        assert forall |x: X::V| #[trigger] self@.contains_key(x) implies inv@[self@[x]] == x by {
+                            assert(map_range(self@).contains(self@[x]));
+                            let x2 = inverse_view(self@)[self@[x]];
+                            assert(self@.contains_key(x2) && self@[x2] == self@[x]);
+                        }
+                    }
+                    Some(inv)
+                },
+                None => None,
+            }
         }
 
-        fn size(&self) -> N { self.mapping.size() }
-        fn mem(&self, p: &Pair<X, Y>) -> B { self.mapping.relates(p) }
-        fn domain(&self) -> SetStEph<X> { self.mapping.domain() }
+        fn compose<Z: StT + Hash>(&self, g: &MappingStEph<Y, Z>) -> MappingStEph<X, Z> {
+            let mut mapping = HashMapWithViewPlus::<X, Pair<X, Z>>::new();
+            let mut it = self.mapping.iter();
+            let ghost entries = it@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, Y>(),
+                    valid_key_type_Pair::<Y, Z>(),
+                    it@.0 <= entries.len(),
+                    it@.1 == entries,
+                    spec_enumerates(entries, self.mapping@),
+                    keyed_by_first(mapping@),
+                    pairs_view(mapping@) == Map::new(
+                        |x: X::V| spec_seen_keys(entries, it@.0).contains(x)
+                            && compose_view(self@, g@).contains_key(x),
+                        |x: X::V| g@[self@[x]]),
+                decreases entries.len() - it@.0,
+            {
+                let ghost old_index = it@.0;
+                match it.next() {
+                    Some((k, p)) => {
+                        proof { assert(p@.0 == k@ && self@[k@] == p@.1); }
+                        match g.mapping.get(&p.1) {
+                            Some(q) => {
+                                let _ = mapping.insert(k.clone_plus(), Pair(k.clone_plus(), q.1.clone_plus()));
+                            },
+                            None => {},
+                        }
+                        proof {
+                            lemma_seen_keys_next(entries, old_index);
+                            assert(pairs_view(mapping@) =~= Map::new(
+                                |x: X::V| spec_seen_keys(entries, old_index + 1).contains(x)
+                                    && compose_view(self@, g@).contains_key(x),
+                                |x: X::V| g@[self@[x]]));
+                        }
+                    },
+                    None => {
+                        let composed = MappingStEph { mapping };
+                        proof {
+                            lemma_seen_keys_all(entries, self.mapping@);
+                            assert(composed@ =~= compose_view(self@, g@));
+                        }
+                        return composed;
+                    }
+                }
+            }
+        }
+
+        fn tabulate<F: Fn(&X) -> Y>(domain: &SetStEph<X>, f: &F, spec_f: Ghost<spec_fn(X::V) -> Y::V>) -> MappingStEph<X, Y> {
+            let mut mapping = HashMapWithViewPlus::<X, Pair<X, Y>>::new();
+            let mut it = domain.iter();
+            let ghost xs = it@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, Y>(),
+                    it@.0 <= xs.len(),
+                    it@.1 == xs,
+                    xs.map(|i: int, x: X| x@).to_set() == domain@,
+                    keyed_by_first(mapping@),
+                    pairs_view(mapping@) == tabulate_view(xs.take(it@.0).map(|i: int, x: X| x@).to_set(), spec_f@),
+                decreases xs.len() - it@.0,
+            {
+                let ghost old_index = it@.0;
+                match it.next() {
+                    Some(x) => {
+                        let y = f(x);
+                        let _ = mapping.insert(x.clone_plus(), Pair(x.clone_plus(), y));
+                        proof {
+                            lemma_take_one_more_extends_the_seq_set_with_view(xs, old_index);
+                            assert(pairs_view(mapping@) =~=
+                                tabulate_view(xs.take(old_index + 1).map(|i: int, x: X| x@).to_set(), spec_f@));
+                        }
+                    },
+                    None => {
+                        proof { lemma_take_full_to_set_with_view(xs); }
+                        return MappingStEph { mapping };
+                    }
+                }
+            }
+        }
+
+        fn union_with<F: Fn(&Y, &Y) -> Y>(&self, other: &Self, combine: &F, spec_combine: Ghost<spec_fn(Y::V, Y::V) -> Y::V>)
+            -> MappingStEph<X, Y> {
+            let ghost u = union_with_view(self@, other@, spec_combine@);
+            let mut mapping = HashMapWithViewPlus::<X, Pair<X, Y>>::new();
+
+            // Keys of self, combined where other also has them.
+            let mut it = self.mapping.iter();
+            let ghost entries = it@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, Y>(),
+                    it@.0 <= entries.len(),
+                    it@.1 == entries,
+                    spec_enumerates(entries, self.mapping@),
+                    keyed_by_first(mapping@),
+                    pairs_view(mapping@) == Map::new(
+                        |x: X::V| spec_seen_keys(entries, it@.0).contains(x),
+                        |x: X::V| u[x]),
+                decreases entries.len() - it@.0,
+            {
+                let ghost old_index = it@.0;
+                match it.next() {
+                    Some((k, p)) => {
+                        proof { assert(p@.0 == k@ && self@[k@] == p@.1); }
+                        match other.mapping.get(&p.0) {
+                            Some(q) => {
+                                let y = combine(&p.1, &q.1);
+                                let _ = mapping.insert(k.clone_plus(), Pair(k.clone_plus(), y));
+                            },
+                            None => {
+                                let _ = mapping.insert(k.clone_plus(), Pair(k.clone_plus(), p.1.clone_plus()));
+                            },
+                        }
+                        proof {
+                            lemma_seen_keys_next(entries, old_index);
+                            assert(pairs_view(mapping@) =~= Map::new(
+                                |x: X::V| spec_seen_keys(entries, old_index + 1).contains(x),
+                                |x: X::V| u[x]));
+                        }
+                    },
+                    None => break,
+                }
+            }
 
-        fn range(&self) -> SetStEph<Y> { 
-            let result = self.mapping.range();
             proof {
-                assert forall |y: Y::V| result@.contains(y) implies 
-                    (exists |x: X::V| #![auto] self@.dom().contains(x) && self@[x] == y) by {
-                    if result@.contains(y) {
-                        let witness_x = choose |x: X::V| self.mapping@.contains((x, y));
-                        let chosen_y = choose |y_prime: Y::V| self.mapping@.contains((witness_x, y_prime));
-                        assert(self@[witness_x] == chosen_y);
+                lemma_seen_keys_all(entries, self.mapping@);
+                assert(pairs_view(mapping@) =~= Map::new(|x: X::V| self@.contains_key(x), |x: X::V| u[x]));
+            }
+
+            // Keys only in other.
+            let mut it = other.mapping.iter();
+            let ghost entries = it@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, Y>(),
+                    it@.0 <= entries.len(),
+                    it@.1 == entries,
+                    spec_enumerates(entries, other.mapping@),
+                    keyed_by_first(mapping@),
+                    pairs_view(mapping@) == Map::new(
+                        |x: X::V| self@.contains_key(x) || spec_seen_keys(entries, it@.0).contains(x),
+                        |x: X::V| u[x]),
+                decreases entries.len() - it@.0,
+            {
+                let ghost old_index = it@.0;
+                match it.next() {
+                    Some((k, p)) => {
+                        proof { assert(p@.0 == k@ && other@[k@] == p@.1); }
+                        if !self.mapping.contains_key(k) {
+                            let _ = mapping.insert(k.clone_plus(), Pair(k.clone_plus(), p.1.clone_plus()));
+                        }
+                        proof {
+                            lemma_seen_keys_next(entries, old_index);
+                            assert(pairs_view(mapping@) =~= Map::new(
+                                |x: X::V| self@.contains_key(x) || spec_seen_keys(entries, old_index + 1).contains(x),
+                                |x: X::V| u[x]));
+                        }
+                    },
+                    None => {
+                        let union = MappingStEph { mapping };
+                        proof {
+                            lemma_seen_keys_all(entries, other.mapping@);
+                            assert(union@ =~= u);
+                        }
+                        return union;
+                    }
+                }
+            }
+        }
+
+        fn intersect_with<F: Fn(&Y, &Y) -> Y>(&self, other: &Self, combine: &F, spec_combine: Ghost<spec_fn(Y::V, Y::V) -> Y::V>)
+            -> MappingStEph<X, Y> {
+            let ghost n = intersect_with_view(self@, other@, spec_combine@);
+            let mut mapping = HashMapWithViewPlus::<X, Pair<X, Y>>::new();
+            let mut it = self.mapping.iter();
+            let ghost entries = it@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, Y>(),
+                    it@.0 <= entries.len(),
+                    it@.1 == entries,
+                    spec_enumerates(entries, self.mapping@),
+                    keyed_by_first(mapping@),
+                    pairs_view(mapping@) == Map::new(
+                        |x: X::V| spec_seen_keys(entries, it@.0).contains(x) && other@.contains_key(x),
+                        |x: X::V| n[x]),
+                decreases entries.len() - it@.0,
+            {
+                let ghost old_index = it@.0;
+                match it.next() {
+                    Some((k, p)) => {
+                        proof { assert(p@.0 == k@ && self@[k@] == p@.1); }
+                        match other.mapping.get(&p.0) {
+                            Some(q) => {
+                                let y = combine(&p.1, &q.1);
+                                let _ = mapping.insert(k.clone_plus(), Pair(k.clone_plus(), y));
+                            },
+                            None => {},
+                        }
+                        proof {
+                            lemma_seen_keys_next(entries, old_index);
+                            assert(pairs_view(mapping@) =~= Map::new(
+                                |x: X::V| spec_seen_keys(entries, old_index + 1).contains(x) && other@.contains_key(x),
+                                |x: X::V| n[x]));
+                        }
+                    },
+                    None => {
+                        let intersection = MappingStEph { mapping };
+                        proof {
+                            lemma_seen_keys_all(entries, self.mapping@);
+                            assert(intersection@ =~= n);
+                        }
+                        return intersection;
                     }
                 }
             }
-            result
         }
 
-        fn iter(&self) -> MappingStEphIter<'_, X, Y> { 
-            MappingStEphIter { inner: self.mapping.iter() }
+        fn difference(&self, other: &Self) -> MappingStEph<X, Y> {
+            let not_in_other = |x: &X, y: &Y| -> (keep: B)
+                requires valid_key_type_Pair::<X, Y>()
+                ensures keep == !other@.contains_key(x@)
+            { !other.mapping.contains_key(x) };
+            let difference = self.filter(&not_in_other, Ghost(|x: X::V, y: Y::V| !other@.contains_key(x)));
+            proof { assert(difference@ =~= difference_view(self@, other@)); }
+            difference
+        }
+
+        fn filter<F: Fn(&X, &Y) -> B>(&self, pred: &F, spec_pred: Ghost<spec_fn(X::V, Y::V) -> bool>) -> MappingStEph<X, Y> {
+            let ghost sp = spec_pred@;
+            let mut mapping = HashMapWithViewPlus::<X, Pair<X, Y>>::new();
+            let mut it = self.mapping.iter();
+            let ghost entries = it@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, Y>(),
+                    it@.0 <= entries.len(),
+                    it@.1 == entries,
+                    spec_enumerates(entries, self.mapping@),
+                    keyed_by_first(mapping@),
+                    pairs_view(mapping@) == Map::new(
+                        |x: X::V| spec_seen_keys(entries, it@.0).contains(x) && sp(x, self@[x]),
+                        |x: X::V| self@[x]),
+                decreases entries.len() - it@.0,
+            {
+                let ghost old_index = it@.0;
+                match it.next() {
+                    Some((k, p)) => {
+                        proof { assert(p@.0 == k@ && self@[k@] == p@.1); }
+                        if pred(&p.0, &p.1) {
+                            let _ = mapping.insert(k.clone_plus(), Pair(k.clone_plus(), p.1.clone_plus()));
+                        }
+                        proof {
+                            lemma_seen_keys_next(entries, old_index);
+                            assert(pairs_view(mapping@) =~= Map::new(
+                                |x: X::V| spec_seen_keys(entries, old_index + 1).contains(x) && sp(x, self@[x]),
+                                |x: X::V| self@[x]));
+                        }
+                    },
+                    None => {
+                        let filtered = MappingStEph { mapping };
+                        proof {
+                            lemma_seen_keys_all(entries, self.mapping@);
+                            assert(filtered@ =~= filter_view(self@, sp));
+                        }
+                        return filtered;
+                    }
+                }
+            }
+        }
+
+        fn map_values<Z: StT + Hash, F: Fn(&Y) -> Z>(&self, f: &F, spec_f: Ghost<spec_fn(Y::V) -> Z::V>) -> MappingStEph<X, Z> {
+            let mut mapping = HashMapWithViewPlus::<X, Pair<X, Z>>::new();
+            let mut it = self.mapping.iter();
+            let ghost entries = it@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, Y>(),
+                    valid_key_type_Pair::<X, Z>(),
+                    it@.0 <= entries.len(),
+                    it@.1 == entries,
+                    spec_enumerates(entries, self.mapping@),
+                    keyed_by_first(mapping@),
+                    pairs_view(mapping@) == Map::new(
+                        |x: X::V| spec_seen_keys(entries, it@.0).contains(x),
+                        |x: X::V| spec_f@(self@[x])),
+                decreases entries.len() - it@.0,
+            {
+                let ghost old_index = it@.0;
+                match it.next() {
+                    Some((k, p)) => {
+                        proof { assert(p@.0 == k@ && self@[k@] == p@.1); }
+                        let z = f(&p.1);
+                        // Keys are distinct in self, so each insert adds a new key and functionality is kept.
+                        let _ = mapping.insert(k.clone_plus(), Pair(k.clone_plus(), z));
+                        proof {
+                            lemma_seen_keys_next(entries, old_index);
+                            assert(pairs_view(mapping@) =~= Map::new(
+                                |x: X::V| spec_seen_keys(entries, old_index + 1).contains(x),
+                                |x: X::V| spec_f@(self@[x])));
+                        }
+                    },
+                    None => {
+                        let mapped = MappingStEph { mapping };
+                        proof {
+                            lemma_seen_keys_all(entries, self.mapping@);
+                            assert(mapped@ =~= self@.map_values(spec_f@));
+                        }
+                        return mapped;
+                    }
+                }
+            }
+        }
+
+        fn restrict(&self, keys: &SetStEph<X>) -> MappingStEph<X, Y> {
+            let in_keys = |x: &X, y: &Y| -> (keep: B)
+                requires valid_key_type_Pair::<X, Y>()
+                ensures keep == keys@.contains(x@)
+            { keys.mem(x) };
+            let restricted = self.filter(&in_keys, Ghost(|x: X::V, y: Y::V| keys@.contains(x)));
+            proof { assert(restricted@ =~= self@.restrict(keys@)); }
+            restricted
+        }
+
+        fn iter(&self) -> MappingStEphIter<'_, X, Y> {
+            let inner = self.mapping.iter();
+            let it = MappingStEphIter { inner };
+            proof {
+                let entries = inner@.1;
+                let pairs = it@.1;
+                // Each stored pair carries its own key, and keys are distinct, so the pairs are too.
+                assert forall |i: int, j: int| 0 <= i < pairs.len() && 0 <= j < pairs.len() && i != j
+                    implies #[trigger] pairs[i] != #[trigger] pairs[j] by {
+                    assert(pairs[i] == entries[i].1 && pairs[j] == entries[j].1);
+                    assert(pairs[i]@.0 == entries[i].0@ && pairs[j]@.0 == entries[j].0@);
+                }
+                assert(pairs.no_duplicates());
+                assert forall |p: (X::V, Y::V)| #[trigger] pairs.map(|i: int, q: Pair<X, Y>| q@).to_set().contains(p) <==>
+                    (self@.dom().contains(p.0) && self@[p.0] == p.1) by {
+                    if pairs.map(|i: int, q: Pair<X, Y>| q@).to_set().contains(p) {
+                        lemma_map_to_set_contains_index(pairs, p);
+                        let i = choose |i: int| #![auto] 0 <= i < pairs.len() && p == pairs[i]@;
+                        assert(entries[i].1 == pairs[i]);
+                    }
+                    if self@.dom().contains(p.0) && self@[p.0] == p.1 {
+                        let i = choose |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i].0@ == p.0;
+                        assert(pairs[i] == entries[i].1);
+                        lemma_seq_index_in_map_to_set(pairs, i);
+                    }
+                }
+                assert(pairs.map(|i: int, q: Pair<X, Y>| q@).to_set() =~=
+                    Set::new(|p: (X::V, Y::V)| self@.dom().contains(p.0) && self@[p.0] == p.1));
+            }
+            it
+        }
+    }
+
+    /// Grouping and map-reduce over a relation. These return mappings, so they live here rather
+    /// than in RelationStEph, which MappingStEph builds on.
+    pub trait RelationGroupStEphTrait<X: StT + Hash, Y: StT + Hash> :
+        View<V = Set<(<X as View>::V, <Y as View>::V)>> + Sized {
+
+        /// Group each x in the domain with its image set { y | x R y }.
+        /// APAS: Work Θ(|R|), Span Θ(1)
+        fn group_by_domain(&self) -> (grouped: MappingStEph<X, SetStEph<Y>>)
+            requires
+                valid_key_type_Pair::<X, Y>(),
+                valid_key_type_Pair::<X, SetStEph<Y>>(),
+            ensures
+                grouped@ == group_by_view(self@),
+                grouped.is_functional();
+
+        /// Inverse of `group_by_domain`: relate each x to every element of its image set.
+        /// APAS: Work Θ(|R|), Span Θ(1)
+        fn flatten(grouped: &MappingStEph<X, SetStEph<Y>>) -> (relation: Self)
+            requires
+                valid_key_type_Pair::<X, Y>(),
+                valid_key_type_Pair::<X, SetStEph<Y>>(),
+                grouped.is_functional(),
+            ensures relation@ == flatten_view(grouped@);
+
+        /// Map-reduce over the relation: fold each image set with the associative and commutative `f`.
+        /// `f` has the bounds of `MtReduceFn` (spelled out, as that trait is outside verus!), so the
+        /// same reducer serves the Mt variants.
+        /// APAS: Work Θ(|R|), Span Θ(1)
+        fn group_reduce<F: Fn(&Y, &Y) -> Y + Clone + Send + Sync + 'static>(&self, f: &F, spec_f: Ghost<spec_fn(Y::V, Y::V) -> Y::V>) -> (reduced: MappingStEph<X, Y>)
+            requires
+                valid_key_type_Pair::<X, Y>(),
+                spec_associative(spec_f@),
+                spec_commutative(spec_f@),
+                forall |a: &Y, b: &Y| #[trigger] f.requires((a, b)),
+                forall |a: Y, b: Y, r: Y| #[trigger] f.ensures((&a, &b), r) ==> r@ == spec_f@(a@, b@),
+            ensures
+                reduced.is_functional(),
+                reduced@.dom() == group_by_view(self@).dom(),
+                forall |x: X::V| #[trigger] reduced@.contains_key(x) ==>
+                    reduced@[x] == reduce_set(group_by_view(self@)[x], spec_f@);
+    }
+
+    impl<X: StT + Hash, Y: StT + Hash> RelationGroupStEphTrait<X, Y> for RelationStEph<X, Y> {
+
+        fn group_by_domain(&self) -> MappingStEph<X, SetStEph<Y>> {
+            let index = index_by_first(self);
+            MappingStEph::FromHashMap(index)
+        }
+
+        fn flatten(grouped: &MappingStEph<X, SetStEph<Y>>) -> RelationStEph<X, Y> {
+            let mut out = SetStEph::<Pair<X, Y>>::empty();
+            let mut git = grouped.iter();
+            let ghost entries = git@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, Y>(),
+                    valid_key_type_Pair::<X, SetStEph<Y>>(),
+                    git@.0 <= entries.len(),
+                    git@.1 == entries,
+                    entries.map(|i: int, p: Pair<X, SetStEph<Y>>| p@).to_set() ==
+                        Set::new(|p: (X::V, Set<Y::V>)| grouped@.dom().contains(p.0) && grouped@[p.0] == p.1),
+                    out@ == Set::<(X::V, Y::V)>::new(|p: (X::V, Y::V)|
+                        exists |i: int| #![trigger entries[i]] 0 <= i < git@.0 && entries[i]@.0 == p.0 && entries[i]@.1.contains(p.1)),
+                decreases entries.len() - git@.0,
+            {
+                let ghost old_index = git@.0;
+                let ghost old_out = out@;
+                match git.next() {
+                    Some(entry) => {
+                        let Pair(x, ys) = entry;
+                        let mut yit = ys.iter();
+                        let ghost ys_seq = yit@.1;
+
+                        #[verifier::loop_isolation(false)]
+                        loop
+                            invariant
+                                valid_key_type_Pair::<X, Y>(),
+                                yit@.0 <= ys_seq.len(),
+                                yit@.1 == ys_seq,
+                                ys_seq.map(|i: int, k: Y| k@).to_set() == ys@,
+                                out@ == old_out.union(Set::<(X::V, Y::V)>::new(|p: (X::V, Y::V)|
+                                    p.0 == x@ && ys_seq.take(yit@.0).map(|i: int, k: Y| k@).to_set().contains(p.1))),
+                            decreases ys_seq.len() - yit@.0,
+                        {
+                            let ghost old_yindex = yit@.0;
+                            match yit.next() {
+                                Some(y) => {
+                                    let _ = out.insert(Pair(x.clone_plus(), y.clone_plus()));
+                                    proof {
+                                        lemma_take_one_more_extends_the_seq_set_with_view(ys_seq, old_yindex);
+                                        assert(out@ =~= old_out.union(Set::<(X::V, Y::V)>::new(|p: (X::V, Y::V)|
+                                            p.0 == x@ && ys_seq.take(old_yindex + 1).map(|i: int, k: Y| k@).to_set().contains(p.1))));
+                                    }
+                                },
+                                None => {
+                                    proof { lemma_take_full_to_set_with_view(ys_seq); }
+                                    break;
+                                }
+                            }
+                        }
+                        proof {
+                            assert(out@ =~= Set::<(X::V, Y::V)>::new(|p: (X::V, Y::V)|
+                                exists |i: int| #![trigger entries[i]] 0 <= i < old_index + 1 && entries[i]@.0 == p.0 && entries[i]@.1.contains(p.1)));
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |p: (X::V, Y::V)| #[trigger] out@.contains(p) implies flatten_view(grouped@).contains(p) by {
+                                let i = choose |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i]@.0 == p.0 && entries[i]@.1.contains(p.1);
+                                lemma_seq_index_in_map_to_set(entries, i);
+                            }
+                            assert forall |p: (X::V, Y::V)| #[trigger] flatten_view(grouped@).contains(p) implies out@.contains(p) by {
+                                lemma_map_to_set_contains_index(entries, (p.0, grouped@[p.0]));
+                                let i = choose |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i]@ == (p.0, grouped@[p.0]);
+                            }
+                            assert(out@ =~= flatten_view(grouped@));
+                        }
+                        return RelationStEph { pairs: out };
+                    }
+                }
+            }
+        }
+
+        fn group_reduce<F: Fn(&Y, &Y) -> Y + Clone + Send + Sync + 'static>(&self, f: &F, spec_f: Ghost<spec_fn(Y::V, Y::V) -> Y::V>) -> MappingStEph<X, Y> {
+            let mut acc = HashMapWithViewPlus::<X, Y>::new();
+            // The ys folded so far for each x, in fold order.
+            let ghost mut folded = Map::<X::V, Seq<Y::V>>::empty();
+            let mut it = self.iter();
+            let ghost pairs_seq = it@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, Y>(),
+                    it@.0 <= pairs_seq.len(),
+                    it@.1 == pairs_seq,
+                    pairs_seq.map(|i: int, p: Pair<X, Y>| p@).to_set() == self@,
+                    pairs_seq.no_duplicates(),
+                    acc@.dom() == folded.dom(),
+                    folded.dom() == group_by_view(pairs_seq.take(it@.0).map(|i: int, p: Pair<X, Y>| p@).to_set()).dom(),
+                    forall |x: X::V| #[trigger] folded.contains_key(x) ==> {
+                        &&& folded[x].len() > 0
+                        &&& folded[x].no_duplicates()
+                        &&& folded[x].to_set() == group_by_view(pairs_seq.take(it@.0).map(|i: int, p: Pair<X, Y>| p@).to_set())[x]
+                        &&& acc@[x]@ == reduce_seq(folded[x], spec_f@)
+                    },
+                decreases pairs_seq.len() - it@.0,
+            {
+                let ghost old_index = it@.0;
+                let ghost prefix = pairs_seq.take(old_index).map(|i: int, p: Pair<X, Y>| p@).to_set();
+                match it.next() {
+                    Some(pair) => {
+                        let Pair(x, y) = pair;
+                        proof {
+                            lemma_take_one_more_extends_the_seq_set_with_view(pairs_seq, old_index);
+                            lemma_no_duplicates_view_not_in_take(pairs_seq, old_index);
+                            lemma_group_by_view_insert(prefix, x@, y@);
+                        }
+                        match acc.remove(x) {
+                            Some(v) => {
+                                let r = f(&v, y);
+                                let _ = acc.insert(x.clone_plus(), r);
+                                proof {
+                                    let ys = folded[x@];
+                                    assert(!ys.contains(y@));
+                                    lemma_reduce_seq_push(ys, y@, spec_f@);
+                                    assert(ys.push(y@).to_set() =~= ys.to_set().insert(y@));
+                                    folded = folded.insert(x@, ys.push(y@));
+                                }
+                            },
+                            None => {
+                                let _ = acc.insert(x.clone_plus(), y.clone_plus());
+                                proof {
+                                    assert forall |y2: Y::V| !prefix.contains((x@, y2)) by {
+                                        if prefix.contains((x@, y2)) { assert(group_by_view(prefix).contains_key(x@)); }
+                                    }
+                                    lemma_reduce_seq_singleton(y@, spec_f@);
+                                    assert(seq![y@].to_set() =~= Set::new(|y2: Y::V| prefix.contains((x@, y2))).insert(y@));
+                                    folded = folded.insert(x@, seq![y@]);
+                                }
+                            },
+                        }
+                        proof {
+                            assert(acc@.dom() =~= folded.dom());
+                            assert(folded.dom() =~= group_by_view(pairs_seq.take(old_index + 1).map(|i: int, p: Pair<X, Y>| p@).to_set()).dom());
+                        }
+                    },
+                    None => {
+                        proof { lemma_take_full_to_set_with_view(pairs_seq); }
+                        let reduced = MappingStEph::FromHashMap(acc);
+                        proof {
+                            assert forall |x: X::V| #[trigger] reduced@.contains_key(x) implies
+                                reduced@[x] == reduce_set(group_by_view(self@)[x], spec_f@) by {
+                                assert(folded.contains_key(x));
+                                lemma_reduce_set_is_reduce_seq(folded[x], spec_f@);
+                            }
+                        }
+                        return reduced;
+                    }
+                }
+            }
         }
     }
 
@@ -363,37 +1702,53 @@ verus! {
         }};
         ( $( ($a:expr, $b:expr) ),* $(,)? ) => {{
             let __pairs = vec![ $( $crate::Types::Types::Pair($a, $b) ),* ];
-            // Check for duplicate domain elements
-            let mut __seen_keys = std::collections::HashSet::new();
-            for pair in &__pairs {
-                let key = &pair.0;
-                if !__seen_keys.insert(key) {
-                    panic!("MappingLit!: duplicate domain element {:?}", key);
-                }
+            match < $crate::Chap05::MappingStEph::MappingStEph::MappingStEph<_, _> >::try_from_vec(__pairs) {
+                Ok(__m) => __m,
+                Err(__e) => panic!("MappingLit!: duplicate domain element {:?} maps to both {:?} and {:?}",
+                                   __e.key, __e.first, __e.second),
             }
-            < $crate::Chap05::MappingStEph::MappingStEph::MappingStEph<_, _> >::FromVec(__pairs)
         }};
     }
 
+    impl<A: StT + Hash + Send + Sync, B: StT + Hash + Send + Sync> MtT for MappingStEph<A, B> {
+        type Inner = MappingStEph<A, B>;
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { self@ == other@ }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { self@ == inner@ }
+        fn clone_mt(&self) -> (clone: Self) { self.clone() }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
+    }
+
   } // verus!
 
     impl<A: StT + Hash, B: StT + Hash> PartialEq for MappingStEph<A, B> {
         fn eq(&self, other: &Self) -> bool { self.mapping == other.mapping }
     }
 
+    impl<X: Debug, Y: Debug> Debug for NonFunctional<X, Y> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            write!(f, "NonFunctional({:?} -> {:?}, {:?})", self.key, self.first, self.second)
+        }
+    }
+
+    impl<X: Debug, Y: Debug> Display for NonFunctional<X, Y> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            write!(f, "{:?} maps to both {:?} and {:?}", self.key, self.first, self.second)
+        }
+    }
+
     impl<A: StT + Hash, B: StT + Hash> Debug for MappingStEph<A, B> {
-        fn fmt(&self, f: &mut Formatter<'_>) -> Result { Debug::fmt(&self.mapping, f) }
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result { write!(f, "MappingStEph({})", self.mapping.m.len()) }
     }
 
     impl<A: StT + Hash, B: StT + Hash> Display for MappingStEph<A, B> {
-        fn fmt(&self, f: &mut Formatter<'_>) -> Result { Display::fmt(&self.mapping, f) }
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result { write!(f, "Mapping({})", self.mapping.m.len()) }
     }
 
     // Implement std::iter::Iterator for MappingStEphIter to enable standard iteration methods
     impl<'a, A: StT + Hash, B: StT + Hash> std::iter::Iterator for MappingStEphIter<'a, A, B> {
         type Item = &'a crate::Types::Types::Pair<A, B>;
         fn next(&mut self) -> Option<Self::Item> {
-            self.inner.next()
+            self.inner.next().map(|(_k, p)| p)
         }
     }
 }
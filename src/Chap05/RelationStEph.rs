@@ -22,10 +22,11 @@ verus! {
     #[cfg(not(verus_keep_ghost))]
     use crate::vstdplus::feq::feq::feq;
     use crate::vstdplus::clone_plus::clone_plus::ClonePlus;
+    use crate::vstdplus::hash_map_with_view_plus::hash_map_with_view_plus::HashMapWithViewPlus;
     use crate::Chap05::SetStEph::SetStEph::*;
     use crate::Types::Types::*;
 
-    broadcast use {vstd::seq_lib::group_seq_properties, vstd::set::group_set_axioms};
+    broadcast use {vstd::seq_lib::group_seq_properties, vstd::set::group_set_axioms, crate::vstdplus::feq::feq::group_feq_axioms};
 
     #[verifier::reject_recursive_types(A)]
     #[verifier::reject_recursive_types(B)]
@@ -68,6 +69,338 @@ verus! {
         }
     }
 
+    /// Each x in the domain of r maps to its image { y | x r y }.
+    pub open spec fn group_by_view<X, Y>(r: Set<(X, Y)>) -> Map<X, Set<Y>> {
+        Map::new(
+            |x: X| exists |y: Y| r.contains((x, y)),
+            |x: X| Set::new(|y: Y| r.contains((x, y)))
+        )
+    }
+
+    /// Relate each key of m to every element of its image set.
+    pub open spec fn flatten_view<X, Y>(m: Map<X, Set<Y>>) -> Set<(X, Y)> {
+        Set::new(|p: (X, Y)| m.contains_key(p.0) && m[p.0].contains(p.1))
+    }
+
+    /// Flattening the grouping of a relation gives the relation back.
+    pub proof fn lemma_flatten_group_by_view<X, Y>(r: Set<(X, Y)>)
+        ensures flatten_view(group_by_view(r)) == r,
+    {
+        assert(flatten_view(group_by_view(r)) =~= r);
+    }
+
+    /// Grouping a flattened map gives the map back, provided no image set is empty.
+    pub proof fn lemma_group_by_flatten_view<X, Y>(m: Map<X, Set<Y>>)
+        requires forall |x: X| #[trigger] m.contains_key(x) ==> m[x] != Set::<Y>::empty(),
+        ensures group_by_view(flatten_view(m)) == m,
+    {
+        let g = group_by_view(flatten_view(m));
+        assert forall |x: X| #[trigger] m.contains_key(x) implies g.contains_key(x) by {
+            assert(!(m[x] =~= Set::<Y>::empty()));
+            let y = choose |y: Y| m[x].contains(y);
+            assert(flatten_view(m).contains((x, y)));
+        }
+        assert forall |x: X| #[trigger] g.contains_key(x) implies m.contains_key(x) && g[x] =~= m[x] by {
+            let y = choose |y: Y| flatten_view(m).contains((x, y));
+        }
+        assert(g =~= m);
+    }
+
+    /// Adding a pair (x, y) to a relation adds y to the image of x and leaves other images alone.
+    pub proof fn lemma_group_by_view_insert<X, Y>(r: Set<(X, Y)>, x: X, y: Y)
+        ensures group_by_view(r.insert((x, y))) ==
+            group_by_view(r).insert(x, Set::new(|y2: Y| r.contains((x, y2))).insert(y)),
+    {
+        let g = group_by_view(r);
+        let g2 = group_by_view(r.insert((x, y)));
+        assert(r.insert((x, y)).contains((x, y)));
+        assert(g2[x] =~= Set::new(|y2: Y| r.contains((x, y2))).insert(y));
+        assert forall |k: X| k != x implies (#[trigger] g2.contains_key(k) <==> g.contains_key(k)) by {
+            if g2.contains_key(k) {
+                let w = choose |w: Y| r.insert((x, y)).contains((k, w));
+                assert(r.contains((k, w)));
+            }
+            if g.contains_key(k) {
+                let w = choose |w: Y| r.contains((k, w));
+                assert(r.insert((x, y)).contains((k, w)));
+            }
+        }
+        assert forall |k: X| k != x && #[trigger] g2.contains_key(k) implies g2[k] =~= g[k] by {}
+        assert(g2 =~= g.insert(x, Set::new(|y2: Y| r.contains((x, y2))).insert(y)));
+    }
+
+    /// A witness that a homogeneous relation is not acyclic: v0 R v1 R ... R v(k-1) R v0.
+    #[verifier::reject_recursive_types(X)]
+    pub struct Cycle<X: StT + Hash> {
+        pub vertices: Vec<X>,
+    }
+
+    impl<X: StT + Hash> View for Cycle<X> {
+        type V = Seq<X::V>;
+        open spec fn view(&self) -> Seq<X::V> { self.vertices@.map(|i: int, x: X| x@) }
+    }
+
+    pub open spec fn is_cycle<X>(r: Set<(X, X)>, c: Seq<X>) -> bool {
+        &&& c.len() > 0
+        &&& forall |i: int| #![trigger c[i]] 0 <= i < c.len() ==> r.contains((c[i], c[(i + 1) % c.len()]))
+    }
+
+    /// order lists the universe once each, and every pair of r points forward in order.
+    pub open spec fn is_linear_extension<X>(r: Set<(X, X)>, universe: Set<X>, order: Seq<X>) -> bool {
+        &&& order.no_duplicates()
+        &&& order.to_set() == universe
+        &&& forall |i: int, j: int| #![trigger r.contains((order[i], order[j]))]
+            0 <= i < order.len() && 0 <= j < order.len() && r.contains((order[i], order[j])) ==> i < j
+    }
+
+    /// Both components of every pair of r lie in universe.
+    pub open spec fn field_within<X>(r: Set<(X, X)>, universe: Set<X>) -> bool {
+        forall |p: (X, X)| #[trigger] r.contains(p) ==> universe.contains(p.0) && universe.contains(p.1)
+    }
+
+    /// Consecutive elements of path are related by r.
+    pub open spec fn is_path<X>(r: Set<(X, X)>, path: Seq<X>) -> bool {
+        forall |i: int| #![trigger path[i]] 0 <= i < path.len() - 1 ==> r.contains((path[i], path[i + 1]))
+    }
+
+    /// a r+ b: b is reachable from a by one or more steps of r.
+    pub open spec fn reaches<X>(r: Set<(X, X)>, a: X, b: X) -> bool {
+        exists |path: Seq<X>| #[trigger] is_path(r, path) && path.len() >= 2 && path[0] == a && path.last() == b
+    }
+
+    pub open spec fn transitive_closure<X>(r: Set<(X, X)>) -> Set<(X, X)> {
+        Set::new(|p: (X, X)| reaches(r, p.0, p.1))
+    }
+
+    pub open spec fn is_acyclic<X>(r: Set<(X, X)>) -> bool {
+        forall |a: X| !(#[trigger] reaches(r, a, a))
+    }
+
+    pub open spec fn succ_view<X>(r: Set<(X, X)>, a: X) -> Set<X> {
+        Set::new(|y: X| r.contains((a, y)))
+    }
+
+    pub open spec fn range_view<X>(r: Set<(X, X)>) -> Set<X> {
+        Set::new(|y: X| exists |x: X| r.contains((x, y)))
+    }
+
+    pub open spec fn descendants_view<X>(r: Set<(X, X)>, a: X) -> Set<X> {
+        Set::new(|w: X| reaches(r, a, w))
+    }
+
+    /// The Hasse diagram of r: the pairs of r with nothing strictly between them.
+    pub open spec fn hasse_view<X>(r: Set<(X, X)>) -> Set<(X, X)> {
+        Set::new(|p: (X, X)| r.contains(p)
+            && !exists |c: X| #![trigger reaches(r, p.0, c), reaches(r, c, p.1)] reaches(r, p.0, c) && reaches(r, c, p.1))
+    }
+
+    /// The pairs of r whose target lies strictly between a and b; the induction measure for hasse_view.
+    pub open spec fn between_edges<X>(r: Set<(X, X)>, a: X, b: X) -> Set<(X, X)> {
+        r.filter(|p: (X, X)| reaches(r, a, p.1) && reaches(r, p.1, b))
+    }
+
+    pub proof fn lemma_edge_reaches<X>(r: Set<(X, X)>, a: X, b: X)
+        requires r.contains((a, b)),
+        ensures reaches(r, a, b),
+    {
+        let path = seq![a, b];
+        assert(is_path(r, path));
+    }
+
+    pub proof fn lemma_reaches_trans<X>(r: Set<(X, X)>, a: X, b: X, c: X)
+        requires reaches(r, a, b), reaches(r, b, c),
+        ensures reaches(r, a, c),
+    {
+        let p1 = choose |path: Seq<X>| #[trigger] is_path(r, path) && path.len() >= 2 && path[0] == a && path.last() == b;
+        let p2 = choose |path: Seq<X>| #[trigger] is_path(r, path) && path.len() >= 2 && path[0] == b && path.last() == c;
+        let path = p1 + p2.drop_first();
+        let n = p1.len();
+        assert forall |i: int| #![trigger path[i]] 0 <= i < path.len() - 1 implies r.contains((path[i], path[i + 1])) by {
+            if i < n - 1 {
+                assert(path[i] == p1[i] && path[i + 1] == p1[i + 1]);
+            } else if i == n - 1 {
+                assert(path[i] == p2[0] && path[i + 1] == p2[1]);
+            } else {
+                assert(path[i] == p2[i - n + 1] && path[i + 1] == p2[i - n + 2]);
+            }
+        }
+        assert(path.last() == p2.last());
+        assert(is_path(r, path));
+    }
+
+    /// A path of more than one step starts with an edge to a vertex that still reaches b.
+    pub proof fn lemma_reaches_first_step<X>(r: Set<(X, X)>, a: X, b: X)
+        requires reaches(r, a, b),
+        ensures r.contains((a, b)) || exists |s: X| #[trigger] r.contains((a, s)) && reaches(r, s, b),
+    {
+        let path = choose |path: Seq<X>| #[trigger] is_path(r, path) && path.len() >= 2 && path[0] == a && path.last() == b;
+        if path.len() == 2 {
+            assert(r.contains((path[0], path[1])));
+        } else {
+            let rest = path.drop_first();
+            assert forall |i: int| #![trigger rest[i]] 0 <= i < rest.len() - 1 implies r.contains((rest[i], rest[i + 1])) by {
+                assert(rest[i] == path[i + 1] && rest[i + 1] == path[i + 2]);
+            }
+            assert(is_path(r, rest));
+            assert(r.contains((path[0], path[1])));
+            assert(r.contains((a, path[1])) && reaches(r, path[1], b));
+        }
+    }
+
+    pub proof fn lemma_reaches_last_step<X>(r: Set<(X, X)>, a: X, b: X)
+        requires reaches(r, a, b),
+        ensures range_view(r).contains(b),
+    {
+        let path = choose |path: Seq<X>| #[trigger] is_path(r, path) && path.len() >= 2 && path[0] == a && path.last() == b;
+        assert(r.contains((path[path.len() - 2], path[path.len() - 1])));
+    }
+
+    pub proof fn lemma_reaches_subset<X>(r1: Set<(X, X)>, r2: Set<(X, X)>, a: X, b: X)
+        requires r1.subset_of(r2), reaches(r1, a, b),
+        ensures reaches(r2, a, b),
+    {
+        let path = choose |path: Seq<X>| #[trigger] is_path(r1, path) && path.len() >= 2 && path[0] == a && path.last() == b;
+        assert(is_path(r2, path));
+    }
+
+    /// a set holding the successors of a and closed under r holds everything a reaches.
+    pub proof fn lemma_closed_contains_reaches<X>(r: Set<(X, X)>, s: Set<X>, a: X, b: X)
+        requires
+            succ_view(r, a).subset_of(s),
+            forall |w: X, y: X| #![trigger s.contains(w), r.contains((w, y))] s.contains(w) && r.contains((w, y)) ==> s.contains(y),
+            reaches(r, a, b),
+        ensures s.contains(b),
+    {
+        let path = choose |path: Seq<X>| #[trigger] is_path(r, path) && path.len() >= 2 && path[0] == a && path.last() == b;
+        lemma_closed_contains_path(r, s, path, path.len() - 1);
+    }
+
+    proof fn lemma_closed_contains_path<X>(r: Set<(X, X)>, s: Set<X>, path: Seq<X>, i: int)
+        requires
+            is_path(r, path),
+            1 <= i < path.len(),
+            succ_view(r, path[0]).subset_of(s),
+            forall |w: X, y: X| #![trigger s.contains(w), r.contains((w, y))] s.contains(w) && r.contains((w, y)) ==> s.contains(y),
+        ensures s.contains(path[i]),
+        decreases i,
+    {
+        assert(r.contains((path[i - 1], path[i])));
+        if i == 1 {
+            assert(succ_view(r, path[0]).contains(path[1]));
+        } else {
+            lemma_closed_contains_path(r, s, path, i - 1);
+        }
+    }
+
+    pub proof fn lemma_range_finite<X>(r: Set<(X, X)>)
+        requires r.finite(),
+        ensures range_view(r).finite(),
+        decreases r.len(),
+    {
+        if r.len() == 0 {
+            r.lemma_len0_is_empty();
+            assert(range_view(r) =~= Set::<X>::empty());
+        } else {
+            let p = r.choose();
+            lemma_range_finite(r.remove(p));
+            assert forall |y: X| range_view(r).contains(y) implies #[trigger] range_view(r.remove(p)).insert(p.1).contains(y) by {
+                let x = choose |x: X| r.contains((x, y));
+                if (x, y) != p { assert(r.remove(p).contains((x, y))); }
+            }
+            assert(range_view(r).subset_of(range_view(r.remove(p)).insert(p.1)));
+            vstd::set_lib::lemma_len_subset(range_view(r), range_view(r.remove(p)).insert(p.1));
+        }
+    }
+
+    /// Something lies strictly between a and b iff some successor of a reaches b.
+    pub proof fn lemma_between_iff_successor_reaches<X>(r: Set<(X, X)>, a: X, b: X)
+        ensures
+            (exists |c: X| #![trigger reaches(r, a, c), reaches(r, c, b)] reaches(r, a, c) && reaches(r, c, b))
+            <==> (exists |s: X| #[trigger] r.contains((a, s)) && reaches(r, s, b)),
+    {
+        if exists |c: X| #![trigger reaches(r, a, c), reaches(r, c, b)] reaches(r, a, c) && reaches(r, c, b) {
+            let c = choose |c: X| #![trigger reaches(r, a, c), reaches(r, c, b)] reaches(r, a, c) && reaches(r, c, b);
+            lemma_reaches_first_step(r, a, c);
+            if r.contains((a, c)) {
+                assert(r.contains((a, c)) && reaches(r, c, b));
+            } else {
+                let s = choose |s: X| #[trigger] r.contains((a, s)) && reaches(r, s, c);
+                lemma_reaches_trans(r, s, c, b);
+            }
+        }
+        if exists |s: X| #[trigger] r.contains((a, s)) && reaches(r, s, b) {
+            let s = choose |s: X| #[trigger] r.contains((a, s)) && reaches(r, s, b);
+            lemma_edge_reaches(r, a, s);
+        }
+    }
+
+    /// In a finite acyclic relation, everything reachable stays reachable through the Hasse diagram.
+    pub proof fn lemma_reaches_in_hasse<X>(r: Set<(X, X)>, a: X, b: X)
+        requires r.finite(), is_acyclic(r), reaches(r, a, b),
+        ensures reaches(hasse_view(r), a, b),
+        decreases between_edges(r, a, b).len(),
+    {
+        let h = hasse_view(r);
+        if exists |c: X| #![trigger reaches(r, a, c), reaches(r, c, b)] reaches(r, a, c) && reaches(r, c, b) {
+            let c = choose |c: X| #![trigger reaches(r, a, c), reaches(r, c, b)] reaches(r, a, c) && reaches(r, c, b);
+            // The last edge into c is between a and b, but not between a and c nor between c and b.
+            let path = choose |path: Seq<X>| #[trigger] is_path(r, path) && path.len() >= 2 && path[0] == a && path.last() == c;
+            let e = (path[path.len() - 2], c);
+            assert(r.contains(e));
+            assert(!reaches(r, c, c));
+            let bab = between_edges(r, a, b);
+            vstd::set_lib::lemma_len_subset(bab, r);
+            assert(bab.contains(e));
+            assert forall |q: (X, X)| #[trigger] between_edges(r, a, c).contains(q) implies bab.remove(e).contains(q) by {
+                lemma_reaches_trans(r, q.1, c, b);
+            }
+            assert forall |q: (X, X)| #[trigger] between_edges(r, c, b).contains(q) implies bab.remove(e).contains(q) by {
+                lemma_reaches_trans(r, a, c, q.1);
+            }
+            vstd::set_lib::lemma_len_subset(between_edges(r, a, c), bab.remove(e));
+            vstd::set_lib::lemma_len_subset(between_edges(r, c, b), bab.remove(e));
+            lemma_reaches_in_hasse(r, a, c);
+            lemma_reaches_in_hasse(r, c, b);
+            lemma_reaches_trans(h, a, c, b);
+        } else {
+            lemma_between_iff_successor_reaches(r, a, b);
+            lemma_reaches_first_step(r, a, b);
+            assert(h.contains((a, b)));
+            lemma_edge_reaches(h, a, b);
+        }
+    }
+
+    /// No pair of the Hasse diagram is reachable once that pair is removed.
+    pub proof fn lemma_hasse_minimal<X>(r: Set<(X, X)>, p: (X, X))
+        requires hasse_view(r).contains(p),
+        ensures !reaches(hasse_view(r).remove(p), p.0, p.1),
+    {
+        let h = hasse_view(r);
+        let s = h.remove(p);
+        if reaches(s, p.0, p.1) {
+            lemma_reaches_first_step(s, p.0, p.1);
+            let m = choose |m: X| #[trigger] s.contains((p.0, m)) && reaches(s, m, p.1);
+            assert(s.subset_of(r));
+            lemma_reaches_subset(s, r, m, p.1);
+            lemma_edge_reaches(r, p.0, m);
+            assert(reaches(r, p.0, m) && reaches(r, m, p.1));
+        }
+    }
+
+    /// The Hasse diagram of a finite acyclic relation has the same transitive closure.
+    pub proof fn lemma_hasse_closure<X>(r: Set<(X, X)>)
+        requires r.finite(), is_acyclic(r),
+        ensures transitive_closure(hasse_view(r)) == transitive_closure(r),
+    {
+        let h = hasse_view(r);
+        assert(h.subset_of(r));
+        assert forall |p: (X, X)| #[trigger] transitive_closure(h).contains(p) <==> transitive_closure(r).contains(p) by {
+            if reaches(h, p.0, p.1) { lemma_reaches_subset(h, r, p.0, p.1); }
+            if reaches(r, p.0, p.1) { lemma_reaches_in_hasse(r, p.0, p.1); }
+        }
+        assert(transitive_closure(h) =~= transitive_closure(r));
+    }
+
     pub trait RelationStEphTrait<X: StT + Hash, Y: StT + Hash> : 
         View<V = Set<(<X as View>::V, <Y as View>::V)>> + Sized {
 
@@ -119,6 +452,140 @@ verus! {
                 it@.0 == 0int,
                 it@.1.map(|i: int, p: Pair<X, Y>| p@).to_set() == self@,
                 it@.1.no_duplicates();
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn insert(&mut self, a: X, b: Y) -> (inserted: bool)
+            requires valid_key_type_Pair::<X, Y>()
+            ensures
+                self@ == old(self)@.insert((a@, b@)),
+                inserted == !old(self)@.contains((a@, b@));
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn delete(&mut self, a: &X, b: &Y) -> (deleted: bool)
+            requires valid_key_type_Pair::<X, Y>()
+            ensures
+                self@ == old(self)@.remove((a@, b@)),
+                deleted == old(self)@.contains((a@, b@));
+
+        /// APAS: Work Θ(|R| + |S|), Span Θ(1)
+        fn union(&self, other: &RelationStEph<X, Y>) -> (union: Self)
+            requires valid_key_type_Pair::<X, Y>()
+            ensures union@ == self@.union(other@);
+
+        /// APAS: Work Θ(|R| + |S|), Span Θ(1)
+        fn intersection(&self, other: &RelationStEph<X, Y>) -> (intersection: Self)
+            requires valid_key_type_Pair::<X, Y>()
+            ensures intersection@ == self@.intersect(other@);
+
+        /// APAS: Work Θ(|R| + |S|), Span Θ(1)
+        fn difference(&self, other: &RelationStEph<X, Y>) -> (difference: Self)
+            requires valid_key_type_Pair::<X, Y>()
+            ensures difference@ == self@.difference(other@);
+
+        /// Relational selection σ: the pairs satisfying `pred`, whose meaning is `spec_pred`.
+        /// APAS: Work Θ(|R|), Span Θ(1)
+        fn select<F: Fn(&Pair<X, Y>) -> B>(&self, pred: &F, spec_pred: Ghost<spec_fn((X::V, Y::V)) -> bool>) -> (selected: Self)
+            requires
+                valid_key_type_Pair::<X, Y>(),
+                forall |p: &Pair<X, Y>| #[trigger] pred.requires((p,)),
+                forall |p: Pair<X, Y>, keep: B| pred.ensures((&p,), keep) ==> keep == spec_pred@(p@),
+            ensures selected@ == Set::<(X::V, Y::V)>::new(|p: (X::V, Y::V)| self@.contains(p) && spec_pred@(p));
+
+        /// Domain restriction: the pairs whose first component is in `keep`.
+        /// APAS: Work Θ(|R|), Span Θ(1)
+        fn restrict_domain(&self, keep: &SetStEph<X>) -> (restricted: Self)
+            requires valid_key_type_Pair::<X, Y>()
+            ensures restricted@ == Set::<(X::V, Y::V)>::new(|p: (X::V, Y::V)| self@.contains(p) && keep@.contains(p.0));
+
+        /// Range restriction: the pairs whose second component is in `keep`.
+        /// APAS: Work Θ(|R|), Span Θ(1)
+        fn restrict_range(&self, keep: &SetStEph<Y>) -> (restricted: Self)
+            requires valid_key_type_Pair::<X, Y>()
+            ensures restricted@ == Set::<(X::V, Y::V)>::new(|p: (X::V, Y::V)| self@.contains(p) && keep@.contains(p.1));
+
+        /// Projection π₁ onto the first component; same set as `domain`.
+        /// APAS: Work Θ(|R|), Span Θ(1)
+        fn project_domain(&self) -> (projection: SetStEph<X>)
+            requires valid_key_type_Pair::<X, Y>()
+            ensures projection@ == Set::<X::V>::new(|x: X::V| exists |y: Y::V| self@.contains((x, y)));
+
+        /// Projection π₂ onto the second component; same set as `range`.
+        /// APAS: Work Θ(|R|), Span Θ(1)
+        fn project_range(&self) -> (projection: SetStEph<Y>)
+            requires valid_key_type_Pair::<X, Y>()
+            ensures projection@ == Set::<Y::V>::new(|y: Y::V| exists |x: X::V| self@.contains((x, y)));
+
+        /// Natural join on the shared `Y` component: (x, (y, z)) whenever x R y and y S z.
+        /// Hash join: `other` is indexed by its first component, then each pair of `self` probes the index.
+        /// APAS: Work Θ(|R| + |S| + |R ⋈ S|), Span Θ(1)
+        fn join<Z: StT + Hash>(&self, other: &RelationStEph<Y, Z>) -> (joined: RelationStEph<X, Pair<Y, Z>>)
+            requires
+                valid_key_type_Pair::<X, Y>(),
+                valid_key_type_Pair::<Y, Z>(),
+                valid_key_type_Pair::<X, Pair<Y, Z>>(),
+            ensures joined@ == Set::<(X::V, (Y::V, Z::V))>::new(|t: (X::V, (Y::V, Z::V))|
+                self@.contains((t.0, t.1.0)) && other@.contains(t.1));
+    }
+
+    /// Hash index of a relation on its first component: x -> { y | x r y }.
+    /// APAS: Work Θ(|r|), Span Θ(1)
+    pub fn index_by_first<X: StT + Hash, Y: StT + Hash>(r: &RelationStEph<X, Y>) -> (index: HashMapWithViewPlus<X, SetStEph<Y>>)
+        requires valid_key_type_Pair::<X, Y>()
+        ensures index@.map_values(|s: SetStEph<Y>| s@) == group_by_view(r@)
+    {
+        let mut index = HashMapWithViewPlus::<X, SetStEph<Y>>::new();
+        let mut it = r.iter();
+        let ghost pairs_seq = it@.1;
+
+        #[verifier::loop_isolation(false)]
+        loop
+            invariant
+                valid_key_type_Pair::<X, Y>(),
+                it@.0 <= pairs_seq.len(),
+                it@.1 == pairs_seq,
+                pairs_seq.map(|i: int, p: Pair<X, Y>| p@).to_set() == r@,
+                index@.map_values(|s: SetStEph<Y>| s@) ==
+                    group_by_view(pairs_seq.take(it@.0).map(|i: int, p: Pair<X, Y>| p@).to_set()),
+            decreases pairs_seq.len() - it@.0,
+        {
+            let ghost old_index = it@.0;
+            let ghost prefix = pairs_seq.take(old_index).map(|i: int, p: Pair<X, Y>| p@).to_set();
+            let ghost old_map = index@;
+            match it.next() {
+                Some(pair) => {
+                    let Pair(x, y) = pair;
+                    let mut ys = match index.remove(x) {
+                        Some(ys) => ys,
+                        None => SetStEph::empty(),
+                    };
+                    proof {
+                        assert(ys@ =~= Set::new(|y2: Y::V| prefix.contains((x@, y2)))) by {
+                            if old_map.contains_key(x@) {
+                                assert(old_map.map_values(|s: SetStEph<Y>| s@)[x@] == ys@);
+                            } else {
+                                assert forall |y2: Y::V| !prefix.contains((x@, y2)) by {
+                                    if prefix.contains((x@, y2)) {
+                                        assert(group_by_view(prefix).contains_key(x@));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    let _ = ys.insert(y.clone_plus());
+                    let _ = index.insert(x.clone_plus(), ys);
+                    proof {
+                        lemma_take_one_more_extends_the_seq_set_with_view(pairs_seq, old_index);
+                        lemma_group_by_view_insert(prefix, x@, y@);
+                        assert(index@.map_values(|s: SetStEph<Y>| s@) =~=
+                            group_by_view(prefix).insert(x@, Set::new(|y2: Y::V| prefix.contains((x@, y2))).insert(y@)));
+                    }
+                },
+                None => {
+                    proof { lemma_take_full_to_set_with_view(pairs_seq); }
+                    return index;
+                }
+            }
+        }
     }
 
     impl<A: StT + Hash, B: StT + Hash> View for RelationStEph<A, B> {
@@ -252,6 +719,837 @@ verus! {
         fn iter(&self) -> RelationStEphIter<'_, X, Y> {
             RelationStEphIter { inner: self.pairs.iter() }
         }
+
+        fn insert(&mut self, a: X, b: Y) -> B { self.pairs.insert(Pair(a, b)) }
+
+        fn delete(&mut self, a: &X, b: &Y) -> B {
+            let a_clone = a.clone_plus();
+            let b_clone = b.clone_plus();
+            self.pairs.delete(&Pair(a_clone, b_clone))
+        }
+
+        fn union(&self, other: &RelationStEph<X, Y>) -> RelationStEph<X, Y> {
+            RelationStEph { pairs: self.pairs.union(&other.pairs) }
+        }
+
+        fn intersection(&self, other: &RelationStEph<X, Y>) -> RelationStEph<X, Y> {
+            RelationStEph { pairs: self.pairs.intersection(&other.pairs) }
+        }
+
+        fn difference(&self, other: &RelationStEph<X, Y>) -> RelationStEph<X, Y> {
+            RelationStEph { pairs: self.pairs.difference(&other.pairs) }
+        }
+
+        fn select<F: Fn(&Pair<X, Y>) -> B>(&self, pred: &F, spec_pred: Ghost<spec_fn((X::V, Y::V)) -> bool>) -> RelationStEph<X, Y> {
+            let mut out = SetStEph::<Pair<X, Y>>::empty();
+            let mut it = self.iter();
+            let ghost pairs_seq = it@.1;
+            let ghost pairs_view = self@;
+            let ghost sp = spec_pred@;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, Y>(),
+                    it@.0 <= pairs_seq.len(),
+                    it@.1 == pairs_seq,
+                    pairs_seq.map(|i: int, p: Pair<X, Y>| p@).to_set() == pairs_view,
+                    out@ == pairs_seq.take(it@.0).map(|i: int, p: Pair<X, Y>| p@).to_set().filter(sp),
+                decreases pairs_seq.len() - it@.0,
+            {
+                let ghost old_index = it@.0;
+                match it.next() {
+                    Some(pair) => {
+                        proof { lemma_take_one_more_extends_the_seq_set_with_view(pairs_seq, old_index); }
+                        if pred(pair) {
+                            let _ = out.insert(pair.clone_plus());
+                            proof {
+                                assert(out@ =~= pairs_seq.take(old_index + 1).map(|i: int, p: Pair<X, Y>| p@).to_set().filter(sp));
+                            }
+                        } else {
+                            proof {
+                                assert(out@ =~= pairs_seq.take(old_index + 1).map(|i: int, p: Pair<X, Y>| p@).to_set().filter(sp));
+                            }
+                        }
+                    },
+                    None => {
+                        proof {
+                            lemma_take_full_to_set_with_view(pairs_seq);
+                            assert(out@ =~= Set::<(X::V, Y::V)>::new(|p: (X::V, Y::V)| self@.contains(p) && sp(p)));
+                        }
+                        return RelationStEph { pairs: out };
+                    }
+                }
+            }
+        }
+
+        fn restrict_domain(&self, keep: &SetStEph<X>) -> RelationStEph<X, Y> {
+            let in_keep = |p: &Pair<X, Y>| -> (r: B)
+                requires valid_key_type_Pair::<X, Y>()
+                ensures r == keep@.contains(p@.0)
+            { keep.mem(&p.0) };
+            self.select(&in_keep, Ghost(|p: (X::V, Y::V)| keep@.contains(p.0)))
+        }
+
+        fn restrict_range(&self, keep: &SetStEph<Y>) -> RelationStEph<X, Y> {
+            let in_keep = |p: &Pair<X, Y>| -> (r: B)
+                requires valid_key_type_Pair::<X, Y>()
+                ensures r == keep@.contains(p@.1)
+            { keep.mem(&p.1) };
+            self.select(&in_keep, Ghost(|p: (X::V, Y::V)| keep@.contains(p.1)))
+        }
+
+        fn project_domain(&self) -> SetStEph<X> { self.domain() }
+
+        fn project_range(&self) -> SetStEph<Y> { self.range() }
+
+        fn join<Z: StT + Hash>(&self, other: &RelationStEph<Y, Z>) -> RelationStEph<X, Pair<Y, Z>> {
+            // Build phase: index other by its first component, y -> { z | y S z }.
+            let index = index_by_first(other);
+            proof {
+                assert forall |y: Y::V, z: Z::V| #![trigger index@[y]@.contains(z)]
+                    (index@.contains_key(y) && index@[y]@.contains(z)) <==> other@.contains((y, z)) by {
+                    if other@.contains((y, z)) { assert(group_by_view(other@).contains_key(y)); }
+                    if index@.contains_key(y) { assert(index@.map_values(|s: SetStEph<Z>| s@)[y] == index@[y]@); }
+                }
+            }
+
+            // Probe phase: each x R y meets every z in the bucket for y.
+            let mut joined = SetStEph::<Pair<X, Pair<Y, Z>>>::empty();
+            let mut sit = self.iter();
+            let ghost self_seq = sit@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, Y>(),
+                    valid_key_type_Pair::<Y, Z>(),
+                    valid_key_type_Pair::<X, Pair<Y, Z>>(),
+                    sit@.0 <= self_seq.len(),
+                    sit@.1 == self_seq,
+                    self_seq.map(|i: int, p: Pair<X, Y>| p@).to_set() == self@,
+                    forall |y: Y::V, z: Z::V| #![trigger index@[y]@.contains(z)]
+                        (index@.contains_key(y) && index@[y]@.contains(z)) <==> other@.contains((y, z)),
+                    joined@ == Set::<(X::V, (Y::V, Z::V))>::new(|t: (X::V, (Y::V, Z::V))|
+                        self_seq.take(sit@.0).map(|i: int, p: Pair<X, Y>| p@).to_set().contains((t.0, t.1.0))
+                        && other@.contains(t.1)),
+                decreases self_seq.len() - sit@.0,
+            {
+                let ghost old_index = sit@.0;
+                let ghost old_joined = joined@;
+                match sit.next() {
+                    Some(pair) => {
+                        let Pair(x, y) = pair;
+                        proof { lemma_take_one_more_extends_the_seq_set_with_view(self_seq, old_index); }
+                        match index.get(y) {
+                            Some(zs) => {
+                                let mut zit = zs.iter();
+                                let ghost zs_seq = zit@.1;
+
+                                #[verifier::loop_isolation(false)]
+                                loop
+                                    invariant
+                                        valid_key_type_Pair::<X, Pair<Y, Z>>(),
+                                        zit@.0 <= zs_seq.len(),
+                                        zit@.1 == zs_seq,
+                                        zs_seq.map(|i: int, k: Z| k@).to_set() == zs@,
+                                        joined@ == old_joined.union(Set::<(X::V, (Y::V, Z::V))>::new(|t: (X::V, (Y::V, Z::V))|
+                                            t.0 == x@ && t.1.0 == y@
+                                            && zs_seq.take(zit@.0).map(|i: int, k: Z| k@).to_set().contains(t.1.1))),
+                                    decreases zs_seq.len() - zit@.0,
+                                {
+                                    let ghost old_zindex = zit@.0;
+                                    match zit.next() {
+                                        Some(z) => {
+                                            let _ = joined.insert(Pair(x.clone_plus(), Pair(y.clone_plus(), z.clone_plus())));
+                                            proof {
+                                                lemma_take_one_more_extends_the_seq_set_with_view(zs_seq, old_zindex);
+                                                assert(joined@ =~= old_joined.union(Set::<(X::V, (Y::V, Z::V))>::new(|t: (X::V, (Y::V, Z::V))|
+                                                    t.0 == x@ && t.1.0 == y@
+                                                    && zs_seq.take(old_zindex + 1).map(|i: int, k: Z| k@).to_set().contains(t.1.1))));
+                                            }
+                                        },
+                                        None => {
+                                            proof { lemma_take_full_to_set_with_view(zs_seq); }
+                                            break;
+                                        }
+                                    }
+                                }
+                                proof {
+                                    assert(joined@ =~= Set::<(X::V, (Y::V, Z::V))>::new(|t: (X::V, (Y::V, Z::V))|
+                                        self_seq.take(old_index + 1).map(|i: int, p: Pair<X, Y>| p@).to_set().contains((t.0, t.1.0))
+                                        && other@.contains(t.1)));
+                                }
+                            },
+                            None => {
+                                proof {
+                                    assert forall |z: Z::V| !other@.contains((y@, z)) by {
+                                        if other@.contains((y@, z)) { assert(index@[y@]@.contains(z)); }
+                                    }
+                                    assert(joined@ =~= Set::<(X::V, (Y::V, Z::V))>::new(|t: (X::V, (Y::V, Z::V))|
+                                        self_seq.take(old_index + 1).map(|i: int, p: Pair<X, Y>| p@).to_set().contains((t.0, t.1.0))
+                                        && other@.contains(t.1)));
+                                }
+                            },
+                        }
+                    },
+                    None => {
+                        proof {
+                            lemma_take_full_to_set_with_view(self_seq);
+                            assert(joined@ =~= Set::<(X::V, (Y::V, Z::V))>::new(|t: (X::V, (Y::V, Z::V))|
+                                self@.contains((t.0, t.1.0)) && other@.contains(t.1)));
+                        }
+                        return RelationStEph { pairs: joined };
+                    }
+                }
+            }
+        }
+    }
+
+    /// Operations on homogeneous relations R ⊆ X × X.
+    pub trait RelationStEphOrderTrait<X: StT + Hash> :
+        View<V = Set<(<X as View>::V, <X as View>::V)>> + Sized {
+
+        /// Topological sort: order universe so that every x R y has x before y,
+        /// or return a cycle of R when there is no such order.
+        /// Kahn's algorithm, placing a whole layer of sources per round.
+        /// APAS: Work O(|U| (|U| + |R|)), Span O(|U| (|U| + |R|))
+        fn linear_extension(&self, universe: &SetStEph<X>) -> (order: std::result::Result<Vec<X>, Cycle<X>>)
+            requires
+                valid_key_type_Pair::<X, X>(),
+                field_within(self@, universe@),
+            ensures
+                match order {
+                    Ok(order) => is_linear_extension(self@, universe@, order@.map(|i: int, x: X| x@)),
+                    Err(cycle) => is_cycle(self@, cycle@),
+                };
+
+        /// Hasse diagram: the least relation with the same transitive closure.
+        /// APAS: Work O(|U| |R|), Span O(|U| |R|)
+        fn transitive_reduction(&self) -> (reduced: Self)
+            requires
+                valid_key_type_Pair::<X, X>(),
+                self@.finite(),
+                is_acyclic(self@),
+            ensures
+                reduced@ == hasse_view(self@),
+                transitive_closure(reduced@) == transitive_closure(self@),
+                forall |p: (X::V, X::V)| #[trigger] reduced@.contains(p) ==>
+                    transitive_closure(reduced@.remove(p)) != transitive_closure(self@);
+    }
+
+    pub open spec fn successors_in<X: StT + Hash>(index: Map<X::V, SetStEph<X>>, a: X::V) -> Set<X::V> {
+        if index.contains_key(a) { index[a]@ } else { Set::empty() }
+    }
+
+    pub proof fn lemma_successors_in<X: StT + Hash>(index: Map<X::V, SetStEph<X>>, r: Set<(X::V, X::V)>, a: X::V)
+        requires index.map_values(|s: SetStEph<X>| s@) == group_by_view(r),
+        ensures successors_in(index, a) == succ_view(r, a),
+    {
+        if index.contains_key(a) {
+            assert(index.map_values(|s: SetStEph<X>| s@)[a] == index[a]@);
+            assert(index[a]@ =~= succ_view(r, a));
+        } else {
+            assert forall |y: X::V| !r.contains((a, y)) by {
+                if r.contains((a, y)) { assert(group_by_view(r).contains_key(a)); }
+            }
+            assert(succ_view(r, a) =~= Set::<X::V>::empty());
+        }
+    }
+
+    /// Push every successor of w that is not yet visited, marking it visited.
+    /// APAS: Work Θ(|succ(w)|), Span Θ(|succ(w)|)
+    fn push_unvisited_successors<X: StT + Hash>(
+        index: &HashMapWithViewPlus<X, SetStEph<X>>, w: &X, visited: &mut SetStEph<X>, stack: &mut Vec<X>)
+        requires valid_key_type::<X>(),
+        ensures
+            visited@ == old(visited)@.union(successors_in(index@, w@)),
+            old(stack)@.len() <= stack@.len(),
+            forall |i: int| #![trigger stack@[i]] 0 <= i < old(stack)@.len() ==> stack@[i] == old(stack)@[i],
+            forall |i: int| #![trigger stack@[i]] old(stack)@.len() <= i < stack@.len() ==>
+                visited@.contains(stack@[i]@) && !old(visited)@.contains(stack@[i]@),
+            forall |y: X::V| #[trigger] visited@.contains(y) && !old(visited)@.contains(y) ==>
+                exists |i: int| #![trigger stack@[i]] old(stack)@.len() <= i < stack@.len() && stack@[i]@ == y,
+    {
+        match index.get(w) {
+            Some(ys) => {
+                let mut it = ys.iter();
+                let ghost ys_seq = it@.1;
+
+                #[verifier::loop_isolation(false)]
+                loop
+                    invariant
+                        valid_key_type::<X>(),
+                        it@.0 <= ys_seq.len(),
+                        it@.1 == ys_seq,
+                        ys_seq.map(|i: int, k: X| k@).to_set() == ys@,
+                        visited@ == old(visited)@.union(ys_seq.take(it@.0).map(|i: int, k: X| k@).to_set()),
+                        old(stack)@.len() <= stack@.len(),
+                        forall |i: int| #![trigger stack@[i]] 0 <= i < old(stack)@.len() ==> stack@[i] == old(stack)@[i],
+                        forall |i: int| #![trigger stack@[i]] old(stack)@.len() <= i < stack@.len() ==>
+                            visited@.contains(stack@[i]@) && !old(visited)@.contains(stack@[i]@),
+                        forall |y: X::V| #[trigger] visited@.contains(y) && !old(visited)@.contains(y) ==>
+                            exists |i: int| #![trigger stack@[i]] old(stack)@.len() <= i < stack@.len() && stack@[i]@ == y,
+                    decreases ys_seq.len() - it@.0,
+                {
+                    let ghost old_index = it@.0;
+                    let ghost prev_stack = stack@;
+                    match it.next() {
+                        Some(y) => {
+                            if !visited.mem(y) {
+                                let _ = visited.insert(y.clone_plus());
+                                stack.push(y.clone_plus());
+                                proof {
+                                    assert(stack@[prev_stack.len() as int]@ == y@);
+                                    assert forall |i: int| #![trigger stack@[i]] 0 <= i < prev_stack.len() implies stack@[i] == prev_stack[i] by {}
+                                }
+                            }
+                            proof {
+                                lemma_take_one_more_extends_the_seq_set_with_view(ys_seq, old_index);
+                                assert(visited@ =~= old(visited)@.union(ys_seq.take(old_index + 1).map(|i: int, k: X| k@).to_set()));
+                            }
+                        },
+                        None => {
+                            proof { lemma_take_full_to_set_with_view(ys_seq); }
+                            break;
+                        }
+                    }
+                }
+            },
+            None => {
+                proof { assert(visited@ =~= old(visited)@.union(Set::<X::V>::empty())); }
+            },
+        }
+    }
+
+    /// Everything reachable from v in one or more steps, by depth first search over the index.
+    /// APAS: Work O(|r|), Span O(|r|)
+    fn descendants<X: StT + Hash>(r: &RelationStEph<X, X>, index: &HashMapWithViewPlus<X, SetStEph<X>>, v: &X) -> (desc: SetStEph<X>)
+        requires
+            valid_key_type_Pair::<X, X>(),
+            r@.finite(),
+            index@.map_values(|s: SetStEph<X>| s@) == group_by_view(r@),
+        ensures desc@ == descendants_view(r@, v@),
+    {
+        let mut visited = SetStEph::<X>::empty();
+        let mut stack: Vec<X> = Vec::new();
+        push_unvisited_successors(index, v, &mut visited, &mut stack);
+        proof {
+            lemma_range_finite(r@);
+            lemma_successors_in(index@, r@, v@);
+            assert forall |y: X::V| #[trigger] visited@.contains(y) implies descendants_view(r@, v@).contains(y) by {
+                lemma_edge_reaches(r@, v@, y);
+            }
+        }
+        let ghost bound = range_view(r@).len();
+
+        #[verifier::loop_isolation(false)]
+        loop
+            invariant
+                valid_key_type_Pair::<X, X>(),
+                index@.map_values(|s: SetStEph<X>| s@) == group_by_view(r@),
+                range_view(r@).finite(),
+                bound == range_view(r@).len(),
+                succ_view(r@, v@).subset_of(visited@),
+                visited@.subset_of(descendants_view(r@, v@)),
+                forall |i: int| #![trigger stack@[i]] 0 <= i < stack@.len() ==> visited@.contains(stack@[i]@),
+                // Anything visited and off the stack has had its successors visited.
+                forall |w: X::V| #[trigger] visited@.contains(w) ==>
+                    (exists |i: int| #![trigger stack@[i]] 0 <= i < stack@.len() && stack@[i]@ == w)
+                    || succ_view(r@, w).subset_of(visited@),
+            decreases bound - visited@.len(), stack@.len(),
+        {
+            let ghost old_visited = visited@;
+            let ghost old_stack = stack@;
+            match stack.pop() {
+                Some(w) => {
+                    push_unvisited_successors(index, &w, &mut visited, &mut stack);
+                    proof {
+                        lemma_successors_in(index@, r@, w@);
+                        assert forall |y: X::V| #[trigger] visited@.contains(y) implies descendants_view(r@, v@).contains(y) by {
+                            if !old_visited.contains(y) {
+                                lemma_edge_reaches(r@, w@, y);
+                                lemma_reaches_trans(r@, v@, w@, y);
+                            }
+                        }
+                        assert forall |u: X::V| #[trigger] visited@.contains(u) implies
+                            (exists |i: int| #![trigger stack@[i]] 0 <= i < stack@.len() && stack@[i]@ == u)
+                            || succ_view(r@, u).subset_of(visited@) by {
+                            if old_visited.contains(u) && u != w@ && !succ_view(r@, u).subset_of(old_visited) {
+                                let i = choose |i: int| #![trigger old_stack[i]] 0 <= i < old_stack.len() && old_stack[i]@ == u;
+                                assert(i < old_stack.len() - 1);
+                                assert(stack@[i] == old_stack[i]);
+                            }
+                        }
+                        // Either the visited set grew, or nothing was pushed and the stack shrank.
+                        assert forall |y: X::V| #[trigger] visited@.contains(y) implies range_view(r@).contains(y) by {
+                            lemma_reaches_last_step(r@, v@, y);
+                        }
+                        vstd::set_lib::lemma_len_subset(visited@, range_view(r@));
+                        if stack@.len() >= old_stack.len() {
+                            let y = stack@[old_stack.len() - 1]@;
+                            assert(visited@.contains(y) && !old_visited.contains(y));
+                            assert(old_visited.subset_of(visited@.remove(y)));
+                            vstd::set_lib::lemma_len_subset(old_visited, visited@.remove(y));
+                        } else {
+                            assert(visited@ =~= old_visited);
+                        }
+                    }
+                },
+                None => {
+                    proof {
+                        assert forall |u: X::V, y: X::V| #![trigger visited@.contains(u), r@.contains((u, y))]
+                            visited@.contains(u) && r@.contains((u, y)) implies visited@.contains(y) by {
+                            assert(succ_view(r@, u).contains(y));
+                        }
+                        assert forall |w: X::V| #[trigger] descendants_view(r@, v@).contains(w) implies visited@.contains(w) by {
+                            lemma_closed_contains_reaches(r@, visited@, v@, w);
+                        }
+                        assert(visited@ =~= descendants_view(r@, v@));
+                    }
+                    return visited;
+                }
+            }
+        }
+    }
+
+    /// Whether some successor s of a has s r+ b, caching descendant sets in desc.
+    /// APAS: Work O(|succ(a)| |r|), Span O(|succ(a)| |r|)
+    fn some_successor_reaches<X: StT + Hash>(
+        r: &RelationStEph<X, X>, index: &HashMapWithViewPlus<X, SetStEph<X>>,
+        desc: &mut HashMapWithViewPlus<X, SetStEph<X>>, a: &X, b: &X) -> (found: bool)
+        requires
+            valid_key_type_Pair::<X, X>(),
+            r@.finite(),
+            index@.map_values(|s: SetStEph<X>| s@) == group_by_view(r@),
+            forall |k: X::V| #[trigger] old(desc)@.contains_key(k) ==> old(desc)@[k]@ == descendants_view(r@, k),
+        ensures
+            forall |k: X::V| #[trigger] desc@.contains_key(k) ==> desc@[k]@ == descendants_view(r@, k),
+            found == exists |s: X::V| #[trigger] r@.contains((a@, s)) && reaches(r@, s, b@),
+    {
+        proof { lemma_successors_in(index@, r@, a@); }
+        match index.get(a) {
+            Some(succs) => {
+                let mut it = succs.iter();
+                let ghost s_seq = it@.1;
+
+                #[verifier::loop_isolation(false)]
+                loop
+                    invariant
+                        valid_key_type_Pair::<X, X>(),
+                        it@.0 <= s_seq.len(),
+                        it@.1 == s_seq,
+                        s_seq.map(|i: int, k: X| k@).to_set() == succs@,
+                        succs@ == succ_view(r@, a@),
+                        forall |k: X::V| #[trigger] desc@.contains_key(k) ==> desc@[k]@ == descendants_view(r@, k),
+                        forall |i: int| #![trigger s_seq[i]] 0 <= i < it@.0 ==> !reaches(r@, s_seq[i]@, b@),
+                    decreases s_seq.len() - it@.0,
+                {
+                    let ghost old_index = it@.0;
+                    match it.next() {
+                        Some(s) => {
+                            let hit = match desc.get(s) {
+                                Some(d) => d.mem(b),
+                                None => {
+                                    let d = descendants(r, index, s);
+                                    let hit = d.mem(b);
+                                    let _ = desc.insert(s.clone_plus(), d);
+                                    hit
+                                },
+                            };
+                            if hit {
+                                proof {
+                                    lemma_seq_index_in_map_to_set(s_seq, old_index);
+                                    assert(r@.contains((a@, s@)) && reaches(r@, s@, b@));
+                                }
+                                return true;
+                            }
+                        },
+                        None => {
+                            proof {
+                                assert forall |s: X::V| #[trigger] r@.contains((a@, s)) implies !reaches(r@, s, b@) by {
+                                    assert(succ_view(r@, a@).contains(s));
+                                    lemma_map_to_set_contains_index(s_seq, s);
+                                }
+                            }
+                            return false;
+                        }
+                    }
+                }
+            },
+            None => {
+                proof {
+                    assert forall |s: X::V| !r@.contains((a@, s)) by {
+                        if r@.contains((a@, s)) { assert(succ_view(r@, a@).contains(s)); }
+                    }
+                }
+                false
+            },
+        }
+    }
+
+    /// Some unplaced a with a r b, given that one exists.
+    /// APAS: Work Θ(|r|), Span Θ(1)
+    fn unplaced_predecessor<X: StT + Hash>(r: &RelationStEph<X, X>, placed: &SetStEph<X>, b: &X) -> (a: X)
+        requires
+            valid_key_type_Pair::<X, X>(),
+            exists |a: X::V| !placed@.contains(a) && r@.contains((a, b@)),
+        ensures
+            !placed@.contains(a@),
+            r@.contains((a@, b@)),
+    {
+        let mut it = r.iter();
+        let ghost pairs_seq = it@.1;
+
+        #[verifier::loop_isolation(false)]
+        loop
+            invariant
+                valid_key_type_Pair::<X, X>(),
+                it@.0 <= pairs_seq.len(),
+                it@.1 == pairs_seq,
+                pairs_seq.map(|i: int, p: Pair<X, X>| p@).to_set() == r@,
+                forall |k: int| #![trigger pairs_seq[k]] 0 <= k < it@.0 ==>
+                    placed@.contains(pairs_seq[k]@.0) || pairs_seq[k]@.1 != b@,
+            decreases pairs_seq.len() - it@.0,
+        {
+            match it.next() {
+                Some(pair) => {
+                    let Pair(a, b2) = pair;
+                    if !placed.mem(a) && feq(b2, b) {
+                        proof { lemma_seq_index_in_map_to_set(pairs_seq, it@.0 - 1); }
+                        return a.clone_plus();
+                    }
+                },
+                None => {
+                    proof {
+                        let a = choose |a: X::V| !placed@.contains(a) && r@.contains((a, b@));
+                        lemma_map_to_set_contains_index(pairs_seq, (a, b@));
+                        let k = choose |k: int| #![trigger pairs_seq[k]] 0 <= k < pairs_seq.len() && pairs_seq[k]@ == (a, b@);
+                        assert(false);
+                    }
+                    return unreached();
+                }
+            }
+        }
+    }
+
+    /// Walk unplaced predecessors back from start until a vertex repeats; the repeat closes a cycle.
+    /// APAS: Work O(|U| |r|), Span O(|U| |r|)
+    fn find_cycle<X: StT + Hash>(r: &RelationStEph<X, X>, universe: &SetStEph<X>, placed: &SetStEph<X>, start: &X) -> (cycle: Cycle<X>)
+        requires
+            valid_key_type_Pair::<X, X>(),
+            field_within(r@, universe@),
+            universe@.contains(start@),
+            !placed@.contains(start@),
+            forall |b: X::V| #[trigger] universe@.contains(b) && !placed@.contains(b) ==>
+                exists |a: X::V| !placed@.contains(a) && r@.contains((a, b)),
+        ensures
+            is_cycle(r@, cycle@),
+    {
+        // walk[i + 1] r walk[i]; seen maps each walked vertex to its position.
+        let mut walk: Vec<X> = Vec::new();
+        walk.push(start.clone_plus());
+        let mut seen = HashMapWithViewPlus::<X, usize>::new();
+        let _ = seen.insert(start.clone_plus(), 0);
+        proof {
+            assert(walk@.map(|i: int, x: X| x@).to_set() =~= set![start@]);
+            assert(seen@.dom() =~= set![start@]);
+        }
+
+        #[verifier::loop_isolation(false)]
+        loop
+            invariant
+                valid_key_type_Pair::<X, X>(),
+                0 < walk@.len() <= universe@.len(),
+                forall |i: int| #![trigger walk@[i]] 0 <= i < walk@.len() ==>
+                    universe@.contains(walk@[i]@) && !placed@.contains(walk@[i]@),
+                forall |i: int| #![trigger walk@[i]] 0 <= i < walk@.len() - 1 ==>
+                    r@.contains((walk@[i + 1]@, walk@[i]@)),
+                walk@.map(|i: int, x: X| x@).no_duplicates(),
+                seen@.dom() == walk@.map(|i: int, x: X| x@).to_set(),
+                forall |v: X::V| #[trigger] seen@.contains_key(v) ==>
+                    seen@[v] < walk@.len() && walk@[seen@[v] as int]@ == v,
+            decreases universe@.len() - walk@.len(),
+        {
+            let n = walk.len();
+            let pred = unplaced_predecessor(r, placed, &walk[n - 1]);
+            match seen.get(&pred) {
+                Some(k) => {
+                    // pred = walk[k] r walk[n - 1] r ... r walk[k]: emit walk[n - 1], ..., walk[k].
+                    let k = *k;
+                    let mut vertices: Vec<X> = Vec::new();
+                    let mut i = n;
+                    while i > k
+                        invariant
+                            k < n == walk@.len(),
+                            k <= i <= n,
+                            vertices@.len() == n - i,
+                            forall |t: int| #![trigger vertices@[t]] 0 <= t < vertices@.len() ==>
+                                vertices@[t]@ == walk@[n - 1 - t]@,
+                        decreases i,
+                    {
+                        i = i - 1;
+                        vertices.push(walk[i].clone_plus());
+                    }
+                    let cycle = Cycle { vertices };
+                    proof {
+                        let c = cycle@;
+                        assert forall |t: int| #![trigger c[t]] 0 <= t < c.len() implies
+                            r@.contains((c[t], c[(t + 1) % c.len()])) by {
+                            if t + 1 < c.len() {
+                                assert(walk@[(n - 2 - t) + 1] == walk@[n - 1 - t]);
+                            } else {
+                                assert((t + 1) % c.len() == 0);
+                            }
+                        }
+                    }
+                    return cycle;
+                },
+                None => {
+                    let ghost old_walk = walk@;
+                    let ghost pred_view = pred@;
+                    let _ = seen.insert(pred.clone_plus(), n);
+                    walk.push(pred);
+                    proof {
+                        assert(walk@.map(|i: int, x: X| x@) =~= old_walk.map(|i: int, x: X| x@).push(pred_view));
+                        lemma_push_not_contains_to_set(old_walk.map(|i: int, x: X| x@), pred_view);
+                        assert(seen@.dom() =~= walk@.map(|i: int, x: X| x@).to_set());
+                        walk@.map(|i: int, x: X| x@).unique_seq_to_set();
+                        assert(walk@.map(|i: int, x: X| x@).to_set().subset_of(universe@));
+                        vstd::set_lib::lemma_len_subset(walk@.map(|i: int, x: X| x@).to_set(), universe@);
+                    }
+                },
+            }
+        }
+    }
+
+    impl<X: StT + Hash> RelationStEphOrderTrait<X> for RelationStEph<X, X> {
+        fn linear_extension(&self, universe: &SetStEph<X>) -> std::result::Result<Vec<X>, Cycle<X>> {
+            let mut order: Vec<X> = Vec::new();
+            let mut placed = SetStEph::<X>::empty();
+            proof { assert(order@.map(|i: int, x: X| x@).to_set() =~= Set::<X::V>::empty()); }
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, X>(),
+                    field_within(self@, universe@),
+                    placed@ == order@.map(|i: int, x: X| x@).to_set(),
+                    order@.map(|i: int, x: X| x@).no_duplicates(),
+                    placed@.subset_of(universe@),
+                    // Every predecessor of a placed vertex was placed before it.
+                    forall |j: int, a: X::V| 0 <= j < order@.len() && #[trigger] self@.contains((a, order@[j]@)) ==>
+                        exists |i: int| #![trigger order@[i]] 0 <= i < j && order@[i]@ == a,
+                decreases universe@.len() - placed@.len(),
+            {
+                if placed.size() == universe.size() {
+                    proof {
+                        assert(placed@ =~= universe@) by {
+                            if exists |u: X::V| universe@.contains(u) && !placed@.contains(u) {
+                                let u = choose |u: X::V| universe@.contains(u) && !placed@.contains(u);
+                                vstd::set_lib::lemma_len_subset(placed@, universe@.remove(u));
+                            }
+                        }
+                        let ov = order@.map(|i: int, x: X| x@);
+                        assert forall |i: int, j: int| #![trigger self@.contains((ov[i], ov[j]))]
+                            0 <= i < ov.len() && 0 <= j < ov.len() && self@.contains((ov[i], ov[j])) implies i < j by {
+                            let i2 = choose |i2: int| #![trigger order@[i2]] 0 <= i2 < j && order@[i2]@ == ov[i];
+                            assert(ov[i2] == ov[i]);
+                        }
+                    }
+                    return Ok(order);
+                }
+
+                // blocked = { b | a R b for some unplaced a }; unplaced vertices outside it are sources.
+                let not_placed = |p: &Pair<X, X>| -> (r: B)
+                    requires valid_key_type_Pair::<X, X>()
+                    ensures r == !placed@.contains(p@.0)
+                { !placed.mem(&p.0) };
+                let blocked = self.select(&not_placed, Ghost(|p: (X::V, X::V)| !placed@.contains(p.0))).range();
+
+                let ghost round_start = order@.len();
+                let ghost placed_start = placed@;
+                let mut progressed = false;
+                let mut uit = universe.iter();
+                let ghost u_seq = uit@.1;
+
+                #[verifier::loop_isolation(false)]
+                loop
+                    invariant
+                        valid_key_type_Pair::<X, X>(),
+                        uit@.0 <= u_seq.len(),
+                        uit@.1 == u_seq,
+                        u_seq.map(|i: int, x: X| x@).to_set() == universe@,
+                        u_seq.no_duplicates(),
+                        placed@ == order@.map(|i: int, x: X| x@).to_set(),
+                        order@.map(|i: int, x: X| x@).no_duplicates(),
+                        placed@.subset_of(universe@),
+                        placed_start.subset_of(placed@),
+                        round_start <= order@.len(),
+                        placed_start == order@.take(round_start as int).map(|i: int, x: X| x@).to_set(),
+                        progressed == (order@.len() > round_start),
+                        !progressed ==> placed@ == placed_start,
+                        forall |j: int, a: X::V| 0 <= j < round_start && #[trigger] self@.contains((a, order@[j]@)) ==>
+                            exists |i: int| #![trigger order@[i]] 0 <= i < j && order@[i]@ == a,
+                        forall |j: int, a: X::V| round_start <= j < order@.len() && #[trigger] self@.contains((a, order@[j]@)) ==>
+                            placed_start.contains(a),
+                        forall |k: int| #![trigger u_seq[k]] 0 <= k < uit@.0 ==>
+                            placed@.contains(u_seq[k]@) || blocked@.contains(u_seq[k]@),
+                    decreases u_seq.len() - uit@.0,
+                {
+                    let ghost old_index = uit@.0;
+                    match uit.next() {
+                        Some(x) => {
+                            if !placed.mem(x) && !blocked.mem(x) {
+                                let ghost old_order = order@;
+                                order.push(x.clone_plus());
+                                let _ = placed.insert(x.clone_plus());
+                                progressed = true;
+                                proof {
+                                    lemma_seq_index_in_map_to_set(u_seq, old_index);
+                                    assert(order@.map(|i: int, x: X| x@) =~= old_order.map(|i: int, x: X| x@).push(x@));
+                                    lemma_push_not_contains_to_set(old_order.map(|i: int, x: X| x@), x@);
+                                    assert(order@.take(round_start as int) =~= old_order.take(round_start as int));
+                                    assert forall |a: X::V| #[trigger] self@.contains((a, x@)) implies placed_start.contains(a) by {
+                                        if !placed_start.contains(a) { assert(blocked@.contains(x@)); }
+                                    }
+                                    assert forall |j: int| 0 <= j < old_order.len() implies #[trigger] order@[j] == old_order[j] by {}
+                                }
+                            }
+                        },
+                        None => { break; }
+                    }
+                }
+
+                proof {
+                    // Placed-before holds for the new layer: its predecessors were placed in earlier rounds.
+                    assert forall |j: int, a: X::V| 0 <= j < order@.len() && #[trigger] self@.contains((a, order@[j]@)) implies
+                        exists |i: int| #![trigger order@[i]] 0 <= i < j && order@[i]@ == a by {
+                        if j >= round_start {
+                            let ot = order@.take(round_start as int);
+                            lemma_map_to_set_contains_index(ot, a);
+                            let i = choose |i: int| #![trigger ot[i]] 0 <= i < ot.len() && ot[i]@ == a;
+                            assert(order@[i] == ot[i]);
+                        }
+                    }
+                }
+
+                if !progressed {
+                    // Every unplaced vertex has an unplaced predecessor, so walking backwards must repeat.
+                    proof {
+                        lemma_take_full_to_set_with_view(u_seq);
+                        assert forall |b: X::V| #[trigger] universe@.contains(b) && !placed@.contains(b) implies
+                            exists |a: X::V| !placed@.contains(a) && self@.contains((a, b)) by {
+                            lemma_map_to_set_contains_index(u_seq, b);
+                            let k = choose |k: int| #![trigger u_seq[k]] 0 <= k < u_seq.len() && u_seq[k]@ == b;
+                            assert(blocked@.contains(b));
+                        }
+                    }
+                    let start = unplaced_member(universe, &placed);
+                    return Err(find_cycle(self, universe, &placed, &start));
+                }
+            }
+        }
+
+        fn transitive_reduction(&self) -> RelationStEph<X, X> {
+            let index = index_by_first(self);
+            // desc caches s -> { w | s R+ w }, filled in as successors are probed.
+            let mut desc = HashMapWithViewPlus::<X, SetStEph<X>>::new();
+            let mut reduced = SetStEph::<Pair<X, X>>::empty();
+            let mut it = self.iter();
+            let ghost pairs_seq = it@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Pair::<X, X>(),
+                    self@.finite(),
+                    index@.map_values(|s: SetStEph<X>| s@) == group_by_view(self@),
+                    forall |k: X::V| #[trigger] desc@.contains_key(k) ==> desc@[k]@ == descendants_view(self@, k),
+                    it@.0 <= pairs_seq.len(),
+                    it@.1 == pairs_seq,
+                    pairs_seq.map(|i: int, p: Pair<X, X>| p@).to_set() == self@,
+                    reduced@ == pairs_seq.take(it@.0).map(|i: int, p: Pair<X, X>| p@).to_set().intersect(hasse_view(self@)),
+                decreases pairs_seq.len() - it@.0,
+            {
+                let ghost old_index = it@.0;
+                match it.next() {
+                    Some(pair) => {
+                        let Pair(a, b) = pair;
+                        // a R b is redundant iff some other route a R s R* b exists.
+                        let redundant = some_successor_reaches(self, &index, &mut desc, a, b);
+                        if !redundant {
+                            let _ = reduced.insert(pair.clone_plus());
+                        }
+                        proof {
+                            lemma_between_iff_successor_reaches(self@, a@, b@);
+                            lemma_seq_index_in_map_to_set(pairs_seq, old_index);
+                            lemma_take_one_more_extends_the_seq_set_with_view(pairs_seq, old_index);
+                            assert(reduced@ =~= pairs_seq.take(old_index + 1).map(|i: int, p: Pair<X, X>| p@).to_set().intersect(hasse_view(self@)));
+                        }
+                    },
+                    None => {
+                        let reduced = RelationStEph { pairs: reduced };
+                        proof {
+                            lemma_take_full_to_set_with_view(pairs_seq);
+                            assert(reduced@ =~= hasse_view(self@));
+                            lemma_hasse_closure(self@);
+                            assert forall |p: (X::V, X::V)| #[trigger] reduced@.contains(p) implies
+                                transitive_closure(reduced@.remove(p)) != transitive_closure(self@) by {
+                                lemma_hasse_minimal(self@, p);
+                                lemma_edge_reaches(self@, p.0, p.1);
+                                assert(transitive_closure(self@).contains(p));
+                                assert(!transitive_closure(reduced@.remove(p)).contains(p));
+                            }
+                        }
+                        return reduced;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Some member of universe not in placed, given that placed is a proper subset.
+    /// APAS: Work Θ(|U|), Span Θ(1)
+    fn unplaced_member<X: StT + Hash>(universe: &SetStEph<X>, placed: &SetStEph<X>) -> (x: X)
+        requires
+            valid_key_type::<X>(),
+            exists |u: X::V| universe@.contains(u) && !placed@.contains(u),
+        ensures
+            universe@.contains(x@),
+            !placed@.contains(x@),
+    {
+        let mut it = universe.iter();
+        let ghost u_seq = it@.1;
+
+        #[verifier::loop_isolation(false)]
+        loop
+            invariant
+                valid_key_type::<X>(),
+                it@.0 <= u_seq.len(),
+                it@.1 == u_seq,
+                u_seq.map(|i: int, x: X| x@).to_set() == universe@,
+                forall |k: int| #![trigger u_seq[k]] 0 <= k < it@.0 ==> placed@.contains(u_seq[k]@),
+            decreases u_seq.len() - it@.0,
+        {
+            match it.next() {
+                Some(x) => {
+                    if !placed.mem(x) {
+                        proof { lemma_seq_index_in_map_to_set(u_seq, it@.0 - 1); }
+                        return x.clone_plus();
+                    }
+                },
+                None => {
+                    proof {
+                        let u = choose |u: X::V| universe@.contains(u) && !placed@.contains(u);
+                        lemma_map_to_set_contains_index(u_seq, u);
+                        let k = choose |k: int| #![trigger u_seq[k]] 0 <= k < u_seq.len() && u_seq[k]@ == u;
+                        assert(false);
+                    }
+                    return unreached();
+                }
+            }
+        }
     }
 
     impl<A: StT + Hash, B: StT + Hash> std::hash::Hash for RelationStEph<A, B> {
@@ -272,6 +1570,14 @@ verus! {
         }};
     }
 
+    impl<A: StT + Hash + Send + Sync, B: StT + Hash + Send + Sync> MtT for RelationStEph<A, B> {
+        type Inner = RelationStEph<A, B>;
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { self@ == other@ }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { self@ == inner@ }
+        fn clone_mt(&self) -> (clone: Self) { self.clone() }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
+    }
+
   } // verus!
 
     impl<A: StT + Hash, B: StT + Hash> PartialEq for RelationStEph<A, B> {
@@ -286,6 +1592,10 @@ verus! {
         fn fmt(&self, f: &mut Formatter<'_>) -> Result { std::fmt::Display::fmt(&self.pairs, f) }
     }
 
+    impl<X: StT + Hash> Debug for Cycle<X> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result { f.debug_tuple("Cycle").field(&self.vertices).finish() }
+    }
+
     // Implement std::iter::Iterator for RelationStEphIter to enable standard iteration methods
     impl<'a, A: StT + Hash, B: StT + Hash> std::iter::Iterator for RelationStEphIter<'a, A, B> {
         type Item = &'a crate::Types::Types::Pair<A, B>;
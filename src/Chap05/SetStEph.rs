@@ -116,6 +116,13 @@ verus! {
                 self@ == old(self)@.insert(x@),
                 inserted == !old(self)@.contains(x@);
 
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn delete(&mut self, x: &T)          -> (deleted: bool)
+            requires valid_key_type::<T>()
+            ensures
+                self@ == old(self)@.remove(x@),
+                deleted == old(self)@.contains(x@);
+
         /// APAS: Work Θ(|a| + |b|), Span Θ(1)
         fn union(&self, s2: &SetStEph<T>) -> (union: Self)
             requires 
@@ -128,6 +135,16 @@ verus! {
             requires valid_key_type::<T>()
             ensures intersection@ == self@.intersect(s2@);
 
+        /// APAS: Work Θ(|a| + |b|), Span Θ(1)
+        fn difference(&self, s2: &SetStEph<T>) -> (difference: Self)
+            requires valid_key_type::<T>()
+            ensures difference@ == self@.difference(s2@);
+
+        /// APAS: Work Θ(|a|), Span Θ(1)
+        fn subset_of(&self, s2: &SetStEph<T>) -> (subset: bool)
+            requires valid_key_type::<T>()
+            ensures subset == self@.subset_of(s2@);
+
         fn EltCrossSet<U: StT + Hash + Clone>(a: &T, s2: &SetStEph<U>) -> (product: SetStEph<Pair<T, U>>)
             requires 
               valid_key_type::<T>(),
@@ -190,6 +207,18 @@ verus! {
         open spec fn view(&self) -> Self::V { self.elements@ }
     }
 
+    /// Two sets with the same contents are the same set (the hash set's layout is not observable),
+    /// provided equal views mean equal elements.
+    pub broadcast proof fn axiom_SetStEph_view_injective<T: StT + Hash>(s1: SetStEph<T>, s2: SetStEph<T>)
+        requires
+            valid_key_type::<T>(),
+            #[trigger] s1@ == #[trigger] s2@,
+        ensures
+            s1 == s2,
+    {
+        admit();
+    }
+
     impl<T: StT + Hash> Clone for SetStEph<T> {
         fn clone(&self) -> (clone: Self)
             ensures clone@ == self@
@@ -250,6 +279,9 @@ verus! {
         fn insert(&mut self, x: T) -> (inserted: bool)
         { self.elements.insert(x) }
 
+        fn delete(&mut self, x: &T) -> (deleted: bool)
+        { self.elements.remove(x) }
+
         fn union(&self, s2: &SetStEph<T>) -> (union: SetStEph<T>)
         {
             let mut union = self.clone_plus();
@@ -326,6 +358,78 @@ verus! {
             
             intersection
         }
+
+        fn difference(&self, s2: &SetStEph<T>) -> (difference: SetStEph<T>)
+        {
+            let mut difference = SetStEph::empty();
+            let mut it = self.iter();
+            let ghost s1_view = self@;
+            let ghost s2_view = s2@;
+            let ghost s1_seq = it@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type::<T>(),
+                    it@.0 <= s1_seq.len(),
+                    it@.1 == s1_seq,
+                    s1_seq.map(|i: int, k: T| k@).to_set() == s1_view,
+                    difference@ == s1_seq.take(it@.0).map(|i: int, k: T| k@).to_set().difference(s2_view),
+                decreases s1_seq.len() - it@.0,
+            {
+                let ghost old_index = it@.0;
+
+                match it.next() {
+                    Some(s1mem) => {
+                        proof { lemma_take_one_more_difference(s1_seq, s2_view, old_index); }
+
+                        if !s2.mem(s1mem) {
+                            let s1mem_clone = s1mem.clone_plus();
+                            let _ = difference.insert(s1mem_clone);
+                        }
+                    },
+                    None => {
+                        proof { lemma_take_full_to_set_with_view(s1_seq); }
+                        break;
+                    }
+                }
+            }
+
+            difference
+        }
+
+        fn subset_of(&self, s2: &SetStEph<T>) -> (subset: bool) {
+            let mut it = self.iter();
+            let ghost s1_seq = it@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type::<T>(),
+                    it@.0 <= s1_seq.len(),
+                    it@.1 == s1_seq,
+                    s1_seq.map(|i: int, k: T| k@).to_set() == self@,
+                    forall |k: int| #![trigger s1_seq[k]] 0 <= k < it@.0 ==> s2@.contains(s1_seq[k]@),
+                decreases s1_seq.len() - it@.0,
+            {
+                match it.next() {
+                    Some(s1mem) => {
+                        if !s2.mem(s1mem) {
+                            proof { lemma_seq_index_in_map_to_set(s1_seq, it@.0 - 1); }
+                            return false;
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |x: T::V| self@.contains(x) implies #[trigger] s2@.contains(x) by {
+                                lemma_map_to_set_contains_index(s1_seq, x);
+                            }
+                        }
+                        return true;
+                    }
+                }
+            }
+        }
         
         fn CartesianProduct<U: StT + Hash + Clone>(&self, s2: &SetStEph<U>) -> (product: SetStEph<Pair<T, U>>)
         {
@@ -565,6 +669,14 @@ verus! {
             __s
         }};
     }
+    impl<T: crate::Types::Types::StT + std::hash::Hash + Send + Sync> crate::Types::Types::MtT for SetStEph<T> {
+        type Inner = SetStEph<T>;
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { self@ == other@ }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { self@ == inner@ }
+        fn clone_mt(&self) -> (clone: Self) { self.clone() }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
+    }
+
   } // verus!
 
     impl<T: StT + Hash> PartialEq for SetStEph<T> {
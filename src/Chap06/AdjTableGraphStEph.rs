@@ -0,0 +1,437 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Chapter 6 ephemeral adjacency-table digraph: each vertex maps to its out-neighbor set,
+//! so N+(v) is a Θ(1) lookup instead of a scan of the arcs.
+
+pub mod AdjTableGraphStEph {
+
+    use vstd::prelude::*;
+
+verus! {
+
+    use std::fmt::{Formatter, Result, Debug, Display};
+    use std::hash::Hash;
+
+    #[cfg(verus_keep_ghost)]
+    use vstd::std_specs::hash::obeys_key_model;
+    #[cfg(verus_keep_ghost)]
+    use crate::vstdplus::feq::feq::*;
+    use crate::vstdplus::seq_set::*;
+    use crate::vstdplus::clone_plus::clone_plus::ClonePlus;
+    use crate::vstdplus::hash_map_with_view_plus::hash_map_with_view_plus::*;
+    use crate::Chap05::SetStEph::SetStEph::*;
+    use crate::Chap06::DirGraphStEph::DirGraphStEph::*;
+    use crate::Chap06::GraphStEph::GraphStEph::GraphStEphTrait;
+    use crate::Types::Types::*;
+
+    broadcast use {vstd::seq_lib::group_seq_properties, vstd::set::group_set_axioms, vstd::map::group_map_axioms, crate::vstdplus::feq::feq::group_feq_axioms};
+
+    /// The arcs of an adjacency table: (u, v) for every v in the entry of u.
+    pub open spec fn spec_adj_arcs<V>(adj: Map<V, Set<V>>) -> Set<(V, V)> {
+        Set::new(|p: (V, V)| adj.contains_key(p.0) && adj[p.0].contains(p.1))
+    }
+
+    /// A table with each neighbor set replaced by its view.
+    pub open spec fn spec_table_view<V: StT + Hash>(adj: Map<V::V, SetStEph<V>>) -> Map<V::V, Set<V::V>> {
+        adj.map_values(|s: SetStEph<V>| s@)
+    }
+
+    /// The field is private so that every value built by the trait satisfies `spec_repr_wf`.
+    #[verifier::reject_recursive_types(V)]
+    pub struct AdjTableGraphStEph<V: StT + Hash> {
+        adj: HashMapWithViewPlus<V, SetStEph<V>>,
+    }
+
+    impl<V: StT + Hash> AdjTableGraphStEph<V> {
+        /// The table with each neighbor set replaced by its view.
+        pub closed spec fn spec_adj(&self) -> Map<V::V, Set<V::V>> {
+            spec_table_view(self.adj@)
+        }
+    }
+
+    impl<V: StT + Hash> View for AdjTableGraphStEph<V> {
+        type V = (Set<<V as View>::V>, Set<(<V as View>::V, <V as View>::V)>);
+        closed spec fn view(&self) -> Self::V { (self.adj@.dom(), spec_adj_arcs(self.spec_adj())) }
+    }
+
+    impl<V: StT + Hash> Clone for AdjTableGraphStEph<V> {
+        fn clone(&self) -> (clone: Self)
+            ensures clone@ == self@, clone.spec_adj() == self.spec_adj()
+        {
+            let adj = self.adj.clone();
+            proof {
+                // cloned(s, t) is s == t or SetStEph::clone's ensures, t@ == s@.
+                assert forall |k: V::V| #[trigger] adj@.contains_key(k) implies adj@[k]@ == self.adj@[k]@ by {
+                    assert(cloned(self.adj@[k], adj@[k]));
+                }
+                assert(spec_table_view(adj@) =~= spec_table_view(self.adj@));
+            }
+            AdjTableGraphStEph { adj }
+        }
+    }
+
+    pub trait AdjTableGraphStEphTrait<V: StT + Hash> :
+        View<V = (Set<<V as View>::V>, Set<(<V as View>::V, <V as View>::V)>)> + GraphStEphTrait<V> + Sized {
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn empty() -> (empty: Self)
+            requires valid_key_type_Graph::<V>()
+            ensures
+                empty.spec_repr_wf(),
+                empty@.0 == Set::<V::V>::empty(),
+                empty@.1 == Set::<(V::V, V::V)>::empty();
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn insert_vertex(&mut self, v: V)
+            requires valid_key_type_Graph::<V>(), old(self).spec_repr_wf()
+            ensures
+                self.spec_repr_wf(),
+                self@.0 == old(self)@.0.insert(v@),
+                self@.1 == old(self)@.1;
+
+        /// Adds the arc (u, v), adding u and v as vertices.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn insert_arc(&mut self, u: V, v: V)
+            requires valid_key_type_Graph::<V>(), old(self).spec_repr_wf()
+            ensures
+                self.spec_repr_wf(),
+                self@.0 == old(self)@.0.insert(u@).insert(v@),
+                self@.1 == old(self)@.1.insert((u@, v@));
+
+        /// The stored out-neighbor set of v, without copying it.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn adjacent(&self, v: &V) -> (adjacent: Option<&SetStEph<V>>)
+            requires valid_key_type_Graph::<V>(), self.spec_repr_wf()
+            ensures
+                match adjacent {
+                    Some(s) => self@.0.contains(v@) && s@ == spec_n_plus(self@.1, v@),
+                    None => !self@.0.contains(v@),
+                };
+
+        /// The graph whose out-neighbor table is adj.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn from_table(adj: HashMapWithViewPlus<V, SetStEph<V>>) -> (table: Self)
+            requires spec_graph_wf(adj@.dom(), spec_adj_arcs(spec_table_view(adj@)))
+            ensures
+                table.spec_repr_wf(),
+                table@.0 == adj@.dom(),
+                table@.1 == spec_adj_arcs(spec_table_view(adj@));
+
+        /// APAS: Work Θ(|V| + |A|), Span Θ(|V| + |A|)
+        fn FromDirGraph(g: &DirGraphStEph<V>) -> (table: Self)
+            requires valid_key_type_Graph::<V>(), g.spec_wf()
+            ensures table.spec_repr_wf(), table@ == g@;
+
+        /// APAS: Work Θ(|V| + |A|), Span Θ(|V| + |A|)
+        fn to_dir_graph(&self) -> (g: DirGraphStEph<V>)
+            requires valid_key_type_Graph::<V>(), self.spec_repr_wf()
+            ensures g.spec_wf(), g@ == self@;
+    }
+
+    impl<V: StT + Hash> AdjTableGraphStEphTrait<V> for AdjTableGraphStEph<V> {
+
+        fn empty() -> AdjTableGraphStEph<V> {
+            let table = AdjTableGraphStEph { adj: HashMapWithViewPlus::new() };
+            proof {
+                assert(table.spec_adj() =~= Map::<V::V, Set<V::V>>::empty());
+                assert(table@.1 =~= Set::<(V::V, V::V)>::empty());
+            }
+            table
+        }
+
+        fn insert_vertex(&mut self, v: V) {
+            let ghost v_view = v@;
+            let ghost old_adj = self.spec_adj();
+            if !self.adj.contains_key(&v) {
+                let no_arcs = SetStEph::<V>::empty();
+                let _ = self.adj.insert(v, no_arcs);
+                proof {
+                    assert(self.spec_adj() =~= old_adj.insert(v_view, Set::<V::V>::empty()));
+                    assert(self@.0 =~= old(self)@.0.insert(v_view));
+                    assert(self@.1 =~= old(self)@.1);
+                }
+            } else {
+                proof { assert(self@.0 =~= old(self)@.0.insert(v_view)); }
+            }
+        }
+
+        fn insert_arc(&mut self, u: V, v: V) {
+            let ghost u_view = u@;
+            let ghost v_view = v@;
+            self.insert_vertex(v.clone_plus());
+            let ghost mid = self@;
+            let ghost mid_adj = self.spec_adj();
+            let mut out = match self.adj.remove(&u) {
+                Some(s) => s,
+                None => SetStEph::empty(),
+            };
+            let ghost old_out = out@;
+            let _ = out.insert(v);
+            let _ = self.adj.insert(u, out);
+            proof {
+                assert(old_out == if mid_adj.contains_key(u_view) { mid_adj[u_view] } else { Set::<V::V>::empty() });
+                assert(self.spec_adj() =~= mid_adj.insert(u_view, old_out.insert(v_view)));
+                assert(self@.0 =~= mid.0.insert(u_view));
+                assert(self@.0 =~= old(self)@.0.insert(u_view).insert(v_view));
+                assert(self@.1 =~= mid.1.insert((u_view, v_view)));
+            }
+        }
+
+        fn adjacent(&self, v: &V) -> Option<&SetStEph<V>> {
+            match self.adj.get(v) {
+                Some(s) => {
+                    proof { assert(s@ =~= spec_n_plus(self@.1, v@)); }
+                    Some(s)
+                },
+                None => None,
+            }
+        }
+
+        fn from_table(adj: HashMapWithViewPlus<V, SetStEph<V>>) -> AdjTableGraphStEph<V> {
+            AdjTableGraphStEph { adj }
+        }
+
+        fn FromDirGraph(g: &DirGraphStEph<V>) -> AdjTableGraphStEph<V> {
+            let mut table = <AdjTableGraphStEph<V> as AdjTableGraphStEphTrait<V>>::empty();
+
+            let mut vertices_it = g.V.iter();
+            let ghost vertices_seq = vertices_it@.1;
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Graph::<V>(),
+                    vertices_it@.0 <= vertices_seq.len(),
+                    vertices_it@.1 == vertices_seq,
+                    vertices_seq.map(|i: int, x: V| x@).to_set() == g@.0,
+                    table.spec_repr_wf(),
+                    table@.0 == Set::<V::V>::new(|x: V::V| exists |i: int| #![auto] 0 <= i < vertices_it@.0 && vertices_seq[i]@ == x),
+                    table@.1 == Set::<(V::V, V::V)>::empty(),
+                decreases vertices_seq.len() - vertices_it@.0,
+            {
+                match vertices_it.next() {
+                    Some(x) => { table.insert_vertex(x.clone_plus()); },
+                    None => {
+                        proof {
+                            assert forall |x: V::V| table@.0.contains(x) implies g@.0.contains(x) by {
+                                let i = choose |i: int| #![auto] 0 <= i < vertices_seq.len() && vertices_seq[i]@ == x;
+                                lemma_seq_index_in_map_to_set(vertices_seq, i);
+                            }
+                            assert forall |x: V::V| g@.0.contains(x) implies table@.0.contains(x) by {
+                                lemma_map_to_set_contains_index(vertices_seq, x);
+                            }
+                            assert(table@.0 =~= g@.0);
+                        }
+                        break;
+                    }
+                }
+            }
+
+            let mut arcs_it = g.A.iter();
+            let ghost arcs_seq = arcs_it@.1;
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Graph::<V>(),
+                    g.spec_wf(),
+                    arcs_it@.0 <= arcs_seq.len(),
+                    arcs_it@.1 == arcs_seq,
+                    arcs_seq.map(|i: int, e: Edge<V>| e@).to_set() == g@.1,
+                    table.spec_repr_wf(),
+                    table@.0 == g@.0,
+                    table@.1 == Set::<(V::V, V::V)>::new(|p: (V::V, V::V)| exists |i: int| #![auto] 0 <= i < arcs_it@.0 && arcs_seq[i]@ == p),
+                decreases arcs_seq.len() - arcs_it@.0,
+            {
+                match arcs_it.next() {
+                    Some(arc) => {
+                        proof { lemma_seq_index_in_map_to_set(arcs_seq, arcs_it@.0 - 1); }
+                        let Edge(from, to) = arc;
+                        table.insert_arc(from.clone_plus(), to.clone_plus());
+                        proof {
+                            assert(table@.0 =~= g@.0);
+                            assert(table@.1 =~= Set::<(V::V, V::V)>::new(|p: (V::V, V::V)|
+                                exists |i: int| #![auto] 0 <= i < arcs_it@.0 && arcs_seq[i]@ == p));
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |p: (V::V, V::V)| table@.1.contains(p) implies g@.1.contains(p) by {
+                                let i = choose |i: int| #![auto] 0 <= i < arcs_seq.len() && arcs_seq[i]@ == p;
+                                lemma_seq_index_in_map_to_set(arcs_seq, i);
+                            }
+                            assert forall |p: (V::V, V::V)| g@.1.contains(p) implies table@.1.contains(p) by {
+                                lemma_map_to_set_contains_index(arcs_seq, p);
+                            }
+                            assert(table@.1 =~= g@.1);
+                        }
+                        return table;
+                    }
+                }
+            }
+        }
+
+        fn to_dir_graph(&self) -> DirGraphStEph<V> {
+            let mut vertices = SetStEph::<V>::empty();
+            let mut arcs = SetStEph::<Edge<V>>::empty();
+            let mut entries_it = self.adj.iter();
+            let ghost entries = entries_it@.1;
+            let ghost adj = self.spec_adj();
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Graph::<V>(),
+                    self.spec_repr_wf(),
+                    entries_it@.0 <= entries.len(),
+                    entries_it@.1 == entries,
+                    vertices@ == Set::<V::V>::new(|x: V::V|
+                        exists |i: int| #![trigger entries[i]] 0 <= i < entries_it@.0 && entries[i].0@ == x),
+                    arcs@ == Set::<(V::V, V::V)>::new(|p: (V::V, V::V)|
+                        exists |i: int| #![trigger entries[i]] 0 <= i < entries_it@.0 && entries[i].0@ == p.0 && entries[i].1@.contains(p.1)),
+                decreases entries.len() - entries_it@.0,
+            {
+                match entries_it.next() {
+                    Some((u, out)) => {
+                        let ghost done = entries_it@.0 - 1;
+                        let _ = vertices.insert(u.clone_plus());
+                        let mut out_it = out.iter();
+                        let ghost out_seq = out_it@.1;
+
+                        #[verifier::loop_isolation(false)]
+                        loop
+                            invariant
+                                valid_key_type_Graph::<V>(),
+                                out_it@.0 <= out_seq.len(),
+                                out_it@.1 == out_seq,
+                                out_seq.map(|i: int, w: V| w@).to_set() == out@,
+                                arcs@ == Set::<(V::V, V::V)>::new(|p: (V::V, V::V)|
+                                    (exists |i: int| #![trigger entries[i]] 0 <= i < done && entries[i].0@ == p.0 && entries[i].1@.contains(p.1))
+                                    || (p.0 == u@ && exists |j: int| #![auto] 0 <= j < out_it@.0 && out_seq[j]@ == p.1)),
+                            decreases out_seq.len() - out_it@.0,
+                        {
+                            match out_it.next() {
+                                Some(w) => { let _ = arcs.insert(Edge(u.clone_plus(), w.clone_plus())); },
+                                None => {
+                                    proof {
+                                        assert forall |w: V::V| out@.contains(w) implies
+                                            (exists |j: int| #![auto] 0 <= j < out_seq.len() && out_seq[j]@ == w) by {
+                                            lemma_map_to_set_contains_index(out_seq, w);
+                                        }
+                                        assert forall |w: V::V| (exists |j: int| #![auto] 0 <= j < out_seq.len() && out_seq[j]@ == w)
+                                            implies out@.contains(w) by {
+                                            let j = choose |j: int| #![auto] 0 <= j < out_seq.len() && out_seq[j]@ == w;
+                                            lemma_seq_index_in_map_to_set(out_seq, j);
+                                        }
+                                        assert(arcs@ =~= Set::<(V::V, V::V)>::new(|p: (V::V, V::V)|
+                                            exists |i: int| #![trigger entries[i]] 0 <= i < entries_it@.0 && entries[i].0@ == p.0 && entries[i].1@.contains(p.1)));
+                                    }
+                                    break;
+                                }
+                            }
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |x: V::V| self@.0.contains(x) implies vertices@.contains(x) by {
+                                let i = choose |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i].0@ == x;
+                            }
+                            assert(vertices@ =~= self@.0);
+                            assert forall |p: (V::V, V::V)| self@.1.contains(p) implies arcs@.contains(p) by {
+                                let i = choose |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i].0@ == p.0;
+                                assert(entries[i].1 == self.adj@[p.0]);
+                            }
+                            assert forall |p: (V::V, V::V)| arcs@.contains(p) implies self@.1.contains(p) by {
+                                let i = choose |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i].0@ == p.0 && entries[i].1@.contains(p.1);
+                                assert(entries[i].1 == self.adj@[p.0]);
+                            }
+                            assert(arcs@ =~= self@.1);
+                        }
+                        return DirGraphStEph::FromSets(vertices, arcs);
+                    }
+                }
+            }
+        }
+    }
+
+    impl<V: StT + Hash> GraphStEphTrait<V> for AdjTableGraphStEph<V> {
+
+        open spec fn spec_repr_wf(&self) -> bool { spec_graph_wf(self@.0, self@.1) }
+
+        proof fn lemma_repr_wf_graph_wf(&self) {}
+
+        fn num_vertices(&self) -> (n: N) { self.adj.len() }
+
+        fn vertex_set(&self) -> SetStEph<V> {
+            let mut vertices = SetStEph::<V>::empty();
+            let mut entries_it = self.adj.iter();
+            let ghost entries = entries_it@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Graph::<V>(),
+                    entries_it@.0 <= entries.len(),
+                    entries_it@.1 == entries,
+                    vertices@ == Set::<V::V>::new(|x: V::V|
+                        exists |i: int| #![trigger entries[i]] 0 <= i < entries_it@.0 && entries[i].0@ == x),
+                decreases entries.len() - entries_it@.0,
+            {
+                match entries_it.next() {
+                    Some((u, _)) => { let _ = vertices.insert(u.clone_plus()); },
+                    None => {
+                        proof {
+                            assert forall |x: V::V| self@.0.contains(x) implies vertices@.contains(x) by {
+                                let i = choose |i: int| #![trigger entries[i]] 0 <= i < entries.len() && entries[i].0@ == x;
+                            }
+                            assert(vertices@ =~= self@.0);
+                        }
+                        return vertices;
+                    }
+                }
+            }
+        }
+
+        fn has_vertex(&self, v: &V) -> B { self.adj.contains_key(v) }
+
+        fn has_arc(&self, u: &V, v: &V) -> B {
+            match self.adj.get(u) {
+                Some(out) => out.mem(v),
+                None => false,
+            }
+        }
+
+        fn out_neighbors(&self, v: &V) -> SetStEph<V> {
+            match self.adj.get(v) {
+                Some(out) => {
+                    let n_plus = out.clone();
+                    proof { assert(n_plus@ =~= spec_n_plus(self@.1, v@)); }
+                    n_plus
+                },
+                None => {
+                    let n_plus = SetStEph::empty();
+                    proof { assert(n_plus@ =~= spec_n_plus(self@.1, v@)); }
+                    n_plus
+                },
+            }
+        }
+    }
+
+  } // verus!
+
+    impl<V: StT + Hash> PartialEq for AdjTableGraphStEph<V> {
+        fn eq(&self, other: &Self) -> bool { self.adj == other.adj }
+    }
+
+    impl<V: StT + Hash> Eq for AdjTableGraphStEph<V> {}
+
+    impl<V: StT + Hash> Debug for AdjTableGraphStEph<V> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            f.debug_map().entries(self.adj.m.iter()).finish()
+        }
+    }
+
+    impl<V: StT + Hash> Display for AdjTableGraphStEph<V> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            let arcs: usize = self.adj.m.values().map(|out| out.size()).sum();
+            write!(f, "AdjTableGraph(V: {}, A: {})", self.adj.m.len(), arcs)
+        }
+    }
+}
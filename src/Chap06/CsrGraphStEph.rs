@@ -0,0 +1,448 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Chapter 6 ephemeral adjacency-array (compressed sparse row) digraph on vertices 0..n:
+//! the out-neighbors of u are targets[offsets[u]..offsets[u + 1]].
+
+pub mod CsrGraphStEph {
+
+    use vstd::prelude::*;
+
+verus! {
+
+    use std::fmt::{Formatter, Result, Debug, Display};
+
+    use crate::vstdplus::seq_set::*;
+    use crate::vstdplus::hash_map_with_view_plus::hash_map_with_view_plus::*;
+    use crate::Chap05::SetStEph::SetStEph::*;
+    use crate::Chap06::DirGraphStEph::DirGraphStEph::{valid_key_type_Graph, spec_graph_wf, spec_n_plus};
+    use crate::Chap06::GraphStEph::GraphStEph::GraphStEphTrait;
+    use crate::Chap06::AdjTableGraphStEph::AdjTableGraphStEph::*;
+    use crate::Types::Types::*;
+
+    broadcast use {vstd::seq_lib::group_seq_properties, vstd::set::group_set_axioms, vstd::map::group_map_axioms};
+
+    /// Vertices 0..n, where offsets has n + 1 entries.
+    pub open spec fn spec_csr_vertices(offsets: Seq<usize>) -> Set<usize> {
+        Set::new(|v: usize| v + 1 < offsets.len())
+    }
+
+    /// The targets in the row of u.
+    pub open spec fn spec_csr_row(offsets: Seq<usize>, targets: Seq<usize>, u: int) -> Set<usize> {
+        Set::new(|w: usize| exists |j: int| offsets[u] <= j < offsets[u + 1] && #[trigger] targets[j] == w)
+    }
+
+    pub proof fn lemma_csr_vertices_len(offsets: Seq<usize>)
+        requires offsets.len() >= 1,
+        ensures
+            spec_csr_vertices(offsets).finite(),
+            spec_csr_vertices(offsets).len() == offsets.len() - 1,
+        decreases offsets.len(),
+    {
+        if offsets.len() == 1 {
+            assert(spec_csr_vertices(offsets) =~= Set::<usize>::empty());
+        } else {
+            let shorter = offsets.drop_last();
+            lemma_csr_vertices_len(shorter);
+            assert(spec_csr_vertices(offsets) =~= spec_csr_vertices(shorter).insert((offsets.len() - 2) as usize));
+        }
+    }
+
+    pub open spec fn spec_csr_arcs(offsets: Seq<usize>, targets: Seq<usize>) -> Set<(usize, usize)> {
+        Set::new(|p: (usize, usize)| p.0 + 1 < offsets.len() && spec_csr_row(offsets, targets, p.0 as int).contains(p.1))
+    }
+
+    /// Offsets start at 0, never decrease and end at |targets|; every target is a vertex.
+    pub open spec fn spec_csr_wf(offsets: Seq<usize>, targets: Seq<usize>) -> bool {
+        &&& offsets.len() >= 1
+        &&& offsets[0] == 0
+        &&& offsets.last() == targets.len()
+        &&& forall |i: int, j: int| 0 <= i <= j < offsets.len() ==> #[trigger] offsets[i] <= #[trigger] offsets[j]
+        &&& forall |j: int| 0 <= j < targets.len() ==> #[trigger] targets[j] + 1 < offsets.len()
+    }
+
+    /// The fields are private so that every value satisfies `spec_csr_wf`.
+    pub struct CsrGraphStEph {
+        offsets: Vec<usize>,
+        targets: Vec<usize>,
+    }
+
+    impl View for CsrGraphStEph {
+        type V = (Set<usize>, Set<(usize, usize)>);
+        closed spec fn view(&self) -> Self::V {
+            (spec_csr_vertices(self.offsets@), spec_csr_arcs(self.offsets@, self.targets@))
+        }
+    }
+
+    impl Clone for CsrGraphStEph {
+        fn clone(&self) -> (clone: Self)
+            ensures
+                clone.spec_offsets() == self.spec_offsets(),
+                clone.spec_targets() == self.spec_targets(),
+                clone@ == self@
+        { CsrGraphStEph { offsets: self.offsets.clone(), targets: self.targets.clone() } }
+    }
+
+    pub trait CsrGraphStEphTrait : View<V = (Set<usize>, Set<(usize, usize)>)> + GraphStEphTrait<usize> + Sized {
+
+        /// The graph with no vertices.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn empty() -> (empty: Self)
+            ensures
+                empty.spec_repr_wf(),
+                empty@.0 == Set::<usize>::empty(),
+                empty@.1 == Set::<(usize, usize)>::empty();
+
+        spec fn spec_offsets(&self) -> Seq<usize>;
+
+        spec fn spec_targets(&self) -> Seq<usize>;
+
+        /// The row starts, with |V| + 1 entries.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn offsets(&self) -> (offsets: &Vec<usize>)
+            ensures offsets@ == self.spec_offsets();
+
+        /// The rows laid end to end.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn targets(&self) -> (targets: &Vec<usize>)
+            ensures targets@ == self.spec_targets();
+
+        /// The number of row entries: |A| when no row repeats a target, more otherwise.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn num_entries(&self) -> (count: N)
+            ensures count == self.spec_targets().len();
+
+        spec fn out_degree_spec(&self, v: usize) -> nat;
+
+        /// The length of the row of v.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn out_degree(&self, v: usize) -> (degree: N)
+            requires self.spec_repr_wf(), self@.0.contains(v)
+            ensures degree == self.out_degree_spec(v);
+
+        /// The k-th entry of the row of v.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn out_neighbor_at(&self, v: usize, k: usize) -> (w: usize)
+            requires self.spec_repr_wf(), self@.0.contains(v), k < self.out_degree_spec(v)
+            ensures self@.1.contains((v, w));
+
+        /// Renumbering is the caller's job: the table's vertices must already be 0..n.
+        /// APAS: Work Θ(n + |A|), Span Θ(n + |A|)
+        fn from_adj_table(g: &AdjTableGraphStEph<usize>, n: usize) -> (csr: Self)
+            requires
+                valid_key_type_Graph::<usize>(),
+                g.spec_repr_wf(),
+                g@.0 == Set::<usize>::new(|v: usize| v < n),
+                n < usize::MAX,
+            ensures csr.spec_repr_wf(), csr@ == g@;
+
+        /// APAS: Work Θ(n + |A|), Span Θ(n + |A|)
+        fn to_adj_table(&self) -> (table: AdjTableGraphStEph<usize>)
+            requires valid_key_type_Graph::<usize>(), self.spec_repr_wf()
+            ensures table.spec_repr_wf(), table@ == self@;
+    }
+
+    impl CsrGraphStEphTrait for CsrGraphStEph {
+
+        fn empty() -> CsrGraphStEph {
+            let mut offsets = Vec::new();
+            offsets.push(0);
+            let csr = CsrGraphStEph { offsets, targets: Vec::new() };
+            proof {
+                assert(csr@.0 =~= Set::<usize>::empty());
+                assert(csr@.1 =~= Set::<(usize, usize)>::empty());
+            }
+            csr
+        }
+
+        closed spec fn spec_offsets(&self) -> Seq<usize> { self.offsets@ }
+
+        closed spec fn spec_targets(&self) -> Seq<usize> { self.targets@ }
+
+        fn offsets(&self) -> &Vec<usize> { &self.offsets }
+
+        fn targets(&self) -> &Vec<usize> { &self.targets }
+
+        fn num_entries(&self) -> N { self.targets.len() }
+
+        closed spec fn out_degree_spec(&self, v: usize) -> nat {
+            (self.offsets@[v + 1] - self.offsets@[v as int]) as nat
+        }
+
+        fn out_degree(&self, v: usize) -> N {
+            proof { assert(self.offsets@[v as int] <= self.offsets@[v + 1]); }
+            self.offsets[v + 1] - self.offsets[v]
+        }
+
+        fn out_neighbor_at(&self, v: usize, k: usize) -> usize {
+            let j = self.offsets[v] + k;
+            proof { assert(self.offsets@[v + 1] <= self.offsets@.last()); }
+            let w = self.targets[j];
+            proof { assert(spec_csr_row(self.offsets@, self.targets@, v as int).contains(w)); }
+            w
+        }
+
+        fn from_adj_table(g: &AdjTableGraphStEph<usize>, n: usize) -> CsrGraphStEph {
+            let mut offsets: Vec<usize> = Vec::new();
+            let mut targets: Vec<usize> = Vec::new();
+            offsets.push(0);
+            let ghost arcs = g@.1;
+
+            let mut u: usize = 0;
+            while u < n
+                invariant
+                    valid_key_type_Graph::<usize>(),
+                    g.spec_repr_wf(),
+                    g@.0 == Set::<usize>::new(|v: usize| v < n),
+                    arcs == g@.1,
+                    u <= n,
+                    offsets@.len() == u + 1,
+                    offsets@[0] == 0,
+                    offsets@[u as int] == targets@.len(),
+                    forall |i: int, j: int| 0 <= i <= j < offsets@.len() ==> #[trigger] offsets@[i] <= #[trigger] offsets@[j],
+                    forall |j: int| 0 <= j < targets@.len() ==> #[trigger] targets@[j] < n,
+                    forall |k: int| 0 <= k < u ==> #[trigger] spec_csr_row(offsets@, targets@, k) == spec_n_plus(arcs, k as usize),
+                decreases n - u,
+            {
+                let ghost start = targets@.len();
+                let ghost before = targets@;
+                let row = match g.adjacent(&u) {
+                    Some(row) => row,
+                    None => { proof { assert(g@.0.contains(u)); } return CsrGraphStEph::empty(); },
+                };
+                let mut row_it = row.iter();
+                let ghost row_seq = row_it@.1;
+
+                #[verifier::loop_isolation(false)]
+                loop
+                    invariant
+                        row_it@.0 <= row_seq.len(),
+                        row_it@.1 == row_seq,
+                        row_seq.map(|i: int, w: usize| w@).to_set() == spec_n_plus(arcs, u),
+                        targets@.len() == start + row_it@.0,
+                        targets@.subrange(0, start as int) == before,
+                        forall |j: int| 0 <= j < row_it@.0 ==> #[trigger] targets@[start + j] == row_seq[j],
+                        forall |j: int| 0 <= j < targets@.len() ==> #[trigger] targets@[j] < n,
+                    decreases row_seq.len() - row_it@.0,
+                {
+                    match row_it.next() {
+                        Some(w) => {
+                            proof {
+                                lemma_seq_index_in_map_to_set(row_seq, row_it@.0 - 1);
+                                assert(g@.1.contains((u, *w)));
+                                assert(g@.0.contains(*w));
+                            }
+                            targets.push(*w);
+                        },
+                        None => break,
+                    }
+                }
+
+                offsets.push(targets.len());
+                proof {
+                    assert forall |k: int| 0 <= k < u implies #[trigger] spec_csr_row(offsets@, targets@, k) == spec_n_plus(arcs, k as usize) by {
+                        assert(offsets@[k + 1] <= offsets@[u as int]);
+                        assert forall |j: int| offsets@[k] <= j < offsets@[k + 1] implies targets@[j] == before[j] by {
+                            assert(targets@.subrange(0, start as int)[j] == before[j]);
+                        }
+                        assert(spec_csr_row(offsets@, targets@, k) =~= spec_csr_row(offsets@.drop_last(), before, k));
+                    }
+                    assert forall |w: usize| spec_n_plus(arcs, u).contains(w) implies spec_csr_row(offsets@, targets@, u as int).contains(w) by {
+                        lemma_map_to_set_contains_index(row_seq, w);
+                        let i = choose |i: int| 0 <= i < row_seq.len() && row_seq[i]@ == w;
+                        assert(targets@[start + i] == w);
+                    }
+                    assert forall |w: usize| spec_csr_row(offsets@, targets@, u as int).contains(w) implies spec_n_plus(arcs, u).contains(w) by {
+                        let j = choose |j: int| offsets@[u as int] <= j < offsets@[u + 1] && #[trigger] targets@[j] == w;
+                        assert(targets@[start + (j - start)] == row_seq[j - start]);
+                        lemma_seq_index_in_map_to_set(row_seq, j - start);
+                    }
+                    assert(spec_csr_row(offsets@, targets@, u as int) =~= spec_n_plus(arcs, u));
+                }
+                u = u + 1;
+            }
+
+            let csr = CsrGraphStEph { offsets, targets };
+            proof {
+                assert(csr@.0 =~= g@.0);
+                assert forall |p: (usize, usize)| csr@.1.contains(p) <==> g@.1.contains(p) by {
+                    if p.0 < n {
+                        assert(spec_csr_row(csr.offsets@, csr.targets@, p.0 as int) == spec_n_plus(arcs, p.0));
+                    }
+                }
+                assert(csr@.1 =~= g@.1);
+            }
+            csr
+        }
+
+        fn to_adj_table(&self) -> AdjTableGraphStEph<usize> {
+            let n = self.offsets.len() - 1;
+            let mut adj = HashMapWithViewPlus::<usize, SetStEph<usize>>::new();
+
+            let mut u: usize = 0;
+            while u < n
+                invariant
+                    valid_key_type_Graph::<usize>(),
+                    self.spec_repr_wf(),
+                    n + 1 == self.offsets@.len(),
+                    u <= n,
+                    adj@.dom() == Set::<usize>::new(|v: usize| v < u),
+                    forall |k: usize| k < u ==> #[trigger] spec_table_view(adj@)[k] == spec_csr_row(self.offsets@, self.targets@, k as int),
+                decreases n - u,
+            {
+                let lo = self.offsets[u];
+                let hi = self.offsets[u + 1];
+                proof { assert(hi <= self.offsets@.last()); }
+                let mut row = SetStEph::<usize>::empty();
+                let mut j = lo;
+                while j < hi
+                    invariant
+                        valid_key_type_Graph::<usize>(),
+                        lo <= j <= hi,
+                        hi <= self.targets@.len(),
+                        row@ == Set::<usize>::new(|w: usize| exists |i: int| lo <= i < j && #[trigger] self.targets@[i] == w),
+                    decreases hi - j,
+                {
+                    let _ = row.insert(self.targets[j]);
+                    proof {
+                        assert(row@ =~= Set::<usize>::new(|w: usize| exists |i: int| lo <= i < j + 1 && #[trigger] self.targets@[i] == w));
+                    }
+                    j = j + 1;
+                }
+                proof { assert(row@ =~= spec_csr_row(self.offsets@, self.targets@, u as int)); }
+                let _ = adj.insert(u, row);
+                proof { assert(adj@.dom() =~= Set::<usize>::new(|v: usize| v < u + 1)); }
+                u = u + 1;
+            }
+
+            proof {
+                assert(adj@.dom() =~= self@.0);
+                assert forall |p: (usize, usize)| spec_adj_arcs(spec_table_view(adj@)).contains(p) <==> self@.1.contains(p) by {
+                    if p.0 < n {
+                        assert(spec_table_view(adj@)[p.0] == spec_csr_row(self.offsets@, self.targets@, p.0 as int));
+                    }
+                }
+                assert(spec_adj_arcs(spec_table_view(adj@)) =~= self@.1);
+                self.lemma_repr_wf_graph_wf();
+            }
+            let table = <AdjTableGraphStEph<usize> as AdjTableGraphStEphTrait<usize>>::from_table(adj);
+            proof {
+                assert(table@.0 =~= self@.0);
+                assert(table@.1 =~= self@.1);
+            }
+            table
+        }
+    }
+
+    impl GraphStEphTrait<usize> for CsrGraphStEph {
+
+        closed spec fn spec_repr_wf(&self) -> bool { spec_csr_wf(self.offsets@, self.targets@) }
+
+        proof fn lemma_repr_wf_graph_wf(&self) {
+            assert forall |u: usize, v: usize| #[trigger] self@.1.contains((u, v)) implies self@.0.contains(u) && self@.0.contains(v) by {
+                let j = choose |j: int| self.offsets@[u as int] <= j < self.offsets@[u + 1] && #[trigger] self.targets@[j] == v;
+                assert(self.offsets@[u + 1] <= self.offsets@.last());
+            }
+        }
+
+        fn num_vertices(&self) -> (n: N) {
+            proof { lemma_csr_vertices_len(self.offsets@); }
+            self.offsets.len() - 1
+        }
+
+        fn vertex_set(&self) -> SetStEph<usize> {
+            let n = self.offsets.len() - 1;
+            let mut vertices = SetStEph::<usize>::empty();
+            let mut v: usize = 0;
+            while v < n
+                invariant
+                    valid_key_type_Graph::<usize>(),
+                    n + 1 == self.offsets@.len(),
+                    v <= n,
+                    vertices@ == Set::<usize>::new(|x: usize| x < v),
+                decreases n - v,
+            {
+                let _ = vertices.insert(v);
+                proof { assert(vertices@ =~= Set::<usize>::new(|x: usize| x < v + 1)); }
+                v = v + 1;
+            }
+            proof { assert(vertices@ =~= self@.0); }
+            vertices
+        }
+
+        fn has_vertex(&self, v: &usize) -> B { *v < self.offsets.len() - 1 }
+
+        fn has_arc(&self, u: &usize, v: &usize) -> B {
+            if !self.has_vertex(u) {
+                return false;
+            }
+            let lo = self.offsets[*u];
+            let hi = self.offsets[*u + 1];
+            proof { assert(hi <= self.offsets@.last()); }
+            let mut j = lo;
+            while j < hi
+                invariant
+                    lo <= j <= hi,
+                    hi <= self.targets@.len(),
+                    lo == self.offsets@[*u as int],
+                    hi == self.offsets@[*u + 1],
+                    forall |i: int| lo <= i < j ==> #[trigger] self.targets@[i] != *v,
+                decreases hi - j,
+            {
+                if self.targets[j] == *v {
+                    return true;
+                }
+                j = j + 1;
+            }
+            false
+        }
+
+        fn out_neighbors(&self, v: &usize) -> SetStEph<usize> {
+            let mut n_plus = SetStEph::<usize>::empty();
+            if !self.has_vertex(v) {
+                proof { assert(n_plus@ =~= spec_n_plus(self@.1, *v)); }
+                return n_plus;
+            }
+            let lo = self.offsets[*v];
+            let hi = self.offsets[*v + 1];
+            proof { assert(hi <= self.offsets@.last()); }
+            let mut j = lo;
+            while j < hi
+                invariant
+                    valid_key_type_Graph::<usize>(),
+                    lo <= j <= hi,
+                    hi <= self.targets@.len(),
+                    lo == self.offsets@[*v as int],
+                    hi == self.offsets@[*v + 1],
+                    n_plus@ == Set::<usize>::new(|w: usize| exists |i: int| lo <= i < j && #[trigger] self.targets@[i] == w),
+                decreases hi - j,
+            {
+                let _ = n_plus.insert(self.targets[j]);
+                proof {
+                    assert(n_plus@ =~= Set::<usize>::new(|w: usize| exists |i: int| lo <= i < j + 1 && #[trigger] self.targets@[i] == w));
+                }
+                j = j + 1;
+            }
+            proof { assert(n_plus@ =~= spec_n_plus(self@.1, *v)); }
+            n_plus
+        }
+    }
+
+  } // verus!
+
+    impl PartialEq for CsrGraphStEph {
+        fn eq(&self, other: &Self) -> bool { self.offsets == other.offsets && self.targets == other.targets }
+    }
+
+    impl Eq for CsrGraphStEph {}
+
+    impl Debug for CsrGraphStEph {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            f.debug_struct("CsrGraphStEph").field("offsets", &self.offsets).field("targets", &self.targets).finish()
+        }
+    }
+
+    impl Display for CsrGraphStEph {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            write!(f, "CsrGraph(V: {}, entries: {})", self.offsets.len() - 1, self.targets.len())
+        }
+    }
+}
@@ -0,0 +1,301 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Chapter 6.1 ephemeral directed graph built on `SetStEph<V>` vertices and `SetStEph<Edge<V>>` arcs.
+
+pub mod DirGraphStEph {
+
+    use vstd::prelude::*;
+
+verus! {
+
+    use std::fmt::{Formatter, Result, Debug, Display};
+    use std::hash::Hash;
+
+    #[cfg(verus_keep_ghost)]
+    use vstd::std_specs::hash::obeys_key_model;
+    #[cfg(verus_keep_ghost)]
+    use crate::vstdplus::feq::feq::*;
+    #[cfg(not(verus_keep_ghost))]
+    use crate::vstdplus::feq::feq::feq;
+    use crate::vstdplus::seq_set::*;
+    use crate::vstdplus::clone_plus::clone_plus::ClonePlus;
+    use crate::Chap05::SetStEph::SetStEph::*;
+    use crate::Chap06::GraphStEph::GraphStEph::GraphStEphTrait;
+    use crate::Types::Types::*;
+
+    broadcast use {vstd::seq_lib::group_seq_properties, vstd::set::group_set_axioms, crate::vstdplus::feq::feq::group_feq_axioms};
+
+    pub open spec fn valid_key_type_Graph<V: StT + Hash>() -> bool {
+        valid_key_type::<V>() && valid_key_type::<Edge<V>>()
+    }
+
+    /// Every arc (or edge) endpoint is a vertex.
+    pub open spec fn spec_graph_wf<V>(vertices: Set<V>, arcs: Set<(V, V)>) -> bool {
+        forall |u: V, v: V| #[trigger] arcs.contains((u, v)) ==> vertices.contains(u) && vertices.contains(v)
+    }
+
+    /// Out-neighbors of v: N+(v) = { w | (v, w) in A }.
+    pub open spec fn spec_n_plus<V>(arcs: Set<(V, V)>, v: V) -> Set<V> {
+        Set::new(|w: V| arcs.contains((v, w)))
+    }
+
+    /// In-neighbors of v: N-(v) = { u | (u, v) in A }.
+    pub open spec fn spec_n_minus<V>(arcs: Set<(V, V)>, v: V) -> Set<V> {
+        Set::new(|u: V| arcs.contains((u, v)))
+    }
+
+    #[verifier::reject_recursive_types(V)]
+    pub struct DirGraphStEph<V: StT + Hash> {
+        pub V: SetStEph<V>,
+        pub A: SetStEph<Edge<V>>,
+    }
+
+    impl<V: StT + Hash> View for DirGraphStEph<V> {
+        type V = (Set<<V as View>::V>, Set<(<V as View>::V, <V as View>::V)>);
+        open spec fn view(&self) -> Self::V { (self.V@, self.A@) }
+    }
+
+    impl<V: StT + Hash> Clone for DirGraphStEph<V> {
+        fn clone(&self) -> (clone: Self)
+            ensures clone@ == self@
+        { DirGraphStEph { V: self.V.clone(), A: self.A.clone() } }
+    }
+
+    pub trait DirGraphStEphTrait<V: StT + Hash> :
+        View<V = (Set<<V as View>::V>, Set<(<V as View>::V, <V as View>::V)>)> + Sized {
+
+        /// Every arc endpoint is a vertex.
+        spec fn spec_wf(&self) -> bool;
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn empty() -> (empty: Self)
+            requires valid_key_type_Graph::<V>()
+            ensures
+                empty.spec_wf(),
+                empty@.0 == Set::<V::V>::empty(),
+                empty@.1 == Set::<(V::V, V::V)>::empty();
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn FromSets(vertices: SetStEph<V>, arcs: SetStEph<Edge<V>>) -> (g: Self)
+            requires valid_key_type_Graph::<V>()
+            ensures
+                g@.0 == vertices@,
+                g@.1 == arcs@,
+                g.spec_wf() == spec_graph_wf(vertices@, arcs@);
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn vertices(&self) -> (vertices: &SetStEph<V>)
+            ensures vertices@ == self@.0;
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn arcs(&self) -> (arcs: &SetStEph<Edge<V>>)
+            ensures arcs@ == self@.1;
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn sizeV(&self) -> (count: N)
+            ensures count == self@.0.len();
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn sizeA(&self) -> (count: N)
+            ensures count == self@.1.len();
+
+        /// Whether (u, v) is an arc.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn Neighbor(&self, u: &V, v: &V) -> (is: B)
+            requires valid_key_type_Graph::<V>()
+            ensures is == self@.1.contains((u@, v@));
+
+        /// Out-neighbors of v.
+        /// APAS: Work Θ(|A|), Span Θ(|A|)
+        fn NPlus(&self, v: &V) -> (n_plus: SetStEph<V>)
+            requires valid_key_type_Graph::<V>()
+            ensures n_plus@ == spec_n_plus(self@.1, v@);
+
+        /// In-neighbors of v.
+        /// APAS: Work Θ(|A|), Span Θ(|A|)
+        fn NMinus(&self, v: &V) -> (n_minus: SetStEph<V>)
+            requires valid_key_type_Graph::<V>()
+            ensures n_minus@ == spec_n_minus(self@.1, v@);
+
+        /// |N+(v)|
+        /// APAS: Work Θ(|A|), Span Θ(|A|)
+        fn OutDegree(&self, v: &V) -> (degree: N)
+            requires valid_key_type_Graph::<V>()
+            ensures degree == spec_n_plus(self@.1, v@).len();
+
+        /// |N-(v)|
+        /// APAS: Work Θ(|A|), Span Θ(|A|)
+        fn InDegree(&self, v: &V) -> (degree: N)
+            requires valid_key_type_Graph::<V>()
+            ensures degree == spec_n_minus(self@.1, v@).len();
+
+        /// Whether arc e is incident on v, i.e. v is one of its endpoints.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn Incident(&self, e: &Edge<V>, v: &V) -> (incident: B)
+            requires valid_key_type_Graph::<V>()
+            ensures incident == (e@.0 == v@ || e@.1 == v@);
+    }
+
+    impl<V: StT + Hash> DirGraphStEphTrait<V> for DirGraphStEph<V> {
+
+        open spec fn spec_wf(&self) -> bool { spec_graph_wf(self.V@, self.A@) }
+
+        fn empty() -> DirGraphStEph<V> {
+            DirGraphStEph { V: SetStEph::empty(), A: SetStEph::empty() }
+        }
+
+        fn FromSets(vertices: SetStEph<V>, arcs: SetStEph<Edge<V>>) -> DirGraphStEph<V> {
+            DirGraphStEph { V: vertices, A: arcs }
+        }
+
+        fn vertices(&self) -> &SetStEph<V> { &self.V }
+
+        fn arcs(&self) -> &SetStEph<Edge<V>> { &self.A }
+
+        fn sizeV(&self) -> (count: N) { self.V.size() }
+
+        fn sizeA(&self) -> (count: N) { self.A.size() }
+
+        fn Neighbor(&self, u: &V, v: &V) -> B {
+            self.A.mem(&Edge(u.clone_plus(), v.clone_plus()))
+        }
+
+        fn NPlus(&self, v: &V) -> SetStEph<V> {
+            let mut out = SetStEph::<V>::empty();
+            let mut it = self.A.iter();
+            let ghost arcs_seq = it@.1;
+            let ghost arcs_view = self@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Graph::<V>(),
+                    it@.0 <= arcs_seq.len(),
+                    it@.1 == arcs_seq,
+                    arcs_seq.map(|i: int, e: Edge<V>| e@).to_set() == arcs_view,
+                    out@ == Set::<V::V>::new(|w: V::V|
+                        exists |i: int| #![auto] 0 <= i < it@.0 && arcs_seq[i]@.0 == v@ && arcs_seq[i]@.1 == w),
+                decreases arcs_seq.len() - it@.0,
+            {
+                match it.next() {
+                    Some(arc) => {
+                        let Edge(from, to) = arc;
+                        if feq(from, v) {
+                            let to_clone = to.clone_plus();
+                            let _ = out.insert(to_clone);
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |w: V::V| out@.contains(w) implies self@.1.contains((v@, w)) by {
+                                let i = choose |i: int| #![auto] 0 <= i < arcs_seq.len() && arcs_seq[i]@.0 == v@ && arcs_seq[i]@.1 == w;
+                                lemma_seq_index_in_map_to_set(arcs_seq, i);
+                            }
+                            assert forall |w: V::V| self@.1.contains((v@, w)) implies out@.contains(w) by {
+                                lemma_map_to_set_contains_index(arcs_seq, (v@, w));
+                            }
+                            assert(out@ =~= spec_n_plus(self@.1, v@));
+                        }
+                        return out;
+                    }
+                }
+            }
+        }
+
+        fn NMinus(&self, v: &V) -> SetStEph<V> {
+            let mut out = SetStEph::<V>::empty();
+            let mut it = self.A.iter();
+            let ghost arcs_seq = it@.1;
+            let ghost arcs_view = self@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Graph::<V>(),
+                    it@.0 <= arcs_seq.len(),
+                    it@.1 == arcs_seq,
+                    arcs_seq.map(|i: int, e: Edge<V>| e@).to_set() == arcs_view,
+                    out@ == Set::<V::V>::new(|u: V::V|
+                        exists |i: int| #![auto] 0 <= i < it@.0 && arcs_seq[i]@.0 == u && arcs_seq[i]@.1 == v@),
+                decreases arcs_seq.len() - it@.0,
+            {
+                match it.next() {
+                    Some(arc) => {
+                        let Edge(from, to) = arc;
+                        if feq(to, v) {
+                            let from_clone = from.clone_plus();
+                            let _ = out.insert(from_clone);
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |u: V::V| out@.contains(u) implies self@.1.contains((u, v@)) by {
+                                let i = choose |i: int| #![auto] 0 <= i < arcs_seq.len() && arcs_seq[i]@.0 == u && arcs_seq[i]@.1 == v@;
+                                lemma_seq_index_in_map_to_set(arcs_seq, i);
+                            }
+                            assert forall |u: V::V| self@.1.contains((u, v@)) implies out@.contains(u) by {
+                                lemma_map_to_set_contains_index(arcs_seq, (u, v@));
+                            }
+                            assert(out@ =~= spec_n_minus(self@.1, v@));
+                        }
+                        return out;
+                    }
+                }
+            }
+        }
+
+        fn OutDegree(&self, v: &V) -> (degree: N) { self.NPlus(v).size() }
+
+        fn InDegree(&self, v: &V) -> (degree: N) { self.NMinus(v).size() }
+
+        fn Incident(&self, e: &Edge<V>, v: &V) -> B {
+            feq(&e.0, v) || feq(&e.1, v)
+        }
+    }
+
+    impl<V: StT + Hash> GraphStEphTrait<V> for DirGraphStEph<V> {
+
+        open spec fn spec_repr_wf(&self) -> bool { spec_graph_wf(self.V@, self.A@) }
+
+        proof fn lemma_repr_wf_graph_wf(&self) {}
+
+        fn num_vertices(&self) -> (n: N) { self.V.size() }
+
+        fn vertex_set(&self) -> SetStEph<V> { self.V.clone() }
+
+        fn has_vertex(&self, v: &V) -> B { self.V.mem(v) }
+
+        fn has_arc(&self, u: &V, v: &V) -> B { self.Neighbor(u, v) }
+
+        fn out_neighbors(&self, v: &V) -> SetStEph<V> { self.NPlus(v) }
+    }
+
+    #[macro_export]
+    macro_rules! DirGraphLit {
+        () => {{
+            < $crate::Chap06::DirGraphStEph::DirGraphStEph::DirGraphStEph<_> >::empty()
+        }};
+        ( V: [ $( $v:expr ),* $(,)? ], A: [ $( ($a:expr, $b:expr) ),* $(,)? ] $(,)? ) => {{
+            let __vertices = $crate::SetLit![ $( $v ),* ];
+            let __arcs = $crate::SetLit![ $( $crate::Types::Types::Edge($a, $b) ),* ];
+            < $crate::Chap06::DirGraphStEph::DirGraphStEph::DirGraphStEph<_> >::FromSets(__vertices, __arcs)
+        }};
+    }
+
+  } // verus!
+
+    impl<V: StT + Hash> PartialEq for DirGraphStEph<V> {
+        fn eq(&self, other: &Self) -> bool { self.V == other.V && self.A == other.A }
+    }
+
+    impl<V: StT + Hash> Eq for DirGraphStEph<V> {}
+
+    impl<V: StT + Hash> Debug for DirGraphStEph<V> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            f.debug_struct("DirGraphStEph").field("V", &self.V).field("A", &self.A).finish()
+        }
+    }
+
+    impl<V: StT + Hash> Display for DirGraphStEph<V> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result { write!(f, "DirGraph(V: {}, A: {})", self.V, self.A) }
+    }
+}
@@ -0,0 +1,53 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Chapter 6 graph representations: the operations every directed graph representation
+//! provides, against the common ghost view (vertex set, arc set) of `DirGraphStEph`.
+
+pub mod GraphStEph {
+
+    use vstd::prelude::*;
+
+verus! {
+
+    use std::hash::Hash;
+
+    use crate::Chap05::SetStEph::SetStEph::*;
+    use crate::Chap06::DirGraphStEph::DirGraphStEph::{valid_key_type_Graph, spec_graph_wf, spec_n_plus};
+    use crate::Types::Types::*;
+
+    /// Edge sets, adjacency tables and CSR arrays all denote the same graph view, so algorithms
+    /// written against this trait run on any of them.
+    pub trait GraphStEphTrait<V: StT + Hash> :
+        View<V = (Set<<V as View>::V>, Set<(<V as View>::V, <V as View>::V)>)> + Sized {
+
+        /// The representation invariant.
+        spec fn spec_repr_wf(&self) -> bool;
+
+        proof fn lemma_repr_wf_graph_wf(&self)
+            requires self.spec_repr_wf()
+            ensures spec_graph_wf(self@.0, self@.1);
+
+        /// |V|
+        fn num_vertices(&self) -> (n: N)
+            requires self.spec_repr_wf()
+            ensures n == self@.0.len();
+
+        fn vertex_set(&self) -> (vertices: SetStEph<V>)
+            requires valid_key_type_Graph::<V>(), self.spec_repr_wf()
+            ensures vertices@ == self@.0;
+
+        fn has_vertex(&self, v: &V) -> (has: B)
+            requires valid_key_type_Graph::<V>(), self.spec_repr_wf()
+            ensures has == self@.0.contains(v@);
+
+        fn has_arc(&self, u: &V, v: &V) -> (has: B)
+            requires valid_key_type_Graph::<V>(), self.spec_repr_wf()
+            ensures has == self@.1.contains((u@, v@));
+
+        /// N+(v). Work Θ(1 + d+(v)) for adjacency tables and CSR, Θ(|A|) for edge sets.
+        fn out_neighbors(&self, v: &V) -> (n_plus: SetStEph<V>)
+            requires valid_key_type_Graph::<V>(), self.spec_repr_wf()
+            ensures n_plus@ == spec_n_plus(self@.1, v@);
+    }
+
+  } // verus!
+}
@@ -0,0 +1,435 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Chapter 6.1 ephemeral labeled directed graph: arcs are `LabEdge(u, v, label)`, e.g. weighted arcs.
+
+pub mod LabDirGraphStEph {
+
+    use vstd::prelude::*;
+
+verus! {
+
+    use std::fmt::{Formatter, Result, Debug, Display};
+    use std::hash::Hash;
+
+    #[cfg(verus_keep_ghost)]
+    use vstd::std_specs::hash::obeys_key_model;
+    #[cfg(verus_keep_ghost)]
+    use crate::vstdplus::feq::feq::*;
+    #[cfg(not(verus_keep_ghost))]
+    use crate::vstdplus::feq::feq::feq;
+    use crate::vstdplus::seq_set::*;
+    use crate::vstdplus::clone_plus::clone_plus::ClonePlus;
+    use crate::Chap05::SetStEph::SetStEph::*;
+    use crate::Types::Types::*;
+
+    broadcast use {vstd::seq_lib::group_seq_properties, vstd::set::group_set_axioms, crate::vstdplus::feq::feq::group_feq_axioms};
+
+    pub open spec fn valid_key_type_LabGraph<V: StT + Hash, L: StT + Hash>() -> bool {
+        valid_key_type::<V>() && valid_key_type::<L>() && valid_key_type::<LabEdge<V, L>>()
+    }
+
+    /// Every labeled arc (or edge) endpoint is a vertex.
+    pub open spec fn spec_lab_graph_wf<V, L>(vertices: Set<V>, arcs: Set<(V, V, L)>) -> bool {
+        forall |u: V, v: V, l: L| #[trigger] arcs.contains((u, v, l)) ==> vertices.contains(u) && vertices.contains(v)
+    }
+
+    /// Every arc (u, v) carries one label.
+    pub open spec fn spec_lab_one_label<V, L>(arcs: Set<(V, V, L)>) -> bool {
+        forall |u: V, v: V, l1: L, l2: L| #![trigger arcs.contains((u, v, l1)), arcs.contains((u, v, l2))]
+            arcs.contains((u, v, l1)) && arcs.contains((u, v, l2)) ==> l1 == l2
+    }
+
+    /// Some arc (u, v) carries a label.
+    pub open spec fn spec_has_lab_arc<V, L>(arcs: Set<(V, V, L)>, u: V, v: V) -> bool {
+        exists |l: L| #[trigger] arcs.contains((u, v, l))
+    }
+
+    /// Out-neighbors of v over labeled arcs.
+    pub open spec fn spec_lab_n_plus<V, L>(arcs: Set<(V, V, L)>, v: V) -> Set<V> {
+        Set::new(|w: V| spec_has_lab_arc(arcs, v, w))
+    }
+
+    /// In-neighbors of v over labeled arcs.
+    pub open spec fn spec_lab_n_minus<V, L>(arcs: Set<(V, V, L)>, v: V) -> Set<V> {
+        Set::new(|u: V| spec_has_lab_arc(arcs, u, v))
+    }
+
+    /// The fields are private so that every value built by the trait satisfies `spec_wf`.
+    #[verifier::reject_recursive_types(V)]
+    #[verifier::reject_recursive_types(L)]
+    pub struct LabDirGraphStEph<V: StT + Hash, L: StT + Hash> {
+        V: SetStEph<V>,
+        A: SetStEph<LabEdge<V, L>>,
+    }
+
+    impl<V: StT + Hash, L: StT + Hash> View for LabDirGraphStEph<V, L> {
+        type V = (Set<<V as View>::V>, Set<(<V as View>::V, <V as View>::V, <L as View>::V)>);
+        closed spec fn view(&self) -> Self::V { (self.V@, self.A@) }
+    }
+
+    impl<V: StT + Hash, L: StT + Hash> Clone for LabDirGraphStEph<V, L> {
+        fn clone(&self) -> (clone: Self)
+            ensures clone@ == self@
+        { LabDirGraphStEph { V: self.V.clone(), A: self.A.clone() } }
+    }
+
+    pub trait LabDirGraphStEphTrait<V: StT + Hash, L: StT + Hash> :
+        View<V = (Set<<V as View>::V>, Set<(<V as View>::V, <V as View>::V, <L as View>::V)>)> + Sized {
+
+        /// Every arc endpoint is a vertex and every arc has one label.
+        spec fn spec_wf(&self) -> bool;
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn empty() -> (empty: Self)
+            requires valid_key_type_LabGraph::<V, L>()
+            ensures
+                empty.spec_wf(),
+                empty@.0 == Set::<V::V>::empty(),
+                empty@.1 == Set::<(V::V, V::V, L::V)>::empty();
+
+        /// Keeps the first label met for each arc.
+        /// APAS: Work Θ(|A|), Span Θ(|A|)
+        fn FromSets(vertices: SetStEph<V>, arcs: SetStEph<LabEdge<V, L>>) -> (g: Self)
+            requires valid_key_type_LabGraph::<V, L>(), valid_key_type::<Edge<V>>()
+            ensures
+                g@.0 == vertices@,
+                g@.1.subset_of(arcs@),
+                forall |u: V::V, v: V::V, l: L::V| #[trigger] arcs@.contains((u, v, l)) ==> spec_has_lab_arc(g@.1, u, v),
+                spec_lab_one_label(arcs@) ==> g@.1 == arcs@,
+                g.spec_wf() == spec_lab_graph_wf(vertices@, arcs@);
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn vertices(&self) -> (vertices: &SetStEph<V>)
+            ensures vertices@ == self@.0;
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn labeled_arcs(&self) -> (arcs: &SetStEph<LabEdge<V, L>>)
+            ensures arcs@ == self@.1;
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn sizeV(&self) -> (count: N)
+            ensures count == self@.0.len();
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn sizeA(&self) -> (count: N)
+            ensures count == self@.1.len();
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn add_vertex(&mut self, v: V)
+            requires valid_key_type_LabGraph::<V, L>()
+            ensures
+                self@.0 == old(self)@.0.insert(v@),
+                self@.1 == old(self)@.1;
+
+        /// Adds the arc (u, v) with the given label, replacing any label it had, and adds u and v as vertices.
+        /// APAS: Work Θ(|A|), Span Θ(|A|)
+        fn add_labeled_arc(&mut self, u: V, v: V, label: L)
+            requires
+                valid_key_type_LabGraph::<V, L>(),
+                old(self).spec_wf(),
+            ensures
+                self.spec_wf(),
+                self@.0 == old(self)@.0.insert(u@).insert(v@),
+                self@.1 == old(self)@.1.filter(|e: (V::V, V::V, L::V)| !(e.0 == u@ && e.1 == v@)).insert((u@, v@, label@));
+
+        /// The label (weight) of arc (u, v), if there is one.
+        /// APAS: Work Θ(|A|), Span Θ(|A|)
+        fn get_arc_label(&self, u: &V, v: &V) -> (label: Option<L>)
+            requires valid_key_type_LabGraph::<V, L>()
+            ensures
+                label matches Some(l) ==> self@.1.contains((u@, v@, l@)),
+                label is None ==> !spec_has_lab_arc(self@.1, u@, v@);
+
+        /// Whether (u, v) is an arc under some label.
+        /// APAS: Work Θ(|A|), Span Θ(|A|)
+        fn has_arc(&self, u: &V, v: &V) -> (has: B)
+            requires valid_key_type_LabGraph::<V, L>()
+            ensures has == spec_has_lab_arc(self@.1, u@, v@);
+
+        /// APAS: Work Θ(|A|), Span Θ(|A|)
+        fn out_neighbors(&self, v: &V) -> (n_plus: SetStEph<V>)
+            requires valid_key_type_LabGraph::<V, L>()
+            ensures n_plus@ == spec_lab_n_plus(self@.1, v@);
+
+        /// APAS: Work Θ(|A|), Span Θ(|A|)
+        fn in_neighbors(&self, v: &V) -> (n_minus: SetStEph<V>)
+            requires valid_key_type_LabGraph::<V, L>()
+            ensures n_minus@ == spec_lab_n_minus(self@.1, v@);
+    }
+
+    impl<V: StT + Hash, L: StT + Hash> LabDirGraphStEphTrait<V, L> for LabDirGraphStEph<V, L> {
+
+        open spec fn spec_wf(&self) -> bool { spec_lab_graph_wf(self@.0, self@.1) && spec_lab_one_label(self@.1) }
+
+        fn empty() -> LabDirGraphStEph<V, L> {
+            LabDirGraphStEph { V: SetStEph::empty(), A: SetStEph::empty() }
+        }
+
+        fn FromSets(vertices: SetStEph<V>, arcs: SetStEph<LabEdge<V, L>>) -> LabDirGraphStEph<V, L> {
+            let mut kept = SetStEph::<LabEdge<V, L>>::empty();
+            let mut pairs = SetStEph::<Edge<V>>::empty();
+            let mut it = arcs.iter();
+            let ghost arcs_seq = it@.1;
+            let ghost arcs_view = arcs@;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_LabGraph::<V, L>(),
+                    valid_key_type::<Edge<V>>(),
+                    it@.0 <= arcs_seq.len(),
+                    it@.1 == arcs_seq,
+                    arcs_seq.map(|i: int, e: LabEdge<V, L>| e@).to_set() == arcs_view,
+                    kept@.subset_of(arcs_view),
+                    spec_lab_one_label(kept@),
+                    forall |u: V::V, v: V::V, l: L::V| #[trigger] kept@.contains((u, v, l)) ==> pairs@.contains((u, v)),
+                    forall |u: V::V, v: V::V| #[trigger] pairs@.contains((u, v)) ==> spec_has_lab_arc(kept@, u, v),
+                    forall |i: int| #![trigger arcs_seq[i]] 0 <= i < it@.0 ==>
+                        spec_has_lab_arc(kept@, arcs_seq[i]@.0, arcs_seq[i]@.1),
+                decreases arcs_seq.len() - it@.0,
+            {
+                match it.next() {
+                    Some(arc) => {
+                        proof { lemma_seq_index_in_map_to_set(arcs_seq, it@.0 - 1); }
+                        let LabEdge(a, b, l) = arc;
+                        if !pairs.mem(&Edge(a.clone_plus(), b.clone_plus())) {
+                            let ghost before = kept@;
+                            let _ = kept.insert(LabEdge(a.clone_plus(), b.clone_plus(), l.clone_plus()));
+                            let _ = pairs.insert(Edge(a.clone_plus(), b.clone_plus()));
+                            proof {
+                                assert(kept@.contains((a@, b@, l@)));
+                                assert forall |l2: L::V| !#[trigger] before.contains((a@, b@, l2)) by {}
+                            }
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |u: V::V, v: V::V, l: L::V| #[trigger] arcs_view.contains((u, v, l)) implies
+                                spec_has_lab_arc(kept@, u, v) by {
+                                lemma_map_to_set_contains_index(arcs_seq, (u, v, l));
+                            }
+                            if spec_lab_one_label(arcs_view) {
+                                assert forall |e: (V::V, V::V, L::V)| arcs_view.contains(e) implies #[trigger] kept@.contains(e) by {
+                                    assert(arcs_view.contains((e.0, e.1, e.2)));
+                                    let l = choose |l: L::V| #[trigger] kept@.contains((e.0, e.1, l));
+                                    assert(arcs_view.contains((e.0, e.1, l)));
+                                }
+                                assert(kept@ =~= arcs_view);
+                            }
+                            if spec_lab_graph_wf(vertices@, kept@) {
+                                assert forall |u: V::V, v: V::V, l: L::V| #[trigger] arcs_view.contains((u, v, l)) implies
+                                    vertices@.contains(u) && vertices@.contains(v) by {
+                                    let l2 = choose |l2: L::V| #[trigger] kept@.contains((u, v, l2));
+                                }
+                            }
+                            if spec_lab_graph_wf(vertices@, arcs_view) {
+                                assert forall |u: V::V, v: V::V, l: L::V| #[trigger] kept@.contains((u, v, l)) implies
+                                    vertices@.contains(u) && vertices@.contains(v) by {
+                                    assert(arcs_view.contains((u, v, l)));
+                                }
+                            }
+                        }
+                        return LabDirGraphStEph { V: vertices, A: kept };
+                    }
+                }
+            }
+        }
+
+        fn vertices(&self) -> &SetStEph<V> { &self.V }
+
+        fn labeled_arcs(&self) -> &SetStEph<LabEdge<V, L>> { &self.A }
+
+        fn sizeV(&self) -> (count: N) { self.V.size() }
+
+        fn sizeA(&self) -> (count: N) { self.A.size() }
+
+        fn add_vertex(&mut self, v: V) {
+            let _ = self.V.insert(v);
+        }
+
+        fn add_labeled_arc(&mut self, u: V, v: V, label: L) {
+            let ghost old_arcs = self@.1;
+            if let Some(l) = self.get_arc_label(&u, &v) {
+                let ghost lv = l@;
+                let _ = self.A.delete(&LabEdge(u.clone_plus(), v.clone_plus(), l));
+                proof {
+                    assert forall |e: (V::V, V::V, L::V)| old_arcs.contains(e) && e.0 == u@ && e.1 == v@ implies e.2 == lv by {
+                        assert(old_arcs.contains((e.0, e.1, e.2)) && old_arcs.contains((u@, v@, lv)));
+                    }
+                }
+            } else {
+                proof {
+                    assert forall |e: (V::V, V::V, L::V)| old_arcs.contains(e) implies !(e.0 == u@ && e.1 == v@) by {
+                        assert(old_arcs.contains((e.0, e.1, e.2)));
+                    }
+                }
+            }
+            proof { assert(self@.1 =~= old_arcs.filter(|e: (V::V, V::V, L::V)| !(e.0 == u@ && e.1 == v@))); }
+            let _ = self.V.insert(u.clone_plus());
+            let _ = self.V.insert(v.clone_plus());
+            let _ = self.A.insert(LabEdge(u, v, label));
+        }
+
+        fn get_arc_label(&self, u: &V, v: &V) -> Option<L> {
+            let mut it = self.A.iter();
+            let ghost arcs_seq = it@.1;
+            let ghost arcs_view = self@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_LabGraph::<V, L>(),
+                    it@.0 <= arcs_seq.len(),
+                    it@.1 == arcs_seq,
+                    arcs_seq.map(|i: int, e: LabEdge<V, L>| e@).to_set() == arcs_view,
+                    forall |j: int| #![auto] 0 <= j < it@.0 ==> !(arcs_seq[j]@.0 == u@ && arcs_seq[j]@.1 == v@),
+                decreases arcs_seq.len() - it@.0,
+            {
+                match it.next() {
+                    Some(arc) => {
+                        let LabEdge(from, to, label) = arc;
+                        if feq(from, u) && feq(to, v) {
+                            proof { lemma_seq_index_in_map_to_set(arcs_seq, it@.0 - 1); }
+                            return Some(label.clone_plus());
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |l: L::V| !#[trigger] self@.1.contains((u@, v@, l)) by {
+                                if self@.1.contains((u@, v@, l)) {
+                                    lemma_map_to_set_contains_index(arcs_seq, (u@, v@, l));
+                                }
+                            }
+                        }
+                        return None;
+                    }
+                }
+            }
+        }
+
+        fn has_arc(&self, u: &V, v: &V) -> B {
+            let label = self.get_arc_label(u, v);
+            proof {
+                if let Some(l) = label {
+                    assert(self@.1.contains((u@, v@, l@)));
+                }
+            }
+            label.is_some()
+        }
+
+        fn out_neighbors(&self, v: &V) -> SetStEph<V> {
+            let mut out = SetStEph::<V>::empty();
+            let mut it = self.A.iter();
+            let ghost arcs_seq = it@.1;
+            let ghost arcs_view = self@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_LabGraph::<V, L>(),
+                    it@.0 <= arcs_seq.len(),
+                    it@.1 == arcs_seq,
+                    arcs_seq.map(|i: int, e: LabEdge<V, L>| e@).to_set() == arcs_view,
+                    out@ == Set::<V::V>::new(|w: V::V|
+                        exists |i: int| #![auto] 0 <= i < it@.0 && arcs_seq[i]@.0 == v@ && arcs_seq[i]@.1 == w),
+                decreases arcs_seq.len() - it@.0,
+            {
+                match it.next() {
+                    Some(arc) => {
+                        let LabEdge(from, to, _) = arc;
+                        if feq(from, v) {
+                            let _ = out.insert(to.clone_plus());
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |w: V::V| out@.contains(w) implies spec_has_lab_arc(self@.1, v@, w) by {
+                                let i = choose |i: int| #![auto] 0 <= i < arcs_seq.len() && arcs_seq[i]@.0 == v@ && arcs_seq[i]@.1 == w;
+                                lemma_seq_index_in_map_to_set(arcs_seq, i);
+                                assert(self@.1.contains((v@, w, arcs_seq[i]@.2)));
+                            }
+                            assert forall |w: V::V| spec_has_lab_arc(self@.1, v@, w) implies out@.contains(w) by {
+                                let l = choose |l: L::V| #[trigger] self@.1.contains((v@, w, l));
+                                lemma_map_to_set_contains_index(arcs_seq, (v@, w, l));
+                            }
+                            assert(out@ =~= spec_lab_n_plus(self@.1, v@));
+                        }
+                        return out;
+                    }
+                }
+            }
+        }
+
+        fn in_neighbors(&self, v: &V) -> SetStEph<V> {
+            let mut out = SetStEph::<V>::empty();
+            let mut it = self.A.iter();
+            let ghost arcs_seq = it@.1;
+            let ghost arcs_view = self@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_LabGraph::<V, L>(),
+                    it@.0 <= arcs_seq.len(),
+                    it@.1 == arcs_seq,
+                    arcs_seq.map(|i: int, e: LabEdge<V, L>| e@).to_set() == arcs_view,
+                    out@ == Set::<V::V>::new(|u: V::V|
+                        exists |i: int| #![auto] 0 <= i < it@.0 && arcs_seq[i]@.0 == u && arcs_seq[i]@.1 == v@),
+                decreases arcs_seq.len() - it@.0,
+            {
+                match it.next() {
+                    Some(arc) => {
+                        let LabEdge(from, to, _) = arc;
+                        if feq(to, v) {
+                            let _ = out.insert(from.clone_plus());
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |u: V::V| out@.contains(u) implies spec_has_lab_arc(self@.1, u, v@) by {
+                                let i = choose |i: int| #![auto] 0 <= i < arcs_seq.len() && arcs_seq[i]@.0 == u && arcs_seq[i]@.1 == v@;
+                                lemma_seq_index_in_map_to_set(arcs_seq, i);
+                                assert(self@.1.contains((u, v@, arcs_seq[i]@.2)));
+                            }
+                            assert forall |u: V::V| spec_has_lab_arc(self@.1, u, v@) implies out@.contains(u) by {
+                                let l = choose |l: L::V| #[trigger] self@.1.contains((u, v@, l));
+                                lemma_map_to_set_contains_index(arcs_seq, (u, v@, l));
+                            }
+                            assert(out@ =~= spec_lab_n_minus(self@.1, v@));
+                        }
+                        return out;
+                    }
+                }
+            }
+        }
+    }
+
+    #[macro_export]
+    macro_rules! LabDirGraphLit {
+        () => {{
+            < $crate::Chap06::LabDirGraphStEph::LabDirGraphStEph::LabDirGraphStEph<_, _> >::empty()
+        }};
+        ( V: [ $( $v:expr ),* $(,)? ], A: [ $( ($a:expr, $b:expr, $l:expr) ),* $(,)? ] $(,)? ) => {{
+            let __vertices = $crate::SetLit![ $( $v ),* ];
+            let __arcs = $crate::SetLit![ $( $crate::Types::Types::LabEdge($a, $b, $l) ),* ];
+            < $crate::Chap06::LabDirGraphStEph::LabDirGraphStEph::LabDirGraphStEph<_, _> >::FromSets(__vertices, __arcs)
+        }};
+    }
+
+  } // verus!
+
+    impl<V: StT + Hash, L: StT + Hash> PartialEq for LabDirGraphStEph<V, L> {
+        fn eq(&self, other: &Self) -> bool { self.V == other.V && self.A == other.A }
+    }
+
+    impl<V: StT + Hash, L: StT + Hash> Eq for LabDirGraphStEph<V, L> {}
+
+    impl<V: StT + Hash, L: StT + Hash> Debug for LabDirGraphStEph<V, L> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            f.debug_struct("LabDirGraphStEph").field("V", &self.V).field("A", &self.A).finish()
+        }
+    }
+
+    impl<V: StT + Hash, L: StT + Hash> Display for LabDirGraphStEph<V, L> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result { write!(f, "LabDirGraph(V: {}, A: {})", self.V, self.A) }
+    }
+}
@@ -0,0 +1,445 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Chapter 6.1 ephemeral labeled undirected graph: `LabEdge(u, v, label)` labels the unordered pair {u, v},
+//! and each pair is stored once, in one orientation with one label.
+
+pub mod LabUnDirGraphStEph {
+
+    use vstd::prelude::*;
+
+verus! {
+
+    use std::fmt::{Formatter, Result, Debug, Display};
+    use std::hash::Hash;
+
+    #[cfg(verus_keep_ghost)]
+    use vstd::std_specs::hash::obeys_key_model;
+    #[cfg(verus_keep_ghost)]
+    use crate::vstdplus::feq::feq::*;
+    #[cfg(not(verus_keep_ghost))]
+    use crate::vstdplus::feq::feq::feq;
+    use crate::vstdplus::seq_set::*;
+    use crate::vstdplus::clone_plus::clone_plus::ClonePlus;
+    use crate::Chap05::SetStEph::SetStEph::*;
+    use crate::Chap06::LabDirGraphStEph::LabDirGraphStEph::{valid_key_type_LabGraph, spec_lab_graph_wf, spec_lab_one_label, spec_has_lab_arc};
+    use crate::Types::Types::*;
+
+    broadcast use {vstd::seq_lib::group_seq_properties, vstd::set::group_set_axioms, crate::vstdplus::feq::feq::group_feq_axioms};
+
+    /// {u, v} is an edge under some label, stored in either orientation.
+    pub open spec fn spec_has_lab_edge<V, L>(edges: Set<(V, V, L)>, u: V, v: V) -> bool {
+        spec_has_lab_arc(edges, u, v) || spec_has_lab_arc(edges, v, u)
+    }
+
+    /// No pair {u, v} with u != v is stored in both orientations, whatever the labels.
+    pub open spec fn spec_lab_one_orientation<V, L>(edges: Set<(V, V, L)>) -> bool {
+        forall |u: V, v: V, l1: L, l2: L| #![trigger edges.contains((u, v, l1)), edges.contains((v, u, l2))]
+            edges.contains((u, v, l1)) && edges.contains((v, u, l2)) ==> u == v
+    }
+
+    /// edges without the pair {u, v}, in either orientation.
+    pub open spec fn spec_lab_without_edge<V, L>(edges: Set<(V, V, L)>, u: V, v: V) -> Set<(V, V, L)> {
+        edges.filter(|e: (V, V, L)| !((e.0 == u && e.1 == v) || (e.0 == v && e.1 == u)))
+    }
+
+    /// N(v) over labeled edges.
+    pub open spec fn spec_lab_neighbors<V, L>(edges: Set<(V, V, L)>, v: V) -> Set<V> {
+        Set::new(|w: V| spec_has_lab_edge(edges, v, w))
+    }
+
+    /// The fields are private so that every value built by the trait satisfies `spec_wf`.
+    #[verifier::reject_recursive_types(V)]
+    #[verifier::reject_recursive_types(L)]
+    pub struct LabUnDirGraphStEph<V: StT + Hash, L: StT + Hash> {
+        V: SetStEph<V>,
+        E: SetStEph<LabEdge<V, L>>,
+    }
+
+    impl<V: StT + Hash, L: StT + Hash> View for LabUnDirGraphStEph<V, L> {
+        type V = (Set<<V as View>::V>, Set<(<V as View>::V, <V as View>::V, <L as View>::V)>);
+        closed spec fn view(&self) -> Self::V { (self.V@, self.E@) }
+    }
+
+    impl<V: StT + Hash, L: StT + Hash> Clone for LabUnDirGraphStEph<V, L> {
+        fn clone(&self) -> (clone: Self)
+            ensures clone@ == self@
+        { LabUnDirGraphStEph { V: self.V.clone(), E: self.E.clone() } }
+    }
+
+    pub trait LabUnDirGraphStEphTrait<V: StT + Hash, L: StT + Hash> :
+        View<V = (Set<<V as View>::V>, Set<(<V as View>::V, <V as View>::V, <L as View>::V)>)> + Sized {
+
+        /// Every edge endpoint is a vertex and every pair is stored in one orientation with one label.
+        spec fn spec_wf(&self) -> bool;
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn empty() -> (empty: Self)
+            requires valid_key_type_LabGraph::<V, L>()
+            ensures
+                empty.spec_wf(),
+                empty@.0 == Set::<V::V>::empty(),
+                empty@.1 == Set::<(V::V, V::V, L::V)>::empty();
+
+        /// Keeps the first labeled edge met for each pair {u, v}, in the orientation it was met.
+        /// APAS: Work Θ(|E|), Span Θ(|E|)
+        fn FromSets(vertices: SetStEph<V>, edges: SetStEph<LabEdge<V, L>>) -> (g: Self)
+            requires valid_key_type_LabGraph::<V, L>(), valid_key_type::<Edge<V>>()
+            ensures
+                g@.0 == vertices@,
+                g@.1.subset_of(edges@),
+                forall |u: V::V, v: V::V, l: L::V| #[trigger] edges@.contains((u, v, l)) ==> spec_has_lab_edge(g@.1, u, v),
+                spec_lab_one_orientation(edges@) && spec_lab_one_label(edges@) ==> g@.1 == edges@,
+                g.spec_wf() == spec_lab_graph_wf(vertices@, edges@);
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn vertices(&self) -> (vertices: &SetStEph<V>)
+            ensures vertices@ == self@.0;
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn labeled_edges(&self) -> (edges: &SetStEph<LabEdge<V, L>>)
+            ensures edges@ == self@.1;
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn sizeV(&self) -> (count: N)
+            ensures count == self@.0.len();
+
+        /// |E|, one per unordered pair.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn sizeE(&self) -> (count: N)
+            ensures count == self@.1.len();
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn add_vertex(&mut self, v: V)
+            requires valid_key_type_LabGraph::<V, L>()
+            ensures
+                self@.0 == old(self)@.0.insert(v@),
+                self@.1 == old(self)@.1;
+
+        /// Adds the edge {u, v} with the given label, replacing any label it had, and adds u and v as vertices.
+        /// APAS: Work Θ(|E|), Span Θ(|E|)
+        fn add_labeled_edge(&mut self, u: V, v: V, label: L)
+            requires
+                valid_key_type_LabGraph::<V, L>(),
+                old(self).spec_wf(),
+            ensures
+                self.spec_wf(),
+                self@.0 == old(self)@.0.insert(u@).insert(v@),
+                self@.1 == spec_lab_without_edge(old(self)@.1, u@, v@).insert((u@, v@, label@));
+
+        /// The label (weight) of edge {u, v}, if there is one.
+        /// APAS: Work Θ(|E|), Span Θ(|E|)
+        fn get_edge_label(&self, u: &V, v: &V) -> (label: Option<L>)
+            requires valid_key_type_LabGraph::<V, L>()
+            ensures
+                label matches Some(l) ==> self@.1.contains((u@, v@, l@)) || self@.1.contains((v@, u@, l@)),
+                label is None ==> !spec_has_lab_edge(self@.1, u@, v@);
+
+        /// Whether {u, v} is an edge under some label.
+        /// APAS: Work Θ(|E|), Span Θ(|E|)
+        fn has_edge(&self, u: &V, v: &V) -> (has: B)
+            requires valid_key_type_LabGraph::<V, L>()
+            ensures has == spec_has_lab_edge(self@.1, u@, v@);
+
+        /// APAS: Work Θ(|E|), Span Θ(|E|)
+        fn Neighbors(&self, v: &V) -> (neighbors: SetStEph<V>)
+            requires valid_key_type_LabGraph::<V, L>()
+            ensures neighbors@ == spec_lab_neighbors(self@.1, v@);
+
+        /// |N(v)|
+        /// APAS: Work Θ(|E|), Span Θ(|E|)
+        fn Degree(&self, v: &V) -> (degree: N)
+            requires valid_key_type_LabGraph::<V, L>()
+            ensures degree == spec_lab_neighbors(self@.1, v@).len();
+    }
+
+    impl<V: StT + Hash, L: StT + Hash> LabUnDirGraphStEphTrait<V, L> for LabUnDirGraphStEph<V, L> {
+
+        open spec fn spec_wf(&self) -> bool {
+            spec_lab_graph_wf(self@.0, self@.1) && spec_lab_one_orientation(self@.1) && spec_lab_one_label(self@.1)
+        }
+
+        fn empty() -> LabUnDirGraphStEph<V, L> {
+            LabUnDirGraphStEph { V: SetStEph::empty(), E: SetStEph::empty() }
+        }
+
+        fn FromSets(vertices: SetStEph<V>, edges: SetStEph<LabEdge<V, L>>) -> LabUnDirGraphStEph<V, L> {
+            let mut kept = SetStEph::<LabEdge<V, L>>::empty();
+            let mut pairs = SetStEph::<Edge<V>>::empty();
+            let mut it = edges.iter();
+            let ghost edges_seq = it@.1;
+            let ghost edges_view = edges@;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_LabGraph::<V, L>(),
+                    valid_key_type::<Edge<V>>(),
+                    it@.0 <= edges_seq.len(),
+                    it@.1 == edges_seq,
+                    edges_seq.map(|i: int, e: LabEdge<V, L>| e@).to_set() == edges_view,
+                    kept@.subset_of(edges_view),
+                    spec_lab_one_orientation(kept@),
+                    spec_lab_one_label(kept@),
+                    forall |u: V::V, v: V::V, l: L::V| #[trigger] kept@.contains((u, v, l)) ==> pairs@.contains((u, v)),
+                    forall |u: V::V, v: V::V| #[trigger] pairs@.contains((u, v)) ==> spec_has_lab_arc(kept@, u, v),
+                    forall |i: int| #![trigger edges_seq[i]] 0 <= i < it@.0 ==>
+                        spec_has_lab_edge(kept@, edges_seq[i]@.0, edges_seq[i]@.1),
+                decreases edges_seq.len() - it@.0,
+            {
+                match it.next() {
+                    Some(edge) => {
+                        proof { lemma_seq_index_in_map_to_set(edges_seq, it@.0 - 1); }
+                        let LabEdge(a, b, l) = edge;
+                        if !pairs.mem(&Edge(a.clone_plus(), b.clone_plus())) && !pairs.mem(&Edge(b.clone_plus(), a.clone_plus())) {
+                            let ghost before = kept@;
+                            let _ = kept.insert(LabEdge(a.clone_plus(), b.clone_plus(), l.clone_plus()));
+                            let _ = pairs.insert(Edge(a.clone_plus(), b.clone_plus()));
+                            proof {
+                                assert(kept@.contains((a@, b@, l@)));
+                                assert forall |l2: L::V| !#[trigger] before.contains((a@, b@, l2)) && !before.contains((b@, a@, l2)) by {}
+                            }
+                        } else {
+                            proof {
+                                if pairs@.contains((a@, b@)) {
+                                    assert(spec_has_lab_arc(kept@, a@, b@));
+                                } else {
+                                    assert(spec_has_lab_arc(kept@, b@, a@));
+                                }
+                            }
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |u: V::V, v: V::V, l: L::V| #[trigger] edges_view.contains((u, v, l)) implies
+                                spec_has_lab_edge(kept@, u, v) by {
+                                lemma_map_to_set_contains_index(edges_seq, (u, v, l));
+                            }
+                            if spec_lab_one_orientation(edges_view) && spec_lab_one_label(edges_view) {
+                                assert forall |e: (V::V, V::V, L::V)| edges_view.contains(e) implies #[trigger] kept@.contains(e) by {
+                                    assert(edges_view.contains((e.0, e.1, e.2)));
+                                    if spec_has_lab_arc(kept@, e.0, e.1) {
+                                        let l = choose |l: L::V| #[trigger] kept@.contains((e.0, e.1, l));
+                                        assert(edges_view.contains((e.0, e.1, l)));
+                                    } else {
+                                        let l = choose |l: L::V| #[trigger] kept@.contains((e.1, e.0, l));
+                                        assert(edges_view.contains((e.1, e.0, l)));
+                                        assert(e.0 == e.1);
+                                    }
+                                }
+                                assert(kept@ =~= edges_view);
+                            }
+                            if spec_lab_graph_wf(vertices@, kept@) {
+                                assert forall |u: V::V, v: V::V, l: L::V| #[trigger] edges_view.contains((u, v, l)) implies
+                                    vertices@.contains(u) && vertices@.contains(v) by {
+                                    if spec_has_lab_arc(kept@, u, v) {
+                                        let l2 = choose |l2: L::V| #[trigger] kept@.contains((u, v, l2));
+                                    } else {
+                                        let l2 = choose |l2: L::V| #[trigger] kept@.contains((v, u, l2));
+                                    }
+                                }
+                            }
+                            if spec_lab_graph_wf(vertices@, edges_view) {
+                                assert forall |u: V::V, v: V::V, l: L::V| #[trigger] kept@.contains((u, v, l)) implies
+                                    vertices@.contains(u) && vertices@.contains(v) by {
+                                    assert(edges_view.contains((u, v, l)));
+                                }
+                            }
+                        }
+                        return LabUnDirGraphStEph { V: vertices, E: kept };
+                    }
+                }
+            }
+        }
+
+        fn vertices(&self) -> &SetStEph<V> { &self.V }
+
+        fn labeled_edges(&self) -> &SetStEph<LabEdge<V, L>> { &self.E }
+
+        fn sizeV(&self) -> (count: N) { self.V.size() }
+
+        fn sizeE(&self) -> (count: N) { self.E.size() }
+
+        fn add_vertex(&mut self, v: V) {
+            let _ = self.V.insert(v);
+        }
+
+        fn add_labeled_edge(&mut self, u: V, v: V, label: L) {
+            let ghost old_edges = self@.1;
+            if let Some(l) = self.get_edge_label(&u, &v) {
+                let ghost lv = l@;
+                let _ = self.E.delete(&LabEdge(u.clone_plus(), v.clone_plus(), l.clone_plus()));
+                let _ = self.E.delete(&LabEdge(v.clone_plus(), u.clone_plus(), l));
+                proof {
+                    assert forall |e: (V::V, V::V, L::V)| old_edges.contains(e) && ((e.0 == u@ && e.1 == v@) || (e.0 == v@ && e.1 == u@))
+                        implies e.2 == lv by {
+                        assert(old_edges.contains((e.0, e.1, e.2)));
+                        if old_edges.contains((u@, v@, lv)) {
+                            if e.0 == v@ && e.1 == u@ { assert(u@ == v@); }
+                        } else {
+                            assert(old_edges.contains((v@, u@, lv)));
+                            if e.0 == u@ && e.1 == v@ { assert(u@ == v@); }
+                        }
+                    }
+                }
+            } else {
+                proof {
+                    assert forall |e: (V::V, V::V, L::V)| old_edges.contains(e) implies
+                        !((e.0 == u@ && e.1 == v@) || (e.0 == v@ && e.1 == u@)) by {
+                        assert(old_edges.contains((e.0, e.1, e.2)));
+                    }
+                }
+            }
+            proof { assert(self@.1 =~= spec_lab_without_edge(old_edges, u@, v@)); }
+            let _ = self.V.insert(u.clone_plus());
+            let _ = self.V.insert(v.clone_plus());
+            let _ = self.E.insert(LabEdge(u, v, label));
+        }
+
+        fn get_edge_label(&self, u: &V, v: &V) -> Option<L> {
+            let mut it = self.E.iter();
+            let ghost edges_seq = it@.1;
+            let ghost edges_view = self@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_LabGraph::<V, L>(),
+                    it@.0 <= edges_seq.len(),
+                    it@.1 == edges_seq,
+                    edges_seq.map(|i: int, e: LabEdge<V, L>| e@).to_set() == edges_view,
+                    forall |j: int| #![auto] 0 <= j < it@.0 ==>
+                        !(edges_seq[j]@.0 == u@ && edges_seq[j]@.1 == v@) && !(edges_seq[j]@.0 == v@ && edges_seq[j]@.1 == u@),
+                decreases edges_seq.len() - it@.0,
+            {
+                match it.next() {
+                    Some(edge) => {
+                        let LabEdge(a, b, label) = edge;
+                        if (feq(a, u) && feq(b, v)) || (feq(a, v) && feq(b, u)) {
+                            proof { lemma_seq_index_in_map_to_set(edges_seq, it@.0 - 1); }
+                            return Some(label.clone_plus());
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |l: L::V| !#[trigger] self@.1.contains((u@, v@, l)) by {
+                                if self@.1.contains((u@, v@, l)) {
+                                    lemma_map_to_set_contains_index(edges_seq, (u@, v@, l));
+                                }
+                            }
+                            assert forall |l: L::V| !#[trigger] self@.1.contains((v@, u@, l)) by {
+                                if self@.1.contains((v@, u@, l)) {
+                                    lemma_map_to_set_contains_index(edges_seq, (v@, u@, l));
+                                }
+                            }
+                        }
+                        return None;
+                    }
+                }
+            }
+        }
+
+        fn has_edge(&self, u: &V, v: &V) -> B {
+            let label = self.get_edge_label(u, v);
+            proof {
+                if let Some(l) = label {
+                    if self@.1.contains((u@, v@, l@)) {
+                        assert(spec_has_lab_arc(self@.1, u@, v@));
+                    } else {
+                        assert(spec_has_lab_arc(self@.1, v@, u@));
+                    }
+                }
+            }
+            label.is_some()
+        }
+
+        fn Neighbors(&self, v: &V) -> SetStEph<V> {
+            let mut out = SetStEph::<V>::empty();
+            let mut it = self.E.iter();
+            let ghost edges_seq = it@.1;
+            let ghost edges_view = self@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_LabGraph::<V, L>(),
+                    it@.0 <= edges_seq.len(),
+                    it@.1 == edges_seq,
+                    edges_seq.map(|i: int, e: LabEdge<V, L>| e@).to_set() == edges_view,
+                    out@ == Set::<V::V>::new(|w: V::V|
+                        exists |i: int| #![auto] 0 <= i < it@.0 &&
+                            ((edges_seq[i]@.0 == v@ && edges_seq[i]@.1 == w) || (edges_seq[i]@.1 == v@ && edges_seq[i]@.0 == w))),
+                decreases edges_seq.len() - it@.0,
+            {
+                match it.next() {
+                    Some(edge) => {
+                        let LabEdge(a, b, _) = edge;
+                        if feq(a, v) {
+                            let _ = out.insert(b.clone_plus());
+                        }
+                        if feq(b, v) {
+                            let _ = out.insert(a.clone_plus());
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |w: V::V| out@.contains(w) implies spec_has_lab_edge(self@.1, v@, w) by {
+                                let i = choose |i: int| #![auto] 0 <= i < edges_seq.len() &&
+                                    ((edges_seq[i]@.0 == v@ && edges_seq[i]@.1 == w) || (edges_seq[i]@.1 == v@ && edges_seq[i]@.0 == w));
+                                lemma_seq_index_in_map_to_set(edges_seq, i);
+                                assert(self@.1.contains(edges_seq[i]@));
+                            }
+                            assert forall |w: V::V| spec_has_lab_edge(self@.1, v@, w) implies out@.contains(w) by {
+                                if spec_has_lab_arc(self@.1, v@, w) {
+                                    let l = choose |l: L::V| #[trigger] self@.1.contains((v@, w, l));
+                                    lemma_map_to_set_contains_index(edges_seq, (v@, w, l));
+                                } else {
+                                    let l = choose |l: L::V| #[trigger] self@.1.contains((w, v@, l));
+                                    lemma_map_to_set_contains_index(edges_seq, (w, v@, l));
+                                }
+                            }
+                            assert(out@ =~= spec_lab_neighbors(self@.1, v@));
+                        }
+                        return out;
+                    }
+                }
+            }
+        }
+
+        fn Degree(&self, v: &V) -> (degree: N) { self.Neighbors(v).size() }
+    }
+
+    #[macro_export]
+    macro_rules! LabUnDirGraphLit {
+        () => {{
+            < $crate::Chap06::LabUnDirGraphStEph::LabUnDirGraphStEph::LabUnDirGraphStEph<_, _> >::empty()
+        }};
+        ( V: [ $( $v:expr ),* $(,)? ], E: [ $( ($a:expr, $b:expr, $l:expr) ),* $(,)? ] $(,)? ) => {{
+            let __vertices = $crate::SetLit![ $( $v ),* ];
+            let __edges = $crate::SetLit![ $( $crate::Types::Types::LabEdge($a, $b, $l) ),* ];
+            < $crate::Chap06::LabUnDirGraphStEph::LabUnDirGraphStEph::LabUnDirGraphStEph<_, _> >::FromSets(__vertices, __edges)
+        }};
+    }
+
+  } // verus!
+
+    /// Equal as undirected graphs: each labeled edge of one is in the other in some orientation.
+    impl<V: StT + Hash, L: StT + Hash> PartialEq for LabUnDirGraphStEph<V, L> {
+        fn eq(&self, other: &Self) -> bool {
+            self.V == other.V
+                && self.E.size() == other.E.size()
+                && self.E.iter().all(|LabEdge(a, b, l)|
+                    other.E.mem(&LabEdge(a.clone(), b.clone(), l.clone())) || other.E.mem(&LabEdge(b.clone(), a.clone(), l.clone())))
+        }
+    }
+
+    impl<V: StT + Hash, L: StT + Hash> Eq for LabUnDirGraphStEph<V, L> {}
+
+    impl<V: StT + Hash, L: StT + Hash> Debug for LabUnDirGraphStEph<V, L> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            f.debug_struct("LabUnDirGraphStEph").field("V", &self.V).field("E", &self.E).finish()
+        }
+    }
+
+    impl<V: StT + Hash, L: StT + Hash> Display for LabUnDirGraphStEph<V, L> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result { write!(f, "LabUnDirGraph(V: {}, E: {})", self.V, self.E) }
+    }
+}
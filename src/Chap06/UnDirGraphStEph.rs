@@ -0,0 +1,294 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Chapter 6.1 ephemeral undirected graph: an `Edge(u, v)` stands for the unordered pair {u, v},
+//! and each pair is stored in only one orientation.
+
+pub mod UnDirGraphStEph {
+
+    use vstd::prelude::*;
+
+verus! {
+
+    use std::fmt::{Formatter, Result, Debug, Display};
+    use std::hash::Hash;
+
+    #[cfg(verus_keep_ghost)]
+    use vstd::std_specs::hash::obeys_key_model;
+    #[cfg(verus_keep_ghost)]
+    use crate::vstdplus::feq::feq::*;
+    #[cfg(not(verus_keep_ghost))]
+    use crate::vstdplus::feq::feq::feq;
+    use crate::vstdplus::seq_set::*;
+    use crate::vstdplus::clone_plus::clone_plus::ClonePlus;
+    use crate::Chap05::SetStEph::SetStEph::*;
+    use crate::Chap06::DirGraphStEph::DirGraphStEph::{valid_key_type_Graph, spec_graph_wf};
+    use crate::Types::Types::*;
+
+    broadcast use {vstd::seq_lib::group_seq_properties, vstd::set::group_set_axioms, crate::vstdplus::feq::feq::group_feq_axioms};
+
+    /// u and v are adjacent when either orientation of {u, v} is stored.
+    pub open spec fn spec_adjacent<V>(edges: Set<(V, V)>, u: V, v: V) -> bool {
+        edges.contains((u, v)) || edges.contains((v, u))
+    }
+
+    /// No pair {u, v} with u != v is stored in both orientations, so |E| counts unordered pairs.
+    pub open spec fn spec_one_orientation<V>(edges: Set<(V, V)>) -> bool {
+        forall |u: V, v: V| #![trigger edges.contains((u, v)), edges.contains((v, u))]
+            edges.contains((u, v)) && edges.contains((v, u)) ==> u == v
+    }
+
+    /// N(v) = { w | {v, w} in E }.
+    pub open spec fn spec_neighbors<V>(edges: Set<(V, V)>, v: V) -> Set<V> {
+        Set::new(|w: V| spec_adjacent(edges, v, w))
+    }
+
+    /// The fields are private so that every value built by the trait satisfies `spec_wf`.
+    #[verifier::reject_recursive_types(V)]
+    pub struct UnDirGraphStEph<V: StT + Hash> {
+        V: SetStEph<V>,
+        E: SetStEph<Edge<V>>,
+    }
+
+    impl<V: StT + Hash> View for UnDirGraphStEph<V> {
+        type V = (Set<<V as View>::V>, Set<(<V as View>::V, <V as View>::V)>);
+        closed spec fn view(&self) -> Self::V { (self.V@, self.E@) }
+    }
+
+    impl<V: StT + Hash> Clone for UnDirGraphStEph<V> {
+        fn clone(&self) -> (clone: Self)
+            ensures clone@ == self@
+        { UnDirGraphStEph { V: self.V.clone(), E: self.E.clone() } }
+    }
+
+    pub trait UnDirGraphStEphTrait<V: StT + Hash> :
+        View<V = (Set<<V as View>::V>, Set<(<V as View>::V, <V as View>::V)>)> + Sized {
+
+        /// Every edge endpoint is a vertex and every edge is stored in one orientation.
+        spec fn spec_wf(&self) -> bool;
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn empty() -> (empty: Self)
+            requires valid_key_type_Graph::<V>()
+            ensures
+                empty.spec_wf(),
+                empty@.0 == Set::<V::V>::empty(),
+                empty@.1 == Set::<(V::V, V::V)>::empty();
+
+        /// Keeps the first orientation met of each pair given both ways.
+        /// APAS: Work Θ(|E|), Span Θ(|E|)
+        fn FromSets(vertices: SetStEph<V>, edges: SetStEph<Edge<V>>) -> (g: Self)
+            requires valid_key_type_Graph::<V>()
+            ensures
+                g@.0 == vertices@,
+                g@.1.subset_of(edges@),
+                forall |u: V::V, v: V::V| #[trigger] spec_adjacent(g@.1, u, v) == spec_adjacent(edges@, u, v),
+                spec_one_orientation(edges@) ==> g@.1 == edges@,
+                g.spec_wf() == spec_graph_wf(vertices@, edges@);
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn vertices(&self) -> (vertices: &SetStEph<V>)
+            ensures vertices@ == self@.0;
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn edges(&self) -> (edges: &SetStEph<Edge<V>>)
+            ensures edges@ == self@.1;
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn sizeV(&self) -> (count: N)
+            ensures count == self@.0.len();
+
+        /// |E|, one per unordered pair.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn sizeE(&self) -> (count: N)
+            ensures count == self@.1.len();
+
+        /// Whether {u, v} is an edge.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn Neighbor(&self, u: &V, v: &V) -> (is: B)
+            requires valid_key_type_Graph::<V>()
+            ensures is == spec_adjacent(self@.1, u@, v@);
+
+        /// APAS: Work Θ(|E|), Span Θ(|E|)
+        fn Neighbors(&self, v: &V) -> (neighbors: SetStEph<V>)
+            requires valid_key_type_Graph::<V>()
+            ensures neighbors@ == spec_neighbors(self@.1, v@);
+
+        /// |N(v)|
+        /// APAS: Work Θ(|E|), Span Θ(|E|)
+        fn Degree(&self, v: &V) -> (degree: N)
+            requires valid_key_type_Graph::<V>()
+            ensures degree == spec_neighbors(self@.1, v@).len();
+
+        /// Whether edge e is incident on v.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn Incident(&self, e: &Edge<V>, v: &V) -> (incident: B)
+            requires valid_key_type_Graph::<V>()
+            ensures incident == (e@.0 == v@ || e@.1 == v@);
+    }
+
+    impl<V: StT + Hash> UnDirGraphStEphTrait<V> for UnDirGraphStEph<V> {
+
+        open spec fn spec_wf(&self) -> bool { spec_graph_wf(self@.0, self@.1) && spec_one_orientation(self@.1) }
+
+        fn empty() -> UnDirGraphStEph<V> {
+            UnDirGraphStEph { V: SetStEph::empty(), E: SetStEph::empty() }
+        }
+
+        fn FromSets(vertices: SetStEph<V>, edges: SetStEph<Edge<V>>) -> UnDirGraphStEph<V> {
+            let mut canonical = SetStEph::<Edge<V>>::empty();
+            let mut it = edges.iter();
+            let ghost edges_seq = it@.1;
+            let ghost edges_view = edges@;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Graph::<V>(),
+                    it@.0 <= edges_seq.len(),
+                    it@.1 == edges_seq,
+                    edges_seq.map(|i: int, e: Edge<V>| e@).to_set() == edges_view,
+                    canonical@.subset_of(edges_view),
+                    spec_one_orientation(canonical@),
+                    forall |i: int| #![trigger edges_seq[i]] 0 <= i < it@.0 ==>
+                        spec_adjacent(canonical@, edges_seq[i]@.0, edges_seq[i]@.1),
+                decreases edges_seq.len() - it@.0,
+            {
+                match it.next() {
+                    Some(edge) => {
+                        proof { lemma_seq_index_in_map_to_set(edges_seq, it@.0 - 1); }
+                        let Edge(a, b) = edge;
+                        if !canonical.mem(&Edge(b.clone_plus(), a.clone_plus())) {
+                            let _ = canonical.insert(Edge(a.clone_plus(), b.clone_plus()));
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |u: V::V, v: V::V| #[trigger] edges_view.contains((u, v)) implies
+                                spec_adjacent(canonical@, u, v) by {
+                                lemma_map_to_set_contains_index(edges_seq, (u, v));
+                            }
+                            assert(forall |u: V::V, v: V::V| #[trigger] spec_adjacent(canonical@, u, v) == spec_adjacent(edges_view, u, v));
+                            if spec_one_orientation(edges_view) {
+                                assert forall |e: (V::V, V::V)| edges_view.contains(e) implies #[trigger] canonical@.contains(e) by {
+                                    assert(edges_view.contains((e.0, e.1)));
+                                }
+                                assert(canonical@ =~= edges_view);
+                            }
+                            assert(spec_graph_wf(vertices@, canonical@) == spec_graph_wf(vertices@, edges_view)) by {
+                                if spec_graph_wf(vertices@, canonical@) {
+                                    assert forall |u: V::V, v: V::V| #[trigger] edges_view.contains((u, v)) implies
+                                        vertices@.contains(u) && vertices@.contains(v) by {
+                                        if !canonical@.contains((u, v)) { assert(canonical@.contains((v, u))); }
+                                    }
+                                }
+                            }
+                        }
+                        return UnDirGraphStEph { V: vertices, E: canonical };
+                    }
+                }
+            }
+        }
+
+        fn vertices(&self) -> &SetStEph<V> { &self.V }
+
+        fn edges(&self) -> &SetStEph<Edge<V>> { &self.E }
+
+        fn sizeV(&self) -> (count: N) { self.V.size() }
+
+        fn sizeE(&self) -> (count: N) { self.E.size() }
+
+        fn Neighbor(&self, u: &V, v: &V) -> B {
+            self.E.mem(&Edge(u.clone_plus(), v.clone_plus())) || self.E.mem(&Edge(v.clone_plus(), u.clone_plus()))
+        }
+
+        fn Neighbors(&self, v: &V) -> SetStEph<V> {
+            let mut out = SetStEph::<V>::empty();
+            let mut it = self.E.iter();
+            let ghost edges_seq = it@.1;
+            let ghost edges_view = self@.1;
+
+            #[verifier::loop_isolation(false)]
+            loop
+                invariant
+                    valid_key_type_Graph::<V>(),
+                    it@.0 <= edges_seq.len(),
+                    it@.1 == edges_seq,
+                    edges_seq.map(|i: int, e: Edge<V>| e@).to_set() == edges_view,
+                    out@ == Set::<V::V>::new(|w: V::V|
+                        exists |i: int| #![auto] 0 <= i < it@.0 &&
+                            ((edges_seq[i]@.0 == v@ && edges_seq[i]@.1 == w) || (edges_seq[i]@.1 == v@ && edges_seq[i]@.0 == w))),
+                decreases edges_seq.len() - it@.0,
+            {
+                match it.next() {
+                    Some(edge) => {
+                        let Edge(a, b) = edge;
+                        if feq(a, v) {
+                            let _ = out.insert(b.clone_plus());
+                        }
+                        if feq(b, v) {
+                            let _ = out.insert(a.clone_plus());
+                        }
+                    },
+                    None => {
+                        proof {
+                            assert forall |w: V::V| out@.contains(w) implies spec_adjacent(self@.1, v@, w) by {
+                                let i = choose |i: int| #![auto] 0 <= i < edges_seq.len() &&
+                                    ((edges_seq[i]@.0 == v@ && edges_seq[i]@.1 == w) || (edges_seq[i]@.1 == v@ && edges_seq[i]@.0 == w));
+                                lemma_seq_index_in_map_to_set(edges_seq, i);
+                            }
+                            assert forall |w: V::V| spec_adjacent(self@.1, v@, w) implies out@.contains(w) by {
+                                if self@.1.contains((v@, w)) {
+                                    lemma_map_to_set_contains_index(edges_seq, (v@, w));
+                                } else {
+                                    lemma_map_to_set_contains_index(edges_seq, (w, v@));
+                                }
+                            }
+                            assert(out@ =~= spec_neighbors(self@.1, v@));
+                        }
+                        return out;
+                    }
+                }
+            }
+        }
+
+        fn Degree(&self, v: &V) -> (degree: N) { self.Neighbors(v).size() }
+
+        fn Incident(&self, e: &Edge<V>, v: &V) -> B {
+            feq(&e.0, v) || feq(&e.1, v)
+        }
+    }
+
+    #[macro_export]
+    macro_rules! UnDirGraphLit {
+        () => {{
+            < $crate::Chap06::UnDirGraphStEph::UnDirGraphStEph::UnDirGraphStEph<_> >::empty()
+        }};
+        ( V: [ $( $v:expr ),* $(,)? ], E: [ $( ($a:expr, $b:expr) ),* $(,)? ] $(,)? ) => {{
+            let __vertices = $crate::SetLit![ $( $v ),* ];
+            let __edges = $crate::SetLit![ $( $crate::Types::Types::Edge($a, $b) ),* ];
+            < $crate::Chap06::UnDirGraphStEph::UnDirGraphStEph::UnDirGraphStEph<_> >::FromSets(__vertices, __edges)
+        }};
+    }
+
+  } // verus!
+
+    /// Equal as undirected graphs: each edge of one is an edge of the other in some orientation.
+    impl<V: StT + Hash> PartialEq for UnDirGraphStEph<V> {
+        fn eq(&self, other: &Self) -> bool {
+            self.V == other.V
+                && self.E.size() == other.E.size()
+                && self.E.iter().all(|Edge(a, b)| other.E.mem(&Edge(a.clone(), b.clone())) || other.E.mem(&Edge(b.clone(), a.clone())))
+        }
+    }
+
+    impl<V: StT + Hash> Eq for UnDirGraphStEph<V> {}
+
+    impl<V: StT + Hash> Debug for UnDirGraphStEph<V> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            f.debug_struct("UnDirGraphStEph").field("V", &self.V).field("E", &self.E).finish()
+        }
+    }
+
+    impl<V: StT + Hash> Display for UnDirGraphStEph<V> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result { write!(f, "UnDirGraph(V: {}, E: {})", self.V, self.E) }
+    }
+}
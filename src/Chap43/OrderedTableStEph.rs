@@ -0,0 +1,879 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Chapter 43 ephemeral ordered table built on a `Vec<Pair<K, V>>` sorted by `TotalOrder` keys.
+//!
+//! The sorted array trades update cost for simple proofs: find, next, prev, rank and select
+//! are Θ(lg n) or better, but insert, delete, split and join shift or copy entries and take
+//! Θ(n) work, where the balanced trees APAS uses take Θ(lg n). Prefer it for tables that are
+//! built once and then mostly queried.
+
+pub mod OrderedTableStEph {
+
+    use vstd::prelude::*;
+
+verus! {
+
+    use core::cmp::Ordering;
+    use std::fmt::{Formatter, Result, Debug, Display};
+
+    #[cfg(verus_keep_ghost)]
+    use vstd::std_specs::clone::*;
+    #[cfg(verus_keep_ghost)]
+    use crate::vstdplus::feq::feq::*;
+    use crate::vstdplus::clone_plus::clone_plus::ClonePlus;
+    use crate::vstdplus::total_order::total_order::TotalOrder;
+    use crate::Types::Types::*;
+
+    broadcast use {vstd::seq_lib::group_seq_properties, vstd::set::group_set_axioms, crate::vstdplus::feq::feq::group_feq_axioms};
+
+    pub open spec fn valid_key_type_OrderedTable<K: Eq + View + Clone + Sized, V: Eq + View + Clone + Sized>() -> bool {
+        obeys_feq_full::<K>() && obeys_feq_full::<V>()
+    }
+
+    pub open spec fn spec_lt<K: TotalOrder>(a: K, b: K) -> bool {
+        a.le(b) && a != b
+    }
+
+    /// Keys strictly increase along s.
+    pub open spec fn sorted_keys<K: TotalOrder, V>(s: Seq<Pair<K, V>>) -> bool {
+        forall |i: int, j: int| #![trigger s[i], s[j]] 0 <= i < j < s.len() ==> spec_lt(s[i].0, s[j].0)
+    }
+
+    pub open spec fn entries_view<K: View, V: View>(s: Seq<Pair<K, V>>) -> Map<K::V, V::V> {
+        Map::new(
+            |k: K::V| exists |i: int| #![trigger s[i]] 0 <= i < s.len() && s[i].0@ == k,
+            |k: K::V| s[choose |i: int| #![trigger s[i]] 0 <= i < s.len() && s[i].0@ == k].1@)
+    }
+
+    pub proof fn lemma_entries_view_at<K: StT + TotalOrder, V: StT>(s: Seq<Pair<K, V>>, i: int)
+        requires valid_key_type_OrderedTable::<K, V>(), sorted_keys(s), 0 <= i < s.len()
+        ensures entries_view(s).contains_key(s[i].0@), entries_view(s)[s[i].0@] == s[i].1@
+    {
+        let j = choose |j: int| #![trigger s[j]] 0 <= j < s.len() && s[j].0@ == s[i].0@;
+        assert(s[j].0 == s[i].0);
+        if j < i { assert(spec_lt(s[j].0, s[i].0)); }
+        if i < j { assert(spec_lt(s[i].0, s[j].0)); }
+    }
+
+    /// entries_view(s) is m when s lists exactly the entries of m.
+    pub proof fn lemma_entries_view_eq<K: StT + TotalOrder, V: StT>(s: Seq<Pair<K, V>>, m: Map<K::V, V::V>)
+        requires
+            valid_key_type_OrderedTable::<K, V>(),
+            sorted_keys(s),
+            forall |i: int| #![trigger s[i]] 0 <= i < s.len() ==> m.contains_key(s[i].0@) && m[s[i].0@] == s[i].1@,
+            forall |k: K::V| #[trigger] m.contains_key(k) ==> exists |i: int| #![trigger s[i]] 0 <= i < s.len() && s[i].0@ == k,
+        ensures entries_view(s) == m
+    {
+        assert forall |k: K::V| #[trigger] entries_view(s).contains_key(k) implies m.contains_key(k) && entries_view(s)[k] == m[k] by {
+            let i = choose |i: int| #![trigger s[i]] 0 <= i < s.len() && s[i].0@ == k;
+            lemma_entries_view_at(s, i);
+        }
+        assert(entries_view(s) =~= m);
+    }
+
+    pub proof fn lemma_entries_view_contains<K: StT + TotalOrder, V: StT>(s: Seq<Pair<K, V>>, k: K)
+        requires valid_key_type_OrderedTable::<K, V>()
+        ensures entries_view(s).contains_key(k@) <==> exists |i: int| #![trigger s[i]] 0 <= i < s.len() && s[i].0 == k
+    {
+        if entries_view(s).contains_key(k@) {
+            let i = choose |i: int| #![trigger s[i]] 0 <= i < s.len() && s[i].0@ == k@;
+            assert(s[i].0 == k);
+        }
+    }
+
+    pub proof fn lemma_entries_view_len<K: StT + TotalOrder, V: StT>(s: Seq<Pair<K, V>>)
+        requires valid_key_type_OrderedTable::<K, V>(), sorted_keys(s)
+        ensures entries_view(s).len() == s.len(), entries_view(s).dom().finite()
+    {
+        let ks = s.map(|i: int, p: Pair<K, V>| p.0@);
+        assert forall |i: int, j: int| 0 <= i < ks.len() && 0 <= j < ks.len() && i != j implies #[trigger] ks[i] != #[trigger] ks[j] by {
+            if i < j { assert(spec_lt(s[i].0, s[j].0)); } else { assert(spec_lt(s[j].0, s[i].0)); }
+        }
+        assert(ks.no_duplicates());
+        ks.unique_seq_to_set();
+        assert forall |k: K::V| #[trigger] ks.to_set().contains(k) implies entries_view(s).contains_key(k) by {
+            assert(ks.contains(k));
+            let i = choose |i: int| 0 <= i < ks.len() && ks[i] == k;
+            assert(s[i].0@ == k);
+        }
+        assert forall |k: K::V| #[trigger] entries_view(s).contains_key(k) implies ks.to_set().contains(k) by {
+            let i = choose |i: int| #![trigger s[i]] 0 <= i < s.len() && s[i].0@ == k;
+            assert(ks[i] == k);
+        }
+        assert(entries_view(s).dom() =~= ks.to_set());
+    }
+
+    /// Concatenating sorted runs unions their views.
+    pub proof fn lemma_entries_view_append<K: StT + TotalOrder, V: StT>(l: Seq<Pair<K, V>>, r: Seq<Pair<K, V>>)
+        requires valid_key_type_OrderedTable::<K, V>(), sorted_keys(l + r)
+        ensures
+            sorted_keys(l), sorted_keys(r),
+            entries_view(l + r) == entries_view(l).union_prefer_right(entries_view(r)),
+            entries_view(l).dom().disjoint(entries_view(r).dom()),
+    {
+        let s = l + r;
+        assert forall |i: int, j: int| #![trigger l[i], l[j]] 0 <= i < j < l.len() implies spec_lt(l[i].0, l[j].0) by {
+            assert(s[i] == l[i] && s[j] == l[j]);
+        }
+        assert forall |i: int, j: int| #![trigger r[i], r[j]] 0 <= i < j < r.len() implies spec_lt(r[i].0, r[j].0) by {
+            assert(s[l.len() + i] == r[i] && s[l.len() + j] == r[j]);
+        }
+        assert forall |k: K::V| #[trigger] entries_view(l).contains_key(k) implies !entries_view(r).contains_key(k) by {
+            let i = choose |i: int| #![trigger l[i]] 0 <= i < l.len() && l[i].0@ == k;
+            if entries_view(r).contains_key(k) {
+                let j = choose |j: int| #![trigger r[j]] 0 <= j < r.len() && r[j].0@ == k;
+                assert(s[i] == l[i] && s[l.len() + j] == r[j]);
+                assert(spec_lt(s[i].0, s[l.len() + j].0));
+                assert(l[i].0 == r[j].0);
+            }
+        }
+        let m = entries_view(l).union_prefer_right(entries_view(r));
+        assert forall |i: int| #![trigger s[i]] 0 <= i < s.len() implies m.contains_key(s[i].0@) && m[s[i].0@] == s[i].1@ by {
+            if i < l.len() {
+                assert(s[i] == l[i]);
+                lemma_entries_view_at(l, i);
+            } else {
+                assert(s[i] == r[i - l.len()]);
+                lemma_entries_view_at(r, i - l.len());
+            }
+        }
+        assert forall |k: K::V| #[trigger] m.contains_key(k) implies exists |i: int| #![trigger s[i]] 0 <= i < s.len() && s[i].0@ == k by {
+            if entries_view(l).contains_key(k) {
+                let i = choose |i: int| #![trigger l[i]] 0 <= i < l.len() && l[i].0@ == k;
+                assert(s[i] == l[i]);
+            } else {
+                let j = choose |j: int| #![trigger r[j]] 0 <= j < r.len() && r[j].0@ == k;
+                assert(s[l.len() + j] == r[j]);
+            }
+        }
+        lemma_entries_view_eq(s, m);
+    }
+
+    pub proof fn lemma_entries_view_remove<K: StT + TotalOrder, V: StT>(s: Seq<Pair<K, V>>, i: int)
+        requires valid_key_type_OrderedTable::<K, V>(), sorted_keys(s), 0 <= i < s.len()
+        ensures sorted_keys(s.remove(i)), entries_view(s.remove(i)) == entries_view(s).remove(s[i].0@)
+    {
+        let t = s.remove(i);
+        let m = entries_view(s).remove(s[i].0@);
+        assert forall |a: int, b: int| #![trigger t[a], t[b]] 0 <= a < b < t.len() implies spec_lt(t[a].0, t[b].0) by {
+            let a1 = if a < i { a } else { a + 1 };
+            let b1 = if b < i { b } else { b + 1 };
+            assert(t[a] == s[a1] && t[b] == s[b1]);
+        }
+        assert forall |j: int| #![trigger t[j]] 0 <= j < t.len() implies m.contains_key(t[j].0@) && m[t[j].0@] == t[j].1@ by {
+            let j1 = if j < i { j } else { j + 1 };
+            assert(t[j] == s[j1]);
+            lemma_entries_view_at(s, j1);
+            if j1 < i { assert(spec_lt(s[j1].0, s[i].0)); } else { assert(spec_lt(s[i].0, s[j1].0)); }
+        }
+        assert forall |k: K::V| #[trigger] m.contains_key(k) implies exists |j: int| #![trigger t[j]] 0 <= j < t.len() && t[j].0@ == k by {
+            let j1 = choose |j1: int| #![trigger s[j1]] 0 <= j1 < s.len() && s[j1].0@ == k;
+            if j1 < i { assert(t[j1] == s[j1]); } else { assert(t[j1 - 1] == s[j1]); }
+        }
+        lemma_entries_view_eq(t, m);
+    }
+
+    pub proof fn lemma_entries_view_insert<K: StT + TotalOrder, V: StT>(s: Seq<Pair<K, V>>, i: int, p: Pair<K, V>)
+        requires
+            valid_key_type_OrderedTable::<K, V>(),
+            sorted_keys(s), 0 <= i <= s.len(),
+            forall |j: int| #![trigger s[j]] 0 <= j < i ==> spec_lt(s[j].0, p.0),
+            forall |j: int| #![trigger s[j]] i <= j < s.len() ==> spec_lt(p.0, s[j].0),
+        ensures sorted_keys(s.insert(i, p)), entries_view(s.insert(i, p)) == entries_view(s).insert(p.0@, p.1@)
+    {
+        let t = s.insert(i, p);
+        let m = entries_view(s).insert(p.0@, p.1@);
+        assert forall |a: int, b: int| #![trigger t[a], t[b]] 0 <= a < b < t.len() implies spec_lt(t[a].0, t[b].0) by {
+            if a < i && b < i { assert(t[a] == s[a] && t[b] == s[b]); }
+            else if a < i && b == i { assert(t[a] == s[a]); }
+            else if a < i { assert(t[a] == s[a] && t[b] == s[b - 1]); }
+            else if a == i { assert(t[b] == s[b - 1]); }
+            else { assert(t[a] == s[a - 1] && t[b] == s[b - 1]); }
+        }
+        assert forall |j: int| #![trigger t[j]] 0 <= j < t.len() implies m.contains_key(t[j].0@) && m[t[j].0@] == t[j].1@ by {
+            if j != i {
+                let j1 = if j < i { j } else { j - 1 };
+                assert(t[j] == s[j1]);
+                lemma_entries_view_at(s, j1);
+                assert(t[j].0 != p.0);
+            }
+        }
+        assert forall |k: K::V| #[trigger] m.contains_key(k) implies exists |j: int| #![trigger t[j]] 0 <= j < t.len() && t[j].0@ == k by {
+            if k == p.0@ {
+                assert(t[i] == p);
+            } else {
+                let j1 = choose |j1: int| #![trigger s[j1]] 0 <= j1 < s.len() && s[j1].0@ == k;
+                if j1 < i { assert(t[j1] == s[j1]); } else { assert(t[j1 + 1] == s[j1]); }
+            }
+        }
+        lemma_entries_view_eq(t, m);
+    }
+
+    pub proof fn lemma_entries_view_update<K: StT + TotalOrder, V: StT>(s: Seq<Pair<K, V>>, i: int, p: Pair<K, V>)
+        requires valid_key_type_OrderedTable::<K, V>(), sorted_keys(s), 0 <= i < s.len(), p.0 == s[i].0
+        ensures sorted_keys(s.update(i, p)), entries_view(s.update(i, p)) == entries_view(s).insert(p.0@, p.1@)
+    {
+        let t = s.update(i, p);
+        let m = entries_view(s).insert(p.0@, p.1@);
+        assert forall |a: int, b: int| #![trigger t[a], t[b]] 0 <= a < b < t.len() implies spec_lt(t[a].0, t[b].0) by {
+            assert(t[a].0 == s[a].0 && t[b].0 == s[b].0);
+        }
+        assert forall |j: int| #![trigger t[j]] 0 <= j < t.len() implies m.contains_key(t[j].0@) && m[t[j].0@] == t[j].1@ by {
+            if j != i {
+                lemma_entries_view_at(s, j);
+                if j < i { assert(spec_lt(s[j].0, s[i].0)); } else { assert(spec_lt(s[i].0, s[j].0)); }
+            }
+        }
+        assert forall |k: K::V| #[trigger] m.contains_key(k) implies exists |j: int| #![trigger t[j]] 0 <= j < t.len() && t[j].0@ == k by {
+            if k == p.0@ {
+                assert(t[i] == p);
+            } else {
+                let j = choose |j: int| #![trigger s[j]] 0 <= j < s.len() && s[j].0@ == k;
+                assert(t[j].0 == s[j].0);
+            }
+        }
+        lemma_entries_view_eq(t, m);
+    }
+
+    pub proof fn lemma_sorted_subrange<K: TotalOrder, V>(s: Seq<Pair<K, V>>, lo: int, hi: int)
+        requires sorted_keys(s), 0 <= lo <= hi <= s.len()
+        ensures sorted_keys(s.subrange(lo, hi))
+    {
+        let t = s.subrange(lo, hi);
+        assert forall |a: int, b: int| #![trigger t[a], t[b]] 0 <= a < b < t.len() implies spec_lt(t[a].0, t[b].0) by {
+            assert(t[a] == s[lo + a] && t[b] == s[lo + b]);
+        }
+    }
+
+    /// The entries are private so that every value built by the trait satisfies `spec_wf`.
+    pub struct OrderedTableStEph<K: StT + TotalOrder, V: StT> {
+        entries: Vec<Pair<K, V>>,
+    }
+
+    pub trait OrderedTableStEphTrait<K: StT + TotalOrder, V: StT> :
+        View<V = Map<K::V, V::V>> + Sized {
+
+        /// Keys are strictly increasing.
+        spec fn spec_wf(&self) -> bool;
+
+        spec fn spec_entries(&self) -> Seq<Pair<K, V>>;
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn empty() -> (empty: Self)
+            requires valid_key_type_OrderedTable::<K, V>()
+            ensures empty.spec_wf(), empty@ == Map::<K::V, V::V>::empty();
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn singleton(k: K, v: V) -> (singleton: Self)
+            requires valid_key_type_OrderedTable::<K, V>()
+            ensures singleton.spec_wf(), singleton@ == Map::<K::V, V::V>::empty().insert(k@, v@);
+
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn size(&self) -> (size: N)
+            requires valid_key_type_OrderedTable::<K, V>(), self.spec_wf()
+            ensures size == self@.len(), size == self.spec_entries().len();
+
+        /// APAS: Work Θ(lg |t|), Span Θ(lg |t|)
+        fn find(&self, k: &K) -> (found: Option<&V>)
+            requires valid_key_type_OrderedTable::<K, V>(), self.spec_wf()
+            ensures
+                match found {
+                    Some(v) => self@.contains_key(k@) && v@ == self@[k@],
+                    None => !self@.contains_key(k@),
+                };
+
+        /// Insert or replace the value at k.
+        /// APAS: Work Θ(|t|), Span Θ(|t|)
+        fn insert(&mut self, k: K, v: V)
+            requires valid_key_type_OrderedTable::<K, V>(), old(self).spec_wf()
+            ensures self.spec_wf(), self@ == old(self)@.insert(k@, v@);
+
+        /// APAS: Work Θ(|t|), Span Θ(|t|)
+        fn delete(&mut self, k: &K) -> (removed: Option<V>)
+            requires valid_key_type_OrderedTable::<K, V>(), old(self).spec_wf()
+            ensures
+                self.spec_wf(),
+                self@ == old(self)@.remove(k@),
+                match removed {
+                    Some(v) => old(self)@.contains_key(k@) && v@ == old(self)@[k@],
+                    None => !old(self)@.contains_key(k@),
+                };
+
+        /// The least key.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn first(&self) -> (first: Option<&K>)
+            requires valid_key_type_OrderedTable::<K, V>(), self.spec_wf()
+            ensures
+                match first {
+                    Some(k) => self@.contains_key(k@)
+                        && forall |i: int| #![trigger self.spec_entries()[i]] 0 <= i < self.spec_entries().len() ==>
+                            k.le(self.spec_entries()[i].0),
+                    None => self@ == Map::<K::V, V::V>::empty(),
+                };
+
+        /// The greatest key.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn last(&self) -> (last: Option<&K>)
+            requires valid_key_type_OrderedTable::<K, V>(), self.spec_wf()
+            ensures
+                match last {
+                    Some(k) => self@.contains_key(k@)
+                        && forall |i: int| #![trigger self.spec_entries()[i]] 0 <= i < self.spec_entries().len() ==>
+                            self.spec_entries()[i].0.le(*k),
+                    None => self@ == Map::<K::V, V::V>::empty(),
+                };
+
+        /// The least key greater than k.
+        /// APAS: Work Θ(lg |t|), Span Θ(lg |t|)
+        fn next(&self, k: &K) -> (next: Option<&K>)
+            requires valid_key_type_OrderedTable::<K, V>(), self.spec_wf()
+            ensures
+                match next {
+                    Some(n) => self@.contains_key(n@) && spec_lt(*k, *n)
+                        && forall |i: int| #![trigger self.spec_entries()[i]] 0 <= i < self.spec_entries().len()
+                            && spec_lt(*k, self.spec_entries()[i].0) ==> n.le(self.spec_entries()[i].0),
+                    None => forall |i: int| #![trigger self.spec_entries()[i]] 0 <= i < self.spec_entries().len() ==>
+                        self.spec_entries()[i].0.le(*k),
+                };
+
+        /// The greatest key less than k.
+        /// APAS: Work Θ(lg |t|), Span Θ(lg |t|)
+        fn prev(&self, k: &K) -> (prev: Option<&K>)
+            requires valid_key_type_OrderedTable::<K, V>(), self.spec_wf()
+            ensures
+                match prev {
+                    Some(p) => self@.contains_key(p@) && spec_lt(*p, *k)
+                        && forall |i: int| #![trigger self.spec_entries()[i]] 0 <= i < self.spec_entries().len()
+                            && spec_lt(self.spec_entries()[i].0, *k) ==> self.spec_entries()[i].0.le(*p),
+                    None => forall |i: int| #![trigger self.spec_entries()[i]] 0 <= i < self.spec_entries().len() ==>
+                        k.le(self.spec_entries()[i].0),
+                };
+
+        /// The number of keys less than k.
+        /// APAS: Work Θ(lg |t|), Span Θ(lg |t|)
+        fn rank(&self, k: &K) -> (rank: N)
+            requires valid_key_type_OrderedTable::<K, V>(), self.spec_wf()
+            ensures
+                rank <= self.spec_entries().len(),
+                forall |i: int| #![trigger self.spec_entries()[i]] 0 <= i < rank ==> spec_lt(self.spec_entries()[i].0, *k),
+                forall |i: int| #![trigger self.spec_entries()[i]] rank <= i < self.spec_entries().len() ==>
+                    k.le(self.spec_entries()[i].0);
+
+        /// The key of rank i.
+        /// APAS: Work Θ(1), Span Θ(1)
+        fn select(&self, i: N) -> (key: Option<&K>)
+            requires valid_key_type_OrderedTable::<K, V>(), self.spec_wf()
+            ensures
+                match key {
+                    Some(key) => i < self.spec_entries().len() && *key == self.spec_entries()[i as int].0,
+                    None => i >= self.spec_entries().len(),
+                };
+
+        /// The entries below k, the value at k and the entries above k.
+        /// APAS: Work Θ(|t|), Span Θ(|t|)
+        fn split(&self, k: &K) -> (parts: (Self, Option<V>, Self))
+            requires valid_key_type_OrderedTable::<K, V>(), self.spec_wf()
+            ensures
+                parts.0.spec_wf(),
+                parts.2.spec_wf(),
+                forall |i: int| #![trigger parts.0.spec_entries()[i]] 0 <= i < parts.0.spec_entries().len() ==>
+                    spec_lt(parts.0.spec_entries()[i].0, *k),
+                forall |i: int| #![trigger parts.2.spec_entries()[i]] 0 <= i < parts.2.spec_entries().len() ==>
+                    spec_lt(*k, parts.2.spec_entries()[i].0),
+                parts.0@.dom().disjoint(parts.2@.dom()),
+                parts.0@.union_prefer_right(parts.2@) == self@.remove(k@),
+                match parts.1 {
+                    Some(v) => self@.contains_key(k@) && v@ == self@[k@],
+                    None => !self@.contains_key(k@),
+                };
+
+        /// Join tables whose keys are all below and all above one another.
+        /// APAS: Work Θ(|left| + |right|), Span Θ(|left| + |right|)
+        fn join(left: Self, right: Self) -> (joined: Self)
+            requires
+                valid_key_type_OrderedTable::<K, V>(),
+                left.spec_wf(),
+                right.spec_wf(),
+                forall |i: int, j: int| #![trigger left.spec_entries()[i], right.spec_entries()[j]]
+                    0 <= i < left.spec_entries().len() && 0 <= j < right.spec_entries().len() ==>
+                        spec_lt(left.spec_entries()[i].0, right.spec_entries()[j].0),
+            ensures
+                joined.spec_wf(),
+                joined@ == left@.union_prefer_right(right@);
+
+        /// The entries with lo <= key <= hi.
+        /// APAS: Work Θ(lg |t| + |range|), Span Θ(lg |t| + |range|)
+        fn get_range(&self, lo: &K, hi: &K) -> (range: Self)
+            requires valid_key_type_OrderedTable::<K, V>(), self.spec_wf()
+            ensures
+                range.spec_wf(),
+                range@.submap_of(self@),
+                forall |i: int| #![trigger self.spec_entries()[i]] 0 <= i < self.spec_entries().len() ==>
+                    (range@.contains_key(self.spec_entries()[i].0@) <==>
+                        lo.le(self.spec_entries()[i].0) && self.spec_entries()[i].0.le(*hi));
+    }
+
+    impl<K: StT + TotalOrder, V: StT> View for OrderedTableStEph<K, V> {
+        type V = Map<K::V, V::V>;
+        closed spec fn view(&self) -> Self::V { entries_view(self.entries@) }
+    }
+
+    impl<K: StT + TotalOrder, V: StT> Clone for OrderedTableStEph<K, V> {
+        fn clone(&self) -> (clone: Self)
+            ensures clone@ == self@, clone.spec_entries() == self.spec_entries()
+        {
+            proof { axiom_Pair_feq::<K, V>(); }
+            let mut entries = Vec::new();
+            let mut i: usize = 0;
+            while i < self.entries.len()
+                invariant
+                    obeys_feq_full::<Pair<K, V>>(),
+                    i <= self.entries@.len(),
+                    entries@ == self.entries@.subrange(0, i as int),
+                decreases self.entries@.len() - i,
+            {
+                let p = self.entries[i].clone_plus();
+                proof { axiom_cloned_implies_eq(&self.entries@[i as int], p); }
+                entries.push(p);
+                proof { assert(entries@ =~= self.entries@.subrange(0, i + 1)); }
+                i += 1;
+            }
+            proof { assert(entries@ =~= self.entries@); }
+            OrderedTableStEph { entries }
+        }
+    }
+
+    /// The first index whose key is not below k.
+    /// APAS: Work Θ(lg |t|), Span Θ(lg |t|)
+    fn lower_bound<K: StT + TotalOrder, V: StT>(entries: &Vec<Pair<K, V>>, k: &K) -> (i: usize)
+        requires sorted_keys(entries@)
+        ensures
+            i <= entries@.len(),
+            forall |j: int| #![trigger entries@[j]] 0 <= j < i ==> spec_lt(entries@[j].0, *k),
+            forall |j: int| #![trigger entries@[j]] i <= j < entries@.len() ==> k.le(entries@[j].0),
+    {
+        let mut lo: usize = 0;
+        let mut hi: usize = entries.len();
+        while lo < hi
+            invariant
+                lo <= hi <= entries@.len(),
+                sorted_keys(entries@),
+                forall |j: int| #![trigger entries@[j]] 0 <= j < lo ==> spec_lt(entries@[j].0, *k),
+                forall |j: int| #![trigger entries@[j]] hi <= j < entries@.len() ==> k.le(entries@[j].0),
+            decreases hi - lo,
+        {
+            let mid = lo + (hi - lo) / 2;
+            match entries[mid].0.cmp(k) {
+                Ordering::Less => {
+                    proof {
+                        assert forall |j: int| #![trigger entries@[j]] 0 <= j <= mid implies spec_lt(entries@[j].0, *k) by {
+                            if j < mid {
+                                K::transitive(entries@[j].0, entries@[mid as int].0, *k);
+                                if entries@[j].0 == *k {
+                                    K::antisymmetric(entries@[mid as int].0, *k);
+                                }
+                            }
+                        }
+                    }
+                    lo = mid + 1;
+                },
+                _ => {
+                    proof {
+                        K::reflexive(*k);
+                        assert forall |j: int| #![trigger entries@[j]] mid <= j < entries@.len() implies k.le(entries@[j].0) by {
+                            if mid < j { K::transitive(*k, entries@[mid as int].0, entries@[j].0); }
+                        }
+                    }
+                    hi = mid;
+                },
+            }
+        }
+        lo
+    }
+
+    /// The first index whose key is above k.
+    /// APAS: Work Θ(lg |t|), Span Θ(lg |t|)
+    fn upper_bound<K: StT + TotalOrder, V: StT>(entries: &Vec<Pair<K, V>>, k: &K) -> (i: usize)
+        requires sorted_keys(entries@)
+        ensures
+            i <= entries@.len(),
+            forall |j: int| #![trigger entries@[j]] 0 <= j < i ==> entries@[j].0.le(*k),
+            forall |j: int| #![trigger entries@[j]] i <= j < entries@.len() ==> spec_lt(*k, entries@[j].0),
+    {
+        let mut lo: usize = 0;
+        let mut hi: usize = entries.len();
+        while lo < hi
+            invariant
+                lo <= hi <= entries@.len(),
+                sorted_keys(entries@),
+                forall |j: int| #![trigger entries@[j]] 0 <= j < lo ==> entries@[j].0.le(*k),
+                forall |j: int| #![trigger entries@[j]] hi <= j < entries@.len() ==> spec_lt(*k, entries@[j].0),
+            decreases hi - lo,
+        {
+            let mid = lo + (hi - lo) / 2;
+            match entries[mid].0.cmp(k) {
+                Ordering::Greater => {
+                    proof {
+                        assert forall |j: int| #![trigger entries@[j]] mid <= j < entries@.len() implies spec_lt(*k, entries@[j].0) by {
+                            if mid < j {
+                                K::transitive(*k, entries@[mid as int].0, entries@[j].0);
+                                if entries@[j].0 == *k {
+                                    K::antisymmetric(entries@[mid as int].0, *k);
+                                }
+                            }
+                        }
+                    }
+                    hi = mid;
+                },
+                _ => {
+                    proof {
+                        K::reflexive(*k);
+                        assert forall |j: int| #![trigger entries@[j]] 0 <= j <= mid implies entries@[j].0.le(*k) by {
+                            if j < mid { K::transitive(entries@[j].0, entries@[mid as int].0, *k); }
+                        }
+                    }
+                    lo = mid + 1;
+                },
+            }
+        }
+        lo
+    }
+
+    /// A copy of entries[lo..hi].
+    /// APAS: Work Θ(hi - lo), Span Θ(hi - lo)
+    fn copy_range<K: StT + TotalOrder, V: StT>(entries: &Vec<Pair<K, V>>, lo: usize, hi: usize) -> (out: Vec<Pair<K, V>>)
+        requires valid_key_type_OrderedTable::<K, V>(), lo <= hi <= entries@.len()
+        ensures out@ == entries@.subrange(lo as int, hi as int)
+    {
+        let mut out = Vec::new();
+        let mut i = lo;
+        while i < hi
+            invariant
+                valid_key_type_OrderedTable::<K, V>(),
+                lo <= i <= hi <= entries@.len(),
+                out@ == entries@.subrange(lo as int, i as int),
+            decreases hi - i,
+        {
+            let p = &entries[i];
+            out.push(Pair(p.0.clone_plus(), p.1.clone_plus()));
+            proof { assert(out@ =~= entries@.subrange(lo as int, i + 1)); }
+            i += 1;
+        }
+        out
+    }
+
+    /// Whether the key at i is k.
+    fn key_at_is<K: StT + TotalOrder, V: StT>(entries: &Vec<Pair<K, V>>, i: usize, k: &K) -> (is: bool)
+        ensures is == (i < entries@.len() && entries@[i as int].0 == *k)
+    {
+        if i < entries.len() {
+            match entries[i].0.cmp(k) {
+                Ordering::Equal => true,
+                _ => false,
+            }
+        } else {
+            false
+        }
+    }
+
+    impl<K: StT + TotalOrder, V: StT> OrderedTableStEphTrait<K, V> for OrderedTableStEph<K, V> {
+
+        open spec fn spec_wf(&self) -> bool { sorted_keys(self.spec_entries()) }
+
+        closed spec fn spec_entries(&self) -> Seq<Pair<K, V>> { self.entries@ }
+
+        fn empty() -> OrderedTableStEph<K, V> {
+            let table = OrderedTableStEph { entries: Vec::new() };
+            proof { assert(table@ =~= Map::<K::V, V::V>::empty()); }
+            table
+        }
+
+        fn singleton(k: K, v: V) -> OrderedTableStEph<K, V> {
+            let mut table = Self::empty();
+            table.insert(k, v);
+            table
+        }
+
+        fn size(&self) -> N {
+            proof { lemma_entries_view_len(self.entries@); }
+            self.entries.len()
+        }
+
+        fn find(&self, k: &K) -> Option<&V> {
+            let i = lower_bound(&self.entries, k);
+            proof { lemma_entries_view_contains(self.entries@, *k); }
+            if key_at_is(&self.entries, i, k) {
+                proof { lemma_entries_view_at(self.entries@, i as int); }
+                Some(&self.entries[i].1)
+            } else {
+                proof {
+                    assert forall |j: int| #![trigger self.entries@[j]] 0 <= j < self.entries@.len() implies self.entries@[j].0 != *k by {
+                        if i < j && self.entries@[j].0 == *k {
+                            assert(spec_lt(self.entries@[i as int].0, self.entries@[j].0));
+                            K::antisymmetric(self.entries@[i as int].0, *k);
+                        }
+                    }
+                }
+                None
+            }
+        }
+
+        fn insert(&mut self, k: K, v: V) {
+            let i = lower_bound(&self.entries, &k);
+            if key_at_is(&self.entries, i, &k) {
+                proof { lemma_entries_view_update(self.entries@, i as int, Pair(k, v)); }
+                self.entries.set(i, Pair(k, v));
+            } else {
+                proof {
+                    assert forall |j: int| #![trigger self.entries@[j]] i <= j < self.entries@.len() implies spec_lt(k, self.entries@[j].0) by {
+                        if j > i && self.entries@[j].0 == k {
+                            assert(spec_lt(self.entries@[i as int].0, self.entries@[j].0));
+                            K::antisymmetric(self.entries@[i as int].0, k);
+                        }
+                    }
+                    lemma_entries_view_insert(self.entries@, i as int, Pair(k, v));
+                }
+                self.entries.insert(i, Pair(k, v));
+            }
+        }
+
+        fn delete(&mut self, k: &K) -> Option<V> {
+            let i = lower_bound(&self.entries, k);
+            proof { lemma_entries_view_contains(self.entries@, *k); }
+            if key_at_is(&self.entries, i, k) {
+                proof {
+                    lemma_entries_view_at(self.entries@, i as int);
+                    lemma_entries_view_remove(self.entries@, i as int);
+                }
+                let removed = self.entries.remove(i);
+                Some(removed.1)
+            } else {
+                proof {
+                    assert forall |j: int| #![trigger self.entries@[j]] 0 <= j < self.entries@.len() implies self.entries@[j].0 != *k by {
+                        if i < j && self.entries@[j].0 == *k {
+                            assert(spec_lt(self.entries@[i as int].0, self.entries@[j].0));
+                            K::antisymmetric(self.entries@[i as int].0, *k);
+                        }
+                    }
+                    assert(self@.remove(k@) =~= self@);
+                }
+                None
+            }
+        }
+
+        fn first(&self) -> Option<&K> {
+            if self.entries.len() == 0 {
+                proof { assert(self@ =~= Map::<K::V, V::V>::empty()); }
+                None
+            } else {
+                proof {
+                    lemma_entries_view_at(self.entries@, 0);
+                    K::reflexive(self.entries@[0].0);
+                }
+                Some(&self.entries[0].0)
+            }
+        }
+
+        fn last(&self) -> Option<&K> {
+            let n = self.entries.len();
+            if n == 0 {
+                proof { assert(self@ =~= Map::<K::V, V::V>::empty()); }
+                None
+            } else {
+                proof {
+                    lemma_entries_view_at(self.entries@, n - 1);
+                    K::reflexive(self.entries@[n - 1].0);
+                }
+                Some(&self.entries[n - 1].0)
+            }
+        }
+
+        fn next(&self, k: &K) -> Option<&K> {
+            let i = upper_bound(&self.entries, k);
+            if i < self.entries.len() {
+                proof {
+                    lemma_entries_view_at(self.entries@, i as int);
+                    K::reflexive(self.entries@[i as int].0);
+                    assert forall |j: int| #![trigger self.entries@[j]] 0 <= j < self.entries@.len() && spec_lt(*k, self.entries@[j].0)
+                        implies self.entries@[i as int].0.le(self.entries@[j].0) by {
+                        if j < i {
+                            K::antisymmetric(self.entries@[j].0, *k);
+                        } else if i < j {
+                            assert(spec_lt(self.entries@[i as int].0, self.entries@[j].0));
+                        }
+                    }
+                }
+                Some(&self.entries[i].0)
+            } else {
+                None
+            }
+        }
+
+        fn prev(&self, k: &K) -> Option<&K> {
+            let i = lower_bound(&self.entries, k);
+            if i > 0 {
+                proof {
+                    lemma_entries_view_at(self.entries@, i - 1);
+                    K::reflexive(self.entries@[i - 1].0);
+                    assert forall |j: int| #![trigger self.entries@[j]] 0 <= j < self.entries@.len() && spec_lt(self.entries@[j].0, *k)
+                        implies self.entries@[j].0.le(self.entries@[i - 1].0) by {
+                        if j >= i {
+                            K::antisymmetric(self.entries@[j].0, *k);
+                        } else if j < i - 1 {
+                            assert(spec_lt(self.entries@[j].0, self.entries@[i - 1].0));
+                        }
+                    }
+                }
+                Some(&self.entries[i - 1].0)
+            } else {
+                None
+            }
+        }
+
+        fn rank(&self, k: &K) -> N { lower_bound(&self.entries, k) }
+
+        fn select(&self, i: N) -> Option<&K> {
+            if i < self.entries.len() { Some(&self.entries[i].0) } else { None }
+        }
+
+        fn split(&self, k: &K) -> (OrderedTableStEph<K, V>, Option<V>, OrderedTableStEph<K, V>) {
+            let n = self.entries.len();
+            let i = lower_bound(&self.entries, k);
+            let found = key_at_is(&self.entries, i, k);
+            let j = if found { i + 1 } else { i };
+            let left = OrderedTableStEph { entries: copy_range(&self.entries, 0, i) };
+            let right = OrderedTableStEph { entries: copy_range(&self.entries, j, n) };
+            let ghost s = self.entries@;
+            proof {
+                lemma_entries_view_contains(s, *k);
+                assert forall |a: int| #![trigger s[a]] j <= a < n implies spec_lt(*k, s[a].0) by {
+                    if found { assert(spec_lt(s[i as int].0, s[a].0)); }
+                }
+                // The entries other than k, in order.
+                let rest = if found { s.remove(i as int) } else { s };
+                if found {
+                    lemma_entries_view_remove(s, i as int);
+                } else {
+                    assert forall |a: int| #![trigger s[a]] 0 <= a < n implies s[a].0 != *k by {
+                        if i < a && s[a].0 == *k {
+                            assert(spec_lt(s[i as int].0, s[a].0));
+                            K::antisymmetric(s[i as int].0, *k);
+                        }
+                    }
+                    assert(entries_view(s).remove(k@) =~= entries_view(s));
+                }
+                assert(rest =~= left.entries@ + right.entries@);
+                lemma_entries_view_append(left.entries@, right.entries@);
+            }
+            if found {
+                proof { lemma_entries_view_at(s, i as int); }
+                (left, Some(self.entries[i].1.clone_plus()), right)
+            } else {
+                (left, None, right)
+            }
+        }
+
+        fn join(left: OrderedTableStEph<K, V>, right: OrderedTableStEph<K, V>) -> OrderedTableStEph<K, V> {
+            let ghost l = left.entries@;
+            let ghost r = right.entries@;
+            let mut entries = left.entries;
+            let mut rest = right.entries;
+            entries.append(&mut rest);
+            proof {
+                let s = l + r;
+                assert(entries@ =~= s);
+                assert forall |a: int, b: int| #![trigger s[a], s[b]] 0 <= a < b < s.len() implies spec_lt(s[a].0, s[b].0) by {
+                    if b < l.len() { assert(s[a] == l[a] && s[b] == l[b]); }
+                    else if a < l.len() { assert(s[a] == l[a] && s[b] == r[b - l.len()]); }
+                    else { assert(s[a] == r[a - l.len()] && s[b] == r[b - l.len()]); }
+                }
+                lemma_entries_view_append(l, r);
+            }
+            OrderedTableStEph { entries }
+        }
+
+        fn get_range(&self, lo: &K, hi: &K) -> OrderedTableStEph<K, V> {
+            let a = lower_bound(&self.entries, lo);
+            let b = upper_bound(&self.entries, hi);
+            let b = if b < a { a } else { b };
+            let range = OrderedTableStEph { entries: copy_range(&self.entries, a, b) };
+            proof {
+                let s = self.entries@;
+                let t = range.entries@;
+                lemma_sorted_subrange(s, a as int, b as int);
+                assert forall |x: K::V| #[trigger] range@.contains_key(x) implies self@.contains_key(x) && range@[x] == self@[x] by {
+                    let c = choose |c: int| #![trigger t[c]] 0 <= c < t.len() && t[c].0@ == x;
+                    assert(t[c] == s[a + c]);
+                    lemma_entries_view_at(t, c);
+                    lemma_entries_view_at(s, a + c);
+                }
+                assert forall |c: int| #![trigger s[c]] 0 <= c < s.len() implies
+                    (range@.contains_key(s[c].0@) <==> lo.le(s[c].0) && s[c].0.le(*hi)) by {
+                    lemma_entries_view_contains(t, s[c].0);
+                    if a <= c < b {
+                        assert(t[c - a] == s[c]);
+                    }
+                    if range@.contains_key(s[c].0@) {
+                        let d = choose |d: int| #![trigger t[d]] 0 <= d < t.len() && t[d].0 == s[c].0;
+                        assert(t[d] == s[a + d]);
+                        if a + d != c {
+                            if a + d < c { assert(spec_lt(s[a + d].0, s[c].0)); } else { assert(spec_lt(s[c].0, s[a + d].0)); }
+                        }
+                    }
+                    if lo.le(s[c].0) && s[c].0.le(*hi) {
+                        if c < a { K::antisymmetric(s[c].0, *lo); }
+                        if c >= b { K::antisymmetric(s[c].0, *hi); }
+                    }
+                }
+            }
+            range
+        }
+    }
+
+    #[macro_export]
+    macro_rules! OrderedTableLit {
+        () => {{
+            < $crate::Chap43::OrderedTableStEph::OrderedTableStEph::OrderedTableStEph<_, _> >::empty()
+        }};
+        ( $( ($k:expr, $v:expr) ),* $(,)? ) => {{
+            let mut __t = < $crate::Chap43::OrderedTableStEph::OrderedTableStEph::OrderedTableStEph<_, _> >::empty();
+            $( {
+                let (__k, __v) = ($k, $v);
+                if let Some(__first) = __t.find(&__k) {
+                    panic!("OrderedTableLit!: duplicate key {:?} maps to both {:?} and {:?}", __k, __first, __v);
+                }
+                __t.insert(__k, __v);
+            } )*
+            __t
+        }};
+    }
+
+  } // verus!
+
+    impl<K: StT + TotalOrder, V: StT> PartialEq for OrderedTableStEph<K, V> {
+        fn eq(&self, other: &Self) -> bool { self.entries == other.entries }
+    }
+
+    impl<K: StT + TotalOrder, V: StT> Eq for OrderedTableStEph<K, V> {}
+
+    impl<K: StT + TotalOrder, V: StT> Debug for OrderedTableStEph<K, V> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            write!(f, "OrderedTableStEph(")?;
+            for (i, p) in self.entries.iter().enumerate() {
+                if i > 0 { write!(f, ", ")?; }
+                write!(f, "{:?} -> {:?}", p.0, p.1)?;
+            }
+            write!(f, ")")
+        }
+    }
+
+    impl<K: StT + TotalOrder, V: StT> Display for OrderedTableStEph<K, V> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            write!(f, "{{")?;
+            for (i, p) in self.entries.iter().enumerate() {
+                if i > 0 { write!(f, ", ")?; }
+                write!(f, "{} -> {}", p.0, p.1)?;
+            }
+            write!(f, "}}")
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Chapter 54 sequential breadth-first search: hop distances from a source and a BFS tree of
+//! parents, over any graph representation implementing `GraphStEphTrait`.
+
+pub mod BFSStEph {
+
+    use vstd::prelude::*;
+
+verus! {
+
+    use std::hash::Hash;
+
+    #[cfg(verus_keep_ghost)]
+    use crate::vstdplus::feq::feq::*;
+    use crate::vstdplus::seq_set::*;
+    use crate::vstdplus::clone_plus::clone_plus::ClonePlus;
+    use crate::vstdplus::hash_map_with_view_plus::hash_map_with_view_plus::*;
+    use crate::Chap05::SetStEph::SetStEph::*;
+    use crate::Chap05::RelationStEph::RelationStEph::{is_path, reaches};
+    use crate::Chap05::MappingStEph::MappingStEph::*;
+    use crate::Chap06::DirGraphStEph::DirGraphStEph::{valid_key_type_Graph, spec_graph_wf, spec_n_plus};
+    use crate::Chap06::GraphStEph::GraphStEph::GraphStEphTrait;
+    use crate::Types::Types::*;
+
+    broadcast use {vstd::seq_lib::group_seq_properties, vstd::set::group_set_axioms, vstd::map::group_map_axioms, crate::vstdplus::feq::feq::group_feq_axioms};
+
+    /// b is reachable from a by a path of exactly k steps.
+    pub open spec fn reaches_in<X>(r: Set<(X, X)>, a: X, b: X, k: nat) -> bool {
+        exists |path: Seq<X>| #[trigger] is_path(r, path) && path.len() == k + 1 && path[0] == a && path.last() == b
+    }
+
+    /// s and everything s reaches.
+    pub open spec fn reachable_from<X>(r: Set<(X, X)>, s: X) -> Set<X> {
+        Set::new(|v: X| v == s || reaches(r, s, v))
+    }
+
+    /// dist and parent form a BFS tree rooted at s: every other vertex has a parent one hop closer.
+    pub open spec fn is_bfs_tree<X>(r: Set<(X, X)>, s: X, dist: Map<X, usize>, parent: Map<X, X>) -> bool {
+        &&& dist.contains_key(s)
+        &&& dist[s] == 0
+        &&& parent.dom() == dist.dom().remove(s)
+        &&& forall |v: X| #[trigger] parent.contains_key(v) ==>
+                r.contains((parent[v], v)) && dist.contains_key(parent[v]) && dist[parent[v]] + 1 == dist[v]
+    }
+
+    /// Every arc out of the domain of dist lands in it, at most one hop further.
+    pub open spec fn is_closed_layering<X>(r: Set<(X, X)>, dist: Map<X, usize>) -> bool {
+        forall |u: X, w: X| #![trigger dist.contains_key(u), r.contains((u, w))]
+            dist.contains_key(u) && r.contains((u, w)) ==> dist.contains_key(w) && dist[w] <= dist[u] + 1
+    }
+
+    pub open spec fn parents_view<X: View>(parent: Map<X::V, X>) -> Map<X::V, X::V> {
+        parent.map_values(|p: X| p@)
+    }
+
+    /// Following parents from v back to s is a path of dist[v] steps.
+    pub proof fn lemma_bfs_tree_path<X>(r: Set<(X, X)>, s: X, dist: Map<X, usize>, parent: Map<X, X>, v: X)
+        requires is_bfs_tree(r, s, dist, parent), dist.contains_key(v),
+        ensures reaches_in(r, s, v, dist[v] as nat),
+        decreases dist[v],
+    {
+        if v == s {
+            let path = seq![s];
+            assert(is_path(r, path));
+        } else {
+            assert(parent.contains_key(v));
+            let u = parent[v];
+            lemma_bfs_tree_path(r, s, dist, parent, u);
+            let prefix = choose |path: Seq<X>| #[trigger] is_path(r, path) && path.len() == dist[u] + 1 && path[0] == s && path.last() == u;
+            let path = prefix.push(v);
+            assert forall |i: int| #![trigger path[i]] 0 <= i < path.len() - 1 implies r.contains((path[i], path[i + 1])) by {
+                if i < prefix.len() - 1 {
+                    assert(path[i] == prefix[i] && path[i + 1] == prefix[i + 1]);
+                } else {
+                    assert(path[i] == u && path[i + 1] == v);
+                }
+            }
+            assert(is_path(r, path));
+        }
+    }
+
+    /// In a closed layering with s at 0, a path of k steps from s ends in the domain at distance <= k.
+    pub proof fn lemma_closed_layering_bound<X>(r: Set<(X, X)>, s: X, dist: Map<X, usize>, path: Seq<X>)
+        requires
+            is_closed_layering(r, dist),
+            dist.contains_key(s),
+            dist[s] == 0,
+            is_path(r, path),
+            path.len() >= 1,
+            path[0] == s,
+        ensures
+            dist.contains_key(path.last()),
+            dist[path.last()] <= path.len() - 1,
+        decreases path.len(),
+    {
+        if path.len() > 1 {
+            let prefix = path.drop_last();
+            assert forall |i: int| #![trigger prefix[i]] 0 <= i < prefix.len() - 1 implies r.contains((prefix[i], prefix[i + 1])) by {
+                assert(prefix[i] == path[i] && prefix[i + 1] == path[i + 1]);
+            }
+            lemma_closed_layering_bound(r, s, dist, prefix);
+            let n = path.len() as int;
+            assert(r.contains((path[n - 2], path[n - 1])));
+            assert(prefix.last() == path[n - 2]);
+        }
+    }
+
+    /// A closed BFS tree holds exactly the vertices reachable from s, each at its shortest distance.
+    pub proof fn lemma_bfs_complete<X>(r: Set<(X, X)>, s: X, dist: Map<X, usize>, parent: Map<X, X>)
+        requires is_bfs_tree(r, s, dist, parent), is_closed_layering(r, dist),
+        ensures
+            dist.dom() == reachable_from(r, s),
+            forall |v: X, k: nat| dist.contains_key(v) && #[trigger] reaches_in(r, s, v, k) ==> dist[v] <= k,
+    {
+        assert forall |v: X| dist.contains_key(v) implies #[trigger] reachable_from(r, s).contains(v) by {
+            if v != s {
+                lemma_bfs_tree_path(r, s, dist, parent, v);
+                assert(parent.contains_key(v));
+                let path = choose |path: Seq<X>| #[trigger] is_path(r, path) && path.len() == dist[v] + 1 && path[0] == s && path.last() == v;
+                assert(path.len() >= 2);
+            }
+        }
+        assert forall |v: X| reachable_from(r, s).contains(v) implies #[trigger] dist.contains_key(v) by {
+            if v != s {
+                let path = choose |path: Seq<X>| #[trigger] is_path(r, path) && path.len() >= 2 && path[0] == s && path.last() == v;
+                lemma_closed_layering_bound(r, s, dist, path);
+            }
+        }
+        assert(dist.dom() =~= reachable_from(r, s));
+        assert forall |v: X, k: nat| dist.contains_key(v) && #[trigger] reaches_in(r, s, v, k) implies dist[v] <= k by {
+            let path = choose |path: Seq<X>| #[trigger] is_path(r, path) && path.len() == k + 1 && path[0] == s && path.last() == v;
+            lemma_closed_layering_bound(r, s, dist, path);
+        }
+    }
+
+    pub trait BFSStEphTrait<V: StT + Hash> : GraphStEphTrait<V> {
+        /// Layer-by-layer BFS from s: Pair(hop distances, parents) over the vertices reachable from s.
+        /// Each reached vertex costs one `out_neighbors` call, so the bound depends on the representation:
+        /// AdjTableGraphStEph and CsrGraphStEph, whose out_neighbors is Θ(1 + d+(v)):
+        ///   Work Θ(|V| + |A|), Span Θ(|V| + |A|)
+        /// DirGraphStEph, whose out_neighbors scans every arc:
+        ///   Work Θ(|V| × |A|), Span Θ(|V| × |A|)
+        fn bfs(&self, s: &V) -> (tree: Pair<MappingStEph<V, N>, MappingStEph<V, V>>)
+            requires
+                valid_key_type_Graph::<V>(),
+                valid_key_type_Pair::<V, N>(),
+                valid_key_type_Pair::<V, V>(),
+                self.spec_repr_wf(),
+                self@.0.finite(),
+                self@.0.len() < usize::MAX,
+                self@.0.contains(s@),
+            ensures
+                tree.0@.dom() == reachable_from(self@.1, s@),
+                is_bfs_tree(self@.1, s@, tree.0@, tree.1@),
+                forall |v: V::V| #[trigger] tree.0@.contains_key(v) ==> reaches_in(self@.1, s@, v, tree.0@[v] as nat),
+                forall |v: V::V, k: nat| tree.0@.contains_key(v) && #[trigger] reaches_in(self@.1, s@, v, k) ==> tree.0@[v] <= k;
+    }
+
+    impl<V: StT + Hash, G: GraphStEphTrait<V>> BFSStEphTrait<V> for G {
+
+        fn bfs(&self, s: &V) -> Pair<MappingStEph<V, N>, MappingStEph<V, V>> {
+            proof { self.lemma_repr_wf_graph_wf(); }
+            let ghost verts = self@.0;
+            let ghost arcs = self@.1;
+
+            let mut dist = HashMapWithViewPlus::<V, N>::new();
+            let mut parent = HashMapWithViewPlus::<V, V>::new();
+            let mut frontier = SetStEph::<V>::empty();
+            let _ = dist.insert(s.clone_plus(), 0);
+            let _ = frontier.insert(s.clone_plus());
+            let mut d: N = 0;
+            proof {
+                assert(parents_view::<V>(parent@) =~= Map::<V::V, V::V>::empty());
+                assert(dist@.dom() =~= Set::<V::V>::empty().insert(s@));
+                assert(parents_view::<V>(parent@).dom() =~= dist@.dom().remove(s@));
+                assert(frontier@ =~= Set::<V::V>::new(|v: V::V| dist@.contains_key(v) && dist@[v] == 0));
+            }
+
+            #[verifier::loop_isolation(false)]
+            while frontier.size() > 0
+                invariant
+                    valid_key_type_Graph::<V>(),
+                    valid_key_type_Pair::<V, N>(),
+                    valid_key_type_Pair::<V, V>(),
+                    self.spec_repr_wf(),
+                    verts == self@.0,
+                    arcs == self@.1,
+                    spec_graph_wf(verts, arcs),
+                    verts.finite(),
+                    verts.len() < usize::MAX,
+                    verts.contains(s@),
+                    is_bfs_tree(arcs, s@, dist@, parents_view::<V>(parent@)),
+                    dist@.dom().subset_of(verts),
+                    forall |v: V::V| #[trigger] dist@.contains_key(v) ==> dist@[v] <= d,
+                    frontier@ == Set::<V::V>::new(|v: V::V| dist@.contains_key(v) && dist@[v] == d),
+                    forall |u: V::V, w: V::V| #![trigger dist@.contains_key(u), arcs.contains((u, w))]
+                        dist@.contains_key(u) && dist@[u] < d && arcs.contains((u, w)) ==>
+                            dist@.contains_key(w) && dist@[w] <= dist@[u] + 1,
+                    frontier@.len() > 0 ==> d + 1 <= dist@.dom().len(),
+                decreases verts.len() - dist@.dom().len(), frontier@.len(),
+            {
+                proof { vstd::set_lib::lemma_len_subset(dist@.dom(), verts); }
+                let next_d = d + 1;
+                let ghost dist0 = dist@;
+                let mut next = SetStEph::<V>::empty();
+                let mut frontier_it = frontier.iter();
+                let ghost frontier_seq = frontier_it@.1;
+                proof { assert(next@ =~= Set::<V::V>::new(|v: V::V| dist@.contains_key(v) && !dist0.contains_key(v))); }
+
+                #[verifier::loop_isolation(false)]
+                loop
+                    invariant
+                        valid_key_type_Graph::<V>(),
+                        valid_key_type_Pair::<V, N>(),
+                        valid_key_type_Pair::<V, V>(),
+                        self.spec_repr_wf(),
+                        spec_graph_wf(verts, arcs),
+                        next_d == d + 1,
+                        frontier_it@.0 <= frontier_seq.len(),
+                        frontier_it@.1 == frontier_seq,
+                        frontier_seq.map(|i: int, x: V| x@).to_set() == frontier@,
+                        frontier@ == Set::<V::V>::new(|v: V::V| dist0.contains_key(v) && dist0[v] == d),
+                        is_bfs_tree(arcs, s@, dist@, parents_view::<V>(parent@)),
+                        dist@.dom().subset_of(verts),
+                        forall |v: V::V| #[trigger] dist0.contains_key(v) ==> dist@.contains_key(v) && dist@[v] == dist0[v],
+                        forall |v: V::V| #[trigger] dist@.contains_key(v) && !dist0.contains_key(v) ==> dist@[v] == next_d,
+                        next@ == Set::<V::V>::new(|v: V::V| dist@.contains_key(v) && !dist0.contains_key(v)),
+                        forall |i: int, w: V::V| #![trigger frontier_seq[i], arcs.contains((frontier_seq[i]@, w))]
+                            0 <= i < frontier_it@.0 && arcs.contains((frontier_seq[i]@, w)) ==>
+                                dist@.contains_key(w) && dist@[w] <= next_d,
+                    decreases frontier_seq.len() - frontier_it@.0,
+                {
+                    match frontier_it.next() {
+                        Some(u) => {
+                            proof { lemma_seq_index_in_map_to_set(frontier_seq, frontier_it@.0 - 1); }
+                            let succs = self.out_neighbors(u);
+                            let mut succ_it = succs.iter();
+                            let ghost succ_seq = succ_it@.1;
+
+                            #[verifier::loop_isolation(false)]
+                            loop
+                                invariant
+                                    valid_key_type_Graph::<V>(),
+                                    valid_key_type_Pair::<V, N>(),
+                                    valid_key_type_Pair::<V, V>(),
+                                    spec_graph_wf(verts, arcs),
+                                    next_d == d + 1,
+                                    dist0.contains_key(u@),
+                                    dist0[u@] == d,
+                                    succ_it@.0 <= succ_seq.len(),
+                                    succ_it@.1 == succ_seq,
+                                    succ_seq.map(|i: int, x: V| x@).to_set() == spec_n_plus(arcs, u@),
+                                    is_bfs_tree(arcs, s@, dist@, parents_view::<V>(parent@)),
+                                    dist@.dom().subset_of(verts),
+                                    forall |v: V::V| #[trigger] dist0.contains_key(v) ==> dist@.contains_key(v) && dist@[v] == dist0[v],
+                                    forall |v: V::V| #[trigger] dist@.contains_key(v) && !dist0.contains_key(v) ==> dist@[v] == next_d,
+                                    next@ == Set::<V::V>::new(|v: V::V| dist@.contains_key(v) && !dist0.contains_key(v)),
+                                    forall |j: int| #![trigger succ_seq[j]] 0 <= j < succ_it@.0 ==>
+                                        dist@.contains_key(succ_seq[j]@) && dist@[succ_seq[j]@] <= next_d,
+                                decreases succ_seq.len() - succ_it@.0,
+                            {
+                                match succ_it.next() {
+                                    Some(w) => {
+                                        proof {
+                                            lemma_seq_index_in_map_to_set(succ_seq, succ_it@.0 - 1);
+                                            assert(arcs.contains((u@, w@)));
+                                        }
+                                        if !dist.contains_key(w) {
+                                            let ghost parents_before = parents_view::<V>(parent@);
+                                            let _ = dist.insert(w.clone_plus(), next_d);
+                                            let _ = parent.insert(w.clone_plus(), u.clone_plus());
+                                            let _ = next.insert(w.clone_plus());
+                                            proof {
+                                                assert(parents_view::<V>(parent@) =~= parents_before.insert(w@, u@));
+                                                assert(parents_view::<V>(parent@).dom() =~= dist@.dom().remove(s@));
+                                                assert(next@ =~= Set::<V::V>::new(|v: V::V| dist@.contains_key(v) && !dist0.contains_key(v)));
+                                            }
+                                        }
+                                    },
+                                    None => break,
+                                }
+                            }
+                            proof {
+                                assert forall |w: V::V| #[trigger] arcs.contains((u@, w)) implies dist@.contains_key(w) && dist@[w] <= next_d by {
+                                    lemma_map_to_set_contains_index(succ_seq, w);
+                                }
+                            }
+                        },
+                        None => break,
+                    }
+                }
+
+                proof {
+                    assert forall |u: V::V, w: V::V| #![trigger dist@.contains_key(u), arcs.contains((u, w))]
+                        dist@.contains_key(u) && dist@[u] < next_d && arcs.contains((u, w)) implies
+                            dist@.contains_key(w) && dist@[w] <= dist@[u] + 1 by {
+                        assert(dist0.contains_key(u));
+                        if dist0[u] < d {
+                            assert(dist0.contains_key(w) && dist0[w] <= dist0[u] + 1);
+                        } else {
+                            assert(frontier@.contains(u));
+                            lemma_map_to_set_contains_index(frontier_seq, u);
+                        }
+                    }
+                    assert(next@ =~= Set::<V::V>::new(|v: V::V| dist@.contains_key(v) && dist@[v] == next_d));
+                    assert(dist0.dom().subset_of(dist@.dom()));
+                    vstd::set_lib::lemma_len_subset(dist@.dom(), verts);
+                    vstd::set_lib::lemma_len_subset(dist0.dom(), dist@.dom());
+                    if next@.len() > 0 {
+                        let x = next@.choose();
+                        assert(next@.contains(x));
+                        vstd::set_lib::lemma_len_subset(dist0.dom(), dist@.dom().remove(x));
+                    } else {
+                        assert(dist@.dom() =~= dist0.dom());
+                    }
+                    vstd::set_lib::lemma_len_subset(next@, verts);
+                }
+                frontier = next;
+                d = next_d;
+            }
+
+            proof {
+                assert forall |v: V::V| #[trigger] dist@.contains_key(v) implies dist@[v] < d by {
+                    if dist@[v] == d {
+                        assert(frontier@.contains(v));
+                    }
+                }
+                lemma_bfs_complete(arcs, s@, dist@, parents_view::<V>(parent@));
+            }
+            let ghost dist_view = dist@;
+            let ghost parent_view = parents_view::<V>(parent@);
+            let distances = MappingStEph::FromHashMap(dist);
+            let parents = MappingStEph::FromHashMap(parent);
+            proof { assert(distances@ =~= dist_view); }
+            Pair(distances, parents)
+        }
+    }
+
+  } // verus!
+}
@@ -7,7 +7,7 @@ pub mod Types {
     use std::fmt::{Formatter, Debug, Display};
     use std::hash::Hash;
     use std::ops::Add;
-    use std::sync::Mutex;
+    use std::sync::{Arc, Mutex, RwLock};
     use vstd::prelude::*;
     #[cfg(verus_keep_ghost)]
     use vstd::std_specs::hash::SetIterAdditionalSpecFns;
@@ -25,21 +25,6 @@ pub mod Types {
     // Note: bool already implements Display, Debug, Not, etc.
     // No custom implementations needed when B = bool
 
-    // Triple wrapper for three-element tuples
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-    pub struct Triple<A, B, C>(pub A, pub B, pub C);
-
-    // Quadruple wrapper for four-element tuples
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-    pub struct Quadruple<A, B, C, D>(pub A, pub B, pub C, pub D);
-
-    // Key-value struct with named fields
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-    pub struct KeyVal<K, V> {
-        pub key: K,
-        pub val: V,
-    }
-
     verus! {
 
     // Type bounds shorthands
@@ -53,14 +38,39 @@ pub mod Types {
     pub trait StTInMtT: StT + Send + Sync {}
     impl<T> StTInMtT for T where T: StT + Send + Sync {}
 
+    verus! {
+
     // MtT: multi-threaded friendly elements; minimal so it can include Mutex<..>
     // Keep only thread-safety and size requirements.
     pub trait MtT: Sized + Send + Sync {
-        type Inner: StT;
-        fn clone_mt(&self)            -> Self;
-        fn new_mt(inner: Self::Inner) -> Self;
+        /// The StT a lock or Arc wraps; Self for plain values and containers.
+        type Inner: Clone;
+
+        /// What clone_mt and new_mt need of the type, e.g. that clones are equal.
+        spec fn spec_mt_requires() -> bool { true }
+
+        /// other has the same contents as self.
+        spec fn spec_mt_same(&self, other: &Self) -> bool { true }
+
+        /// self has contents inner.
+        spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { true }
+
+        /// A value with the same contents as self: for plain values a clone equal to self,
+        /// for locks (Mutex, RwLock) a fresh lock around a clone of the current contents,
+        /// and for Arc another handle to the same allocation.
+        fn clone_mt(&self)            -> (clone: Self)
+            requires Self::spec_mt_requires()
+            ensures self.spec_mt_same(&clone);
+
+        /// The Mt value whose contents are inner: inner itself for plain values and
+        /// containers, and a lock or Arc around inner otherwise.
+        fn new_mt(inner: Self::Inner) -> (mt: Self)
+            requires Self::spec_mt_requires()
+            ensures mt.spec_mt_holds(inner);
     }
 
+    } // verus!
+
     // MtKey: Multi-threaded key type with ordering and static lifetime
     // Common pattern: StTInMtT + Ord + 'static (appears 15+ times)
     pub trait MtKey: StTInMtT + Ord + 'static {}
@@ -117,91 +127,194 @@ pub mod Types {
     pub trait ArithmeticT: StT + Add<Output = Self> + Default + Copy {}
     impl<T> ArithmeticT for T where T: StT + Add<Output = T> + Default + Copy {}
 
+    verus! {
+
+    use crate::vstdplus::clone_plus::clone_plus::ClonePlus;
+    #[cfg(verus_keep_ghost)]
+    use crate::vstdplus::feq::feq::{obeys_feq_clone, group_feq_axioms};
+
+    broadcast use group_feq_axioms;
+
+    // Locks are outside the verifier's model of memory. Mutex and RwLock get external type
+    // specifications, and the view of a lock's contents is uninterpreted: only the trusted
+    // ensures of clone_mt and new_mt below constrain it.
+    #[verifier::external_type_specification]
+    #[verifier::external_body]
+    #[verifier::reject_recursive_types(T)]
+    pub struct ExMutex<T: ?Sized>(Mutex<T>);
+
+    #[verifier::external_type_specification]
+    #[verifier::external_body]
+    #[verifier::reject_recursive_types(T)]
+    pub struct ExRwLock<T: ?Sized>(RwLock<T>);
+
+    /// The view of the contents of m when clone_mt or new_mt returned.
+    pub uninterp spec fn spec_mutex_contents<T: View>(m: &Mutex<T>) -> T::V;
+
+    /// The view of the contents of l when clone_mt or new_mt returned.
+    pub uninterp spec fn spec_rwlock_contents<T: View>(l: &RwLock<T>) -> T::V;
+
     impl<T: StT + Send> MtT for Mutex<T> {
         type Inner = T;
-        fn clone_mt(&self) -> Self {
-            let inner = self.lock().unwrap().clone();
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { spec_mutex_contents(self) == spec_mutex_contents(other) }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { spec_mutex_contents(self) == inner@ }
+        #[verifier::external_body]
+        fn clone_mt(&self) -> (clone: Self) {
+            // A panic while the lock was held leaves the contents in place, so read them anyway.
+            let inner = self.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
             Mutex::new(inner)
         }
-        fn new_mt(inner: Self::Inner) -> Self { Mutex::new(inner) }
+        #[verifier::external_body]
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { Mutex::new(inner) }
+    }
+
+    impl<T: StT + Send + Sync> MtT for RwLock<T> {
+        type Inner = T;
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { spec_rwlock_contents(self) == spec_rwlock_contents(other) }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { spec_rwlock_contents(self) == inner@ }
+        #[verifier::external_body]
+        fn clone_mt(&self) -> (clone: Self) {
+            let inner = self.read().unwrap_or_else(|poisoned| poisoned.into_inner()).clone();
+            RwLock::new(inner)
+        }
+        #[verifier::external_body]
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { RwLock::new(inner) }
+    }
+
+    // Arc shares rather than copies: clone_mt is another handle to the same value.
+    impl<T: StT + Send + Sync> MtT for Arc<T> {
+        type Inner = T;
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { self@ == other@ }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { self@ == inner@ }
+        fn clone_mt(&self) -> (clone: Self) { Arc::clone(self) }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { Arc::new(inner) }
     }
 
     impl<A: StT + Send + Sync, B: StT + Send + Sync> MtT for Pair<A, B> {
         type Inner = Pair<A, B>;
-        fn clone_mt(&self) -> Self { self.clone() }
-        fn new_mt(inner: Self::Inner) -> Self { inner }
+        open spec fn spec_mt_requires() -> bool { obeys_feq_clone::<Pair<A, B>>() }
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { self@ == other@ }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { self@ == inner@ }
+        fn clone_mt(&self) -> (clone: Self) { self.clone_plus() }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
+    }
+
+    impl<A: StT + Send + Sync, B: StT + Send + Sync, C: StT + Send + Sync> MtT for Triple<A, B, C> {
+        type Inner = Triple<A, B, C>;
+        open spec fn spec_mt_requires() -> bool { obeys_feq_clone::<Triple<A, B, C>>() }
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { self@ == other@ }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { self@ == inner@ }
+        fn clone_mt(&self) -> (clone: Self) { self.clone_plus() }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
+    }
+
+    impl<T: StT + Send + Sync> MtT for Vec<T> {
+        type Inner = Vec<T>;
+        open spec fn spec_mt_requires() -> bool { obeys_feq_clone::<Vec<T>>() }
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { self@ == other@ }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { self@ == inner@ }
+        fn clone_mt(&self) -> (clone: Self) { self.clone_plus() }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
+    }
+
+    impl<T: StT + Send + Sync> MtT for Option<T> {
+        type Inner = Option<T>;
+        open spec fn spec_mt_requires() -> bool { obeys_feq_clone::<Option<T>>() }
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { *self == *other }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { *self == inner }
+        fn clone_mt(&self) -> (clone: Self) { self.clone_plus() }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
     }
 
     // Ad-hoc implementations for specific primitive types to avoid conflicts
     impl MtT for usize {
         type Inner = usize;
-        fn clone_mt(&self) -> Self { *self }
-        fn new_mt(inner: Self::Inner) -> Self { inner }
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { *self == *other }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { *self == inner }
+        fn clone_mt(&self) -> (clone: Self) { *self }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
     }
 
     impl MtT for isize {
         type Inner = isize;
-        fn clone_mt(&self) -> Self { *self }
-        fn new_mt(inner: Self::Inner) -> Self { inner }
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { *self == *other }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { *self == inner }
+        fn clone_mt(&self) -> (clone: Self) { *self }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
     }
 
     impl MtT for i32 {
         type Inner = i32;
-        fn clone_mt(&self) -> Self { *self }
-        fn new_mt(inner: Self::Inner) -> Self { inner }
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { *self == *other }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { *self == inner }
+        fn clone_mt(&self) -> (clone: Self) { *self }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
     }
 
     impl MtT for u32 {
         type Inner = u32;
-        fn clone_mt(&self) -> Self { *self }
-        fn new_mt(inner: Self::Inner) -> Self { inner }
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { *self == *other }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { *self == inner }
+        fn clone_mt(&self) -> (clone: Self) { *self }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
     }
 
     impl MtT for i64 {
         type Inner = i64;
-        fn clone_mt(&self) -> Self { *self }
-        fn new_mt(inner: Self::Inner) -> Self { inner }
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { *self == *other }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { *self == inner }
+        fn clone_mt(&self) -> (clone: Self) { *self }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
     }
 
     impl MtT for u64 {
         type Inner = u64;
-        fn clone_mt(&self) -> Self { *self }
-        fn new_mt(inner: Self::Inner) -> Self { inner }
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { *self == *other }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { *self == inner }
+        fn clone_mt(&self) -> (clone: Self) { *self }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
     }
 
     impl MtT for bool {
         type Inner = bool;
-        fn clone_mt(&self) -> Self { *self }
-        fn new_mt(inner: Self::Inner) -> Self { inner }
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { *self == *other }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { *self == inner }
+        fn clone_mt(&self) -> (clone: Self) { *self }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
     }
 
     impl MtT for char {
         type Inner = char;
-        fn clone_mt(&self) -> Self { *self }
-        fn new_mt(inner: Self::Inner) -> Self { inner }
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { *self == *other }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { *self == inner }
+        fn clone_mt(&self) -> (clone: Self) { *self }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
     }
 
     // Special case: ad-hoc implementation for String
     impl MtT for String {
         type Inner = String;
-        fn clone_mt(&self) -> Self { self.clone() }
-        fn new_mt(inner: Self::Inner) -> Self { inner }
+        open spec fn spec_mt_requires() -> bool { obeys_feq_clone::<String>() }
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { *self == *other }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { *self == inner }
+        fn clone_mt(&self) -> (clone: Self) { self.clone_plus() }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
     }
 
     // String slice implementation
     impl<'a> MtT for &'a str {
         type Inner = &'a str;
-        fn clone_mt(&self) -> Self { self }
-        fn new_mt(inner: Self::Inner) -> Self { inner }
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { *self == *other }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { *self == inner }
+        fn clone_mt(&self) -> (clone: Self) { *self }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
     }
 
+    } // verus!
+
     // Note: bool already has MtT implementation above (line ~112)
     // No custom implementation needed when B = bool
 
-    /// Edge wrapper to enable Display/Debug for pairs (V,V) under baseline bounds.
-    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-    pub struct Edge<V: StT>(pub V, pub V);
-
     impl<V: StT> Display for Edge<V> {
         fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result { write!(f, "({}, {})", self.0, self.1) }
     }
@@ -214,10 +327,6 @@ pub mod Types {
         fn from(e: Edge<V>) -> (V, V) { (e.0, e.1) }
     }
 
-    /// Labeled Edge wrapper to enable edges with labels.
-    #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-    pub struct LabEdge<V: StT, L: StT + Hash>(pub V, pub V, pub L);
-
     impl<V: StT, L: StT + Hash> Display for LabEdge<V, L> {
         fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result { write!(f, "({}, {}, {})", self.0, self.1, self.2) }
     }
@@ -230,14 +339,8 @@ pub mod Types {
         fn from(e: LabEdge<V, L>) -> (V, V, L) { (e.0, e.1, e.2) }
     }
 
-    // Import OrderedFloat from the ordered-float crate
-    // COMMENTED OUT: Not needed for Chapter 5 verified wrappers
-    // pub use ordered_float::OrderedFloat;
-
-    // Convenience type aliases for common float types
-    // COMMENTED OUT: Not needed for Chapter 5 verified wrappers
-    // pub type OrderedF32 = OrderedFloat<f32>;
-    // pub type OrderedF64 = OrderedFloat<f64>;
+    // Totally ordered floats, in-crate rather than from the ordered-float crate.
+    pub use crate::vstdplus::ordered_float::ordered_float::{OrderedF32, OrderedF64};
 
     impl<A, B> From<(A, B)> for Pair<A, B> {
         fn from(t: (A, B)) -> Self { Pair(t.0, t.1) }
@@ -261,26 +364,24 @@ pub mod Types {
         }
     }
 
-    // ARCHITECTURE NOTE: Thread Pool-Based Parallelism
+    // ARCHITECTURE NOTE: Bounded Fork-Join Parallelism
     // ================================================
     // Implements the APAS textbook's || (parallel pair) operator.
-    // Previous implementation spawned unbounded threads, causing exponential growth:
+    // An earlier implementation spawned unbounded threads, causing exponential growth:
     // - 16 elements → ~16 threads
     // - 32 elements → ~32+ threads
     // - Resulted in SIGABRT crashes and thread exhaustion
     //
-    // Current implementation uses rayon's work-stealing thread pool:
-    // - Default pool size (num_cpus threads, typically 8-16)
-    // - Work-stealing prevents deadlock during nested parallelism
-    // - Allows parallel recursion without thread explosion
-    // COMMENTED OUT: Not needed for Chapter 5 verified wrappers
-    // #[macro_export]
-    // macro_rules! ParaPair {
-    //     ( $left:expr, $right:expr ) => {{
-    //         let (left_result, right_result) = rayon::join($left, $right);
-    //         $crate::Types::Types::Pair(left_result, right_result)
-    //     }};
-    // }
+    // ParaPair! calls vstdplus::para_pair::para_pair, built on std::thread::scope:
+    // - At most available_parallelism - 1 helper threads are alive at once
+    // - Past that budget the two closures run sequentially on the calling thread
+    // - ensures conjoins the two closures' postconditions, so Mt algorithms can reason about both halves
+    #[macro_export]
+    macro_rules! ParaPair {
+        ( $left:expr, $right:expr ) => {
+            $crate::vstdplus::para_pair::para_pair::para_pair($left, $right)
+        };
+    }
 
     #[macro_export]
     macro_rules! EdgeLit {
@@ -316,6 +417,120 @@ pub mod Types {
         };
     }
 
+    /// Declares a record struct of StT fields that is a first-class StT key: it generates the
+    /// struct with Debug/Eq/Ord/Hash derives, Clone, Display and a tuple View of the fields, and a
+    /// module of axioms (view injectivity, feq, their broadcast group and valid_key_type). Both
+    /// axioms require fields_obey_feq(), which valid_key_type() implies.
+    /// The derived Ord is lexicographic in field order and gets TotalOrder and PartialOrder
+    /// through OrdTotal, so every field type must implement TotalOrder; the macro checks this.
+    ///
+    /// StTRecord! {
+    ///     pub struct Person { pub name: String, pub age: u32 }
+    ///     axioms Person_axioms;
+    /// }
+    ///
+    /// then `broadcast use Person_axioms::group_axioms;` and `Person_axioms::valid_key_type()`.
+    #[macro_export]
+    macro_rules! StTRecord {
+        (
+            $(#[$attr:meta])*
+            $vis:vis struct $name:ident { $( $fvis:vis $field:ident : $fty:ty ),+ $(,)? }
+            axioms $axioms:ident;
+        ) => {
+            ::vstd::prelude::verus! {
+
+            $(#[$attr])*
+            #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            $vis struct $name { $( $fvis $field : $fty ),+ }
+
+            impl ::core::clone::Clone for $name {
+                fn clone(&self) -> Self {
+                    $name { $( $field: ::core::clone::Clone::clone(&self.$field) ),+ }
+                }
+            }
+
+            impl ::vstd::prelude::View for $name {
+                type V = ( $( <$fty as ::vstd::prelude::View>::V, )+ );
+
+                open spec fn view(&self) -> Self::V { ( $( self.$field@, )+ ) }
+            }
+
+            #[cfg(verus_keep_ghost)]
+            impl $crate::vstdplus::total_order::total_order::TotalOrdSpecImpl for $name {
+                open spec fn obeys_total_ord() -> bool { true }
+            }
+
+            impl $crate::vstdplus::total_order::total_order::OrdTotal for $name {
+                proof fn lemma_obeys_total_order() {}
+            }
+
+            } // verus!
+
+            impl ::std::fmt::Display for $name {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    let fields: &[(&str, &dyn ::std::fmt::Display)] = &[ $( (stringify!($field), &self.$field) ),+ ];
+                    write!(f, "{} {{ ", stringify!($name))?;
+                    for (i, (field, value)) in fields.iter().enumerate() {
+                        if i > 0 { write!(f, ", ")?; }
+                        write!(f, "{}: {}", field, value)?;
+                    }
+                    write!(f, " }}")
+                }
+            }
+
+            // The derived Ord is only a total order if every field's is: check each field type.
+            const _: fn() = || {
+                fn need<T: $crate::vstdplus::total_order::total_order::TotalOrder>() {}
+                $( need::<$fty>(); )+
+            };
+
+            $vis mod $axioms {
+                use super::*;
+                use ::vstd::prelude::*;
+
+                ::vstd::prelude::verus! {
+
+                /// Equal views give equal records when every field's view is injective.
+                pub broadcast proof fn axiom_view_injective(a: $name, b: $name)
+                    requires
+                        fields_obey_feq(),
+                        #[trigger] a@ == #[trigger] b@,
+                    ensures
+                        a == b,
+                {
+                    assert($( a.$field == b.$field )&&+);
+                }
+
+                pub open spec fn fields_obey_feq() -> bool {
+                    $( $crate::vstdplus::feq::feq::obeys_feq_full::<$fty>() )&&+
+                }
+
+                pub open spec fn feq_trigger() -> bool { true }
+
+                pub broadcast proof fn axiom_feq()
+                    requires #[trigger] feq_trigger(), fields_obey_feq()
+                    ensures $crate::vstdplus::feq::feq::obeys_feq_full::<$name>()
+                { admit(); }
+
+                pub broadcast group group_axioms {
+                    axiom_view_injective,
+                    axiom_feq,
+                }
+
+                pub open spec fn valid_key_type() -> bool {
+                    &&& ::vstd::std_specs::hash::obeys_key_model::<$name>()
+                    &&& $crate::vstdplus::feq::feq::obeys_feq_full::<$name>()
+                    $(
+                    &&& ::vstd::std_specs::hash::obeys_key_model::<$fty>()
+                    &&& $crate::vstdplus::feq::feq::obeys_feq_full::<$fty>()
+                    )+
+                }
+
+                } // verus!
+            }
+        };
+    }
+
     verus! {
 
     /// Newtype wrapper for key-value pairs with better Display than tuples
@@ -366,6 +581,217 @@ pub mod Types {
         obeys_feq_full::<K>() && obeys_feq_full::<V>() && obeys_feq_full::<Pair<K, V>>()
     }
 
+    // Triple wrapper for three-element tuples
+    #[derive(Debug, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Triple<A, B, C>(pub A, pub B, pub C);
+
+    impl<A: View, B: View, C: View> View for Triple<A, B, C> {
+        type V = (A::V, B::V, C::V);
+
+        open spec fn view(&self) -> (A::V, B::V, C::V) {(self.0@, self.1@, self.2@)}
+    }
+
+    /// Equal views give equal triples when each component's view is injective.
+    pub broadcast proof fn axiom_Triple_view_injective<A: Eq + View + Clone + Sized, B: Eq + View + Clone + Sized, C: Eq + View + Clone + Sized>(
+        t1: Triple<A, B, C>, t2: Triple<A, B, C>)
+        requires
+            obeys_feq_full::<A>() && obeys_feq_full::<B>() && obeys_feq_full::<C>(),
+            #[trigger] t1@ == #[trigger] t2@,
+        ensures
+            t1 == t2,
+    {
+        assert(t1.0 == t2.0 && t1.1 == t2.1 && t1.2 == t2.2);
+    }
+
+    pub open spec fn Triple_feq_trigger<A, B, C>() -> bool { true }
+
+    pub broadcast proof fn axiom_Triple_feq<A: Eq + View + Clone + Sized, B: Eq + View + Clone + Sized, C: Eq + View + Clone + Sized>()
+        requires
+            #[trigger] Triple_feq_trigger::<A, B, C>(),
+            obeys_feq_full::<A>() && obeys_feq_full::<B>() && obeys_feq_full::<C>(),
+        ensures obeys_feq_full::<Triple<A, B, C>>()
+    { admit(); }
+
+    pub broadcast group group_Triple_axioms {
+        axiom_Triple_view_injective,
+        axiom_Triple_feq,
+    }
+
+    pub open spec fn valid_key_type_Triple<A: Eq + View + Clone + Sized + Hash, B: Eq + View + Clone + Sized + Hash, C: Eq + View + Clone + Sized + Hash>() -> bool {
+        &&& obeys_key_model::<A>() && obeys_key_model::<B>() && obeys_key_model::<C>() && obeys_key_model::<Triple<A, B, C>>()
+        &&& obeys_feq_full::<A>() && obeys_feq_full::<B>() && obeys_feq_full::<C>() && obeys_feq_full::<Triple<A, B, C>>()
+    }
+
+    // Quadruple wrapper for four-element tuples
+    #[derive(Debug, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Quadruple<A, B, C, D>(pub A, pub B, pub C, pub D);
+
+    impl<A: View, B: View, C: View, D: View> View for Quadruple<A, B, C, D> {
+        type V = (A::V, B::V, C::V, D::V);
+
+        open spec fn view(&self) -> (A::V, B::V, C::V, D::V) {(self.0@, self.1@, self.2@, self.3@)}
+    }
+
+    /// Equal views give equal quadruples when each component's view is injective.
+    pub broadcast proof fn axiom_Quadruple_view_injective<
+        A: Eq + View + Clone + Sized, B: Eq + View + Clone + Sized, C: Eq + View + Clone + Sized, D: Eq + View + Clone + Sized>(
+        q1: Quadruple<A, B, C, D>, q2: Quadruple<A, B, C, D>)
+        requires
+            obeys_feq_full::<A>() && obeys_feq_full::<B>() && obeys_feq_full::<C>() && obeys_feq_full::<D>(),
+            #[trigger] q1@ == #[trigger] q2@,
+        ensures
+            q1 == q2,
+    {
+        assert(q1.0 == q2.0 && q1.1 == q2.1 && q1.2 == q2.2 && q1.3 == q2.3);
+    }
+
+    pub open spec fn Quadruple_feq_trigger<A, B, C, D>() -> bool { true }
+
+    pub broadcast proof fn axiom_Quadruple_feq<
+        A: Eq + View + Clone + Sized, B: Eq + View + Clone + Sized, C: Eq + View + Clone + Sized, D: Eq + View + Clone + Sized>()
+        requires
+            #[trigger] Quadruple_feq_trigger::<A, B, C, D>(),
+            obeys_feq_full::<A>() && obeys_feq_full::<B>() && obeys_feq_full::<C>() && obeys_feq_full::<D>(),
+        ensures obeys_feq_full::<Quadruple<A, B, C, D>>()
+    { admit(); }
+
+    pub broadcast group group_Quadruple_axioms {
+        axiom_Quadruple_view_injective,
+        axiom_Quadruple_feq,
+    }
+
+    pub open spec fn valid_key_type_Quadruple<
+        A: Eq + View + Clone + Sized + Hash, B: Eq + View + Clone + Sized + Hash,
+        C: Eq + View + Clone + Sized + Hash, D: Eq + View + Clone + Sized + Hash>() -> bool {
+        &&& obeys_key_model::<A>() && obeys_key_model::<B>() && obeys_key_model::<C>() && obeys_key_model::<D>()
+        &&& obeys_key_model::<Quadruple<A, B, C, D>>()
+        &&& obeys_feq_full::<A>() && obeys_feq_full::<B>() && obeys_feq_full::<C>() && obeys_feq_full::<D>()
+        &&& obeys_feq_full::<Quadruple<A, B, C, D>>()
+    }
+
+    // Key-value struct with named fields
+    #[derive(Debug, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct KeyVal<K, V> {
+        pub key: K,
+        pub val: V,
+    }
+
+    impl<K: View, V: View> View for KeyVal<K, V> {
+        type V = (K::V, V::V);
+
+        open spec fn view(&self) -> (K::V, V::V) {(self.key@, self.val@)}
+    }
+
+    /// Equal views give equal key-values when the key and value views are injective.
+    pub broadcast proof fn axiom_KeyVal_view_injective<K: Eq + View + Clone + Sized, V: Eq + View + Clone + Sized>(kv1: KeyVal<K, V>, kv2: KeyVal<K, V>)
+        requires
+            obeys_feq_full::<K>() && obeys_feq_full::<V>(),
+            #[trigger] kv1@ == #[trigger] kv2@,
+        ensures
+            kv1 == kv2,
+    {
+        assert(kv1.key == kv2.key && kv1.val == kv2.val);
+    }
+
+    pub open spec fn KeyVal_feq_trigger<K, V>() -> bool { true }
+
+    pub broadcast proof fn axiom_KeyVal_feq<K: Eq + View + Clone + Sized, V: Eq + View + Clone + Sized>()
+        requires
+            #[trigger] KeyVal_feq_trigger::<K, V>(),
+            obeys_feq_full::<K>() && obeys_feq_full::<V>(),
+        ensures obeys_feq_full::<KeyVal<K, V>>()
+    { admit(); }
+
+    pub broadcast group group_KeyVal_axioms {
+        axiom_KeyVal_view_injective,
+        axiom_KeyVal_feq,
+    }
+
+    pub open spec fn valid_key_type_KeyVal<K: Eq + View + Clone + Sized + Hash, V: Eq + View + Clone + Sized + Hash>() -> bool {
+        &&& obeys_key_model::<K>() && obeys_key_model::<V>() && obeys_key_model::<KeyVal<K, V>>()
+        &&& obeys_feq_full::<K>() && obeys_feq_full::<V>() && obeys_feq_full::<KeyVal<K, V>>()
+    }
+
+    /// Edge wrapper to enable Display/Debug for pairs (V,V) under baseline bounds.
+    #[derive(Copy, PartialEq, Eq, Hash, Debug)]
+    pub struct Edge<V: StT>(pub V, pub V);
+
+    impl<V: StT> View for Edge<V> {
+        type V = (V::V, V::V);
+
+        open spec fn view(&self) -> (V::V, V::V) {(self.0@, self.1@)}
+    }
+
+    /// Equal views give equal edges when the vertex view is injective.
+    pub broadcast proof fn axiom_Edge_view_injective<V: StT>(e1: Edge<V>, e2: Edge<V>)
+        requires
+            obeys_feq_full::<V>(),
+            #[trigger] e1@ == #[trigger] e2@,
+        ensures
+            e1 == e2,
+    {
+        assert(e1.0 == e2.0 && e1.1 == e2.1);
+    }
+
+    pub open spec fn Edge_feq_trigger<V>() -> bool { true }
+
+    pub broadcast proof fn axiom_Edge_feq<V: StT>()
+        requires
+            #[trigger] Edge_feq_trigger::<V>(),
+            obeys_feq_full::<V>(),
+        ensures obeys_feq_full::<Edge<V>>()
+    { admit(); }
+
+    pub broadcast group group_Edge_axioms {
+        axiom_Edge_view_injective,
+        axiom_Edge_feq,
+    }
+
+    pub open spec fn valid_key_type_Edge<V: StT + Hash>() -> bool {
+        &&& obeys_key_model::<V>() && obeys_key_model::<Edge<V>>()
+        &&& obeys_feq_full::<V>() && obeys_feq_full::<Edge<V>>()
+    }
+
+    /// Labeled Edge wrapper to enable edges with labels.
+    #[derive(Copy, PartialEq, Eq, Hash, Debug)]
+    pub struct LabEdge<V: StT, L: StT + Hash>(pub V, pub V, pub L);
+
+    impl<V: StT, L: StT + Hash> View for LabEdge<V, L> {
+        type V = (V::V, V::V, L::V);
+
+        open spec fn view(&self) -> (V::V, V::V, L::V) {(self.0@, self.1@, self.2@)}
+    }
+
+    /// Equal views give equal labeled edges when the vertex and label views are injective.
+    pub broadcast proof fn axiom_LabEdge_view_injective<V: StT, L: StT + Hash>(e1: LabEdge<V, L>, e2: LabEdge<V, L>)
+        requires
+            obeys_feq_full::<V>() && obeys_feq_full::<L>(),
+            #[trigger] e1@ == #[trigger] e2@,
+        ensures
+            e1 == e2,
+    {
+        assert(e1.0 == e2.0 && e1.1 == e2.1 && e1.2 == e2.2);
+    }
+
+    pub open spec fn LabEdge_feq_trigger<V, L>() -> bool { true }
+
+    pub broadcast proof fn axiom_LabEdge_feq<V: StT, L: StT + Hash>()
+        requires
+            #[trigger] LabEdge_feq_trigger::<V, L>(),
+            obeys_feq_full::<V>() && obeys_feq_full::<L>(),
+        ensures obeys_feq_full::<LabEdge<V, L>>()
+    { admit(); }
+
+    pub broadcast group group_LabEdge_axioms {
+        axiom_LabEdge_view_injective,
+        axiom_LabEdge_feq,
+    }
+
+    pub open spec fn valid_key_type_LabEdge<V: StT + Hash, L: StT + Hash>() -> bool {
+        &&& obeys_key_model::<V>() && obeys_key_model::<L>() && obeys_key_model::<LabEdge<V, L>>()
+        &&& obeys_feq_full::<V>() && obeys_feq_full::<L>() && obeys_feq_full::<LabEdge<V, L>>()
+    }
+
     // Newtype wrapper for Pair iterator to implement ForLoopGhostIterator (orphan rule)
     // Note: Currently unused due to Verus limitation - for loops don't recognize ForLoopGhostIteratorNew
     // on newtype wrappers. Kept for future use when this is supported.
@@ -460,6 +886,36 @@ pub mod Types {
         }
     }
 
+    impl<A: Clone, B: Clone, C: Clone> Clone for Triple<A, B, C> {
+        fn clone(&self) -> Self {
+            Triple(self.0.clone(), self.1.clone(), self.2.clone())
+        }
+    }
+
+    impl<A: Clone, B: Clone, C: Clone, D: Clone> Clone for Quadruple<A, B, C, D> {
+        fn clone(&self) -> Self {
+            Quadruple(self.0.clone(), self.1.clone(), self.2.clone(), self.3.clone())
+        }
+    }
+
+    impl<K: Clone, V: Clone> Clone for KeyVal<K, V> {
+        fn clone(&self) -> Self {
+            KeyVal { key: self.key.clone(), val: self.val.clone() }
+        }
+    }
+
+    impl<V: StT> Clone for Edge<V> {
+        fn clone(&self) -> Self {
+            Edge(self.0.clone(), self.1.clone())
+        }
+    }
+
+    impl<V: StT, L: StT + Hash> Clone for LabEdge<V, L> {
+        fn clone(&self) -> Self {
+            LabEdge(self.0.clone(), self.1.clone(), self.2.clone())
+        }
+    }
+
     // Display implementation for Pair (outside verus! block)
     impl<K: Display, V: Display> Display for Pair<K, V> {
         fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -12,12 +12,31 @@ pub mod Chap05 {
     pub mod MappingStEph;
 }
 
+pub mod Chap06 {
+    pub mod DirGraphStEph;
+    pub mod UnDirGraphStEph;
+    pub mod LabDirGraphStEph;
+    pub mod LabUnDirGraphStEph;
+    pub mod GraphStEph;
+    pub mod AdjTableGraphStEph;
+    pub mod CsrGraphStEph;
+}
+
+pub mod Chap43 {
+    pub mod OrderedTableStEph;
+}
+
+pub mod Chap54 {
+    pub mod BFSStEph;
+}
+
 pub mod vstdplus {
     pub mod set;
     pub mod set_with_view;
     pub mod set_axioms;
     pub mod seq_set;
     pub mod hash_set_with_view_plus;
+    pub mod hash_map_with_view_plus;
     pub mod hash_set_specs;
     pub mod total_order;
     pub mod partial_order;
@@ -25,4 +44,7 @@ pub mod vstdplus {
     pub mod feq;
     pub mod clone_plus;
     pub mod vec;
+    pub mod para_pair;
+    pub mod work_stealing_pool;
+    pub mod ordered_float;
 }
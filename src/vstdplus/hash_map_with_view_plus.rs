@@ -0,0 +1,184 @@
+//! HashMapWithViewPlus - wrapper around std::collections::HashMap with a Map view
+//! Like vstd::hash_map::HashMapWithView, but remove hands back the removed value.
+
+pub mod hash_map_with_view_plus {
+
+use vstd::prelude::*;
+
+#[cfg(verus_keep_ghost)]
+use vstd::std_specs::hash::obeys_key_model;
+use core::hash::Hash;
+use std::collections::HashMap;
+use crate::vstdplus::feq::feq::*;
+
+verus! {
+
+#[verifier::reject_recursive_types(Key)]
+#[verifier::reject_recursive_types(Value)]
+pub struct HashMapWithViewPlus<Key: View + Eq + Hash, Value> {
+    pub m: HashMap<Key, Value>,
+}
+
+impl<Key: View + Eq + Hash, Value> View for HashMapWithViewPlus<Key, Value> {
+    type V = Map<<Key as View>::V, Value>;
+
+    uninterp spec fn view(&self) -> Self::V;
+}
+
+// Iterator wrapper to hide std::collections::hash_map::Iter
+#[verifier::reject_recursive_types(Key)]
+#[verifier::reject_recursive_types(Value)]
+pub struct HashMapWithViewPlusIter<'a, Key: View + Eq + Hash, Value> {
+    pub inner: std::collections::hash_map::Iter<'a, Key, Value>,
+}
+
+impl<'a, Key: View + Eq + Hash, Value> View for HashMapWithViewPlusIter<'a, Key, Value> {
+    type V = (int, Seq<(Key, Value)>);
+
+    uninterp spec fn view(&self) -> Self::V;
+}
+
+impl<'a, Key: View + Eq + Hash, Value> HashMapWithViewPlusIter<'a, Key, Value> {
+    #[verifier::external_body]
+    pub fn next(&mut self) -> (result: Option<(&'a Key, &'a Value)>)
+        ensures ({
+            let (old_index, old_seq) = old(self)@;
+            match result {
+                None => {
+                    &&& self@ == old(self)@
+                    &&& old_index >= old_seq.len()
+                },
+                Some((k, v)) => {
+                    let (new_index, new_seq) = self@;
+                    &&& 0 <= old_index < old_seq.len()
+                    &&& new_seq == old_seq
+                    &&& new_index == old_index + 1
+                    &&& *k == old_seq[old_index].0
+                    &&& *v == old_seq[old_index].1
+                },
+            }
+        })
+    {
+        self.inner.next()
+    }
+}
+
+impl<Key: View + Eq + Hash + Clone, Value: Clone> Clone for HashMapWithViewPlus<Key, Value> {
+    #[verifier::external_body]
+    fn clone(&self) -> (result: Self)
+        ensures result@.dom() == self@.dom(),
+                forall |k: Key::V| #[trigger] result@.contains_key(k) ==> cloned(self@[k], result@[k]),
+    {
+        HashMapWithViewPlus { m: self.m.clone() }
+    }
+}
+
+impl<Key: View + Eq + Hash + Clone, Value> HashMapWithViewPlus<Key, Value> {
+    #[verifier::external_body]
+    pub fn new() -> (result: Self)
+        requires
+            obeys_key_model::<Key>(),
+            obeys_feq_full::<Key>(),
+        ensures
+            result@ == Map::<<Key as View>::V, Value>::empty(),
+    { HashMapWithViewPlus { m: HashMap::new() } }
+
+    #[verifier::external_body]
+    pub fn len(&self) -> (len: usize)
+        ensures
+            len == self@.len(),
+    { self.m.len() }
+
+    #[verifier::external_body]
+    pub fn contains_key(&self, k: &Key) -> (contains: bool)
+        requires
+            obeys_key_model::<Key>(),
+        ensures
+            contains == self@.contains_key(k@),
+    { self.m.contains_key(k) }
+
+    #[verifier::external_body]
+    pub fn get<'a>(&'a self, k: &Key) -> (value: Option<&'a Value>)
+        requires
+            obeys_key_model::<Key>(),
+        ensures
+            match value {
+                Some(v) => self@.contains_key(k@) && *v == self@[k@],
+                None => !self@.contains_key(k@),
+            },
+    { self.m.get(k) }
+
+    #[verifier::external_body]
+    pub fn insert(&mut self, k: Key, v: Value) -> (previous: Option<Value>)
+        requires
+            obeys_key_model::<Key>(),
+            obeys_feq_full::<Key>(),
+        ensures
+            self@ == old(self)@.insert(k@, v),
+            match previous {
+                Some(p) => old(self)@.contains_key(k@) && p == old(self)@[k@],
+                None => !old(self)@.contains_key(k@),
+            },
+    { self.m.insert(k, v) }
+
+    #[verifier::external_body]
+    pub fn remove(&mut self, k: &Key) -> (removed: Option<Value>)
+        requires
+            obeys_key_model::<Key>(),
+        ensures
+            self@ == old(self)@.remove(k@),
+            match removed {
+                Some(v) => old(self)@.contains_key(k@) && v == old(self)@[k@],
+                None => !old(self)@.contains_key(k@),
+            },
+    { self.m.remove(k) }
+
+    /// Each entry appears exactly once, and no two entries share a key view.
+    #[verifier::external_body]
+    pub fn iter(&self) -> (it: HashMapWithViewPlusIter<'_, Key, Value>)
+        ensures
+            it@.0 == 0int,
+            it@.1.len() == self@.len(),
+            forall |i: int| #![trigger it@.1[i]] 0 <= i < it@.1.len() ==>
+                self@.contains_key(it@.1[i].0@) && self@[it@.1[i].0@] == it@.1[i].1,
+            forall |k: Key::V| #[trigger] self@.contains_key(k) ==>
+                exists |i: int| #![trigger it@.1[i]] 0 <= i < it@.1.len() && it@.1[i].0@ == k,
+            forall |i: int, j: int| #![trigger it@.1[i], it@.1[j]]
+                0 <= i < it@.1.len() && 0 <= j < it@.1.len() && it@.1[i].0@ == it@.1[j].0@ ==> i == j,
+    { HashMapWithViewPlusIter { inner: self.m.iter() } }
+}
+
+impl<Key: View + Eq + Hash, Value: Hash> std::hash::Hash for HashMapWithViewPlus<Key, Value> {
+    /// Entry hashes are combined by addition so that iteration order does not matter.
+    #[verifier::external_body]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let mut sum: u64 = 0;
+        for (k, v) in self.m.iter() {
+            let mut h = std::collections::hash_map::DefaultHasher::new();
+            k.hash(&mut h);
+            v.hash(&mut h);
+            sum = sum.wrapping_add(std::hash::Hasher::finish(&h));
+        }
+        state.write_usize(self.m.len());
+        state.write_u64(sum);
+    }
+}
+
+impl<Key: View + Eq + Hash, Value: PartialEq> PartialEq for HashMapWithViewPlus<Key, Value> {
+    #[verifier::external_body]
+    fn eq(&self, other: &Self) -> bool { self.m == other.m }
+}
+
+impl<Key: View + Eq + Hash, Value: Eq> Eq for HashMapWithViewPlus<Key, Value> {}
+
+} // verus!
+
+// Implement std::iter::Iterator for HashMapWithViewPlusIter to enable standard iteration methods
+impl<'a, Key: View + Eq + Hash, Value> std::iter::Iterator for HashMapWithViewPlusIter<'a, Key, Value> {
+    type Item = (&'a Key, &'a Value);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+}
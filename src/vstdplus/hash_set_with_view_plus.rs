@@ -67,6 +67,15 @@ impl<Key: View + Eq + Hash + Clone> HashSetWithViewPlus<Key> {
             self@ == old(self)@.insert(k@),
             inserted == !old(self)@.contains(k@),
     { self.inner.insert(k) }
+
+    #[verifier::external_body]
+    pub fn remove(&mut self, k: &Key) -> (removed: bool)
+        requires
+            obeys_key_model::<Key>(),
+        ensures
+            self@ == old(self)@.remove(k@),
+            removed == old(self)@.contains(k@),
+    { self.inner.m.remove(k) }
 }
 
 pub trait HashSetWithViewPlusTrait<Key: View + Eq + Hash>: View<V = Set<<Key as View>::V>> {
@@ -88,11 +97,17 @@ impl<Key: View + Eq + Hash> HashSetWithViewPlusTrait<Key> for HashSetWithViewPlu
 }
 
 impl<Key: View + Eq + Hash> std::hash::Hash for HashSetWithViewPlus<Key> {
+    /// Element hashes are combined by addition so that iteration order does not matter.
     #[verifier::external_body]
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let mut sum: u64 = 0;
         for key in self.inner.m.iter() {
-            key.hash(state);
+            let mut h = std::collections::hash_map::DefaultHasher::new();
+            key.hash(&mut h);
+            sum = sum.wrapping_add(std::hash::Hasher::finish(&h));
         }
+        state.write_usize(self.inner.m.len());
+        state.write_u64(sum);
     }
 }
 
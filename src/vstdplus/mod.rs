@@ -3,6 +3,7 @@ pub mod set_with_view;
 pub mod set_axioms;
 pub mod seq_set;
 pub mod hash_set_with_view_plus;
+pub mod hash_map_with_view_plus;
 pub mod hash_set_specs;
 pub mod total_order;
 pub mod partial_order;
@@ -10,4 +11,7 @@ pub mod clone_view;
 pub mod feq;
 pub mod clone_plus;
 pub mod vec;
+pub mod para_pair;
+pub mod work_stealing_pool;
+pub mod ordered_float;
 
@@ -0,0 +1,314 @@
+//! ordered_float - Totally ordered f32/f64 newtypes usable as StT keys
+//!
+//! The constructors canonicalize: every NaN becomes one NaN, greater than all other values,
+//! and -0.0 becomes +0.0. The View is a u64 order key, monotone in the float order, so
+//! TotalOrder is <= on views and Eq/Hash follow the same key.
+
+pub mod ordered_float {
+    use core::cmp::Ordering;
+    use std::fmt::{Debug, Display, Formatter};
+    use std::hash::{Hash, Hasher};
+    use vstd::prelude::*;
+    use crate::Types::Types::MtT;
+    use crate::vstdplus::total_order::total_order::TotalOrder;
+    #[cfg(verus_keep_ghost)]
+    use crate::vstdplus::total_order::total_order::TotalOrdSpecImpl;
+    #[cfg(verus_keep_ghost)]
+    use crate::vstdplus::feq::feq::*;
+
+    verus! {
+
+    /// The order key of an f32: NaN is the largest key, and the sign-magnitude bits are
+    /// flipped into an unsigned order. Uninterpreted because Verus does not model float bits.
+    pub uninterp spec fn f32_order_key(x: f32) -> u64;
+
+    /// The order key of an f64, as for f32_order_key.
+    pub uninterp spec fn f64_order_key(x: f64) -> u64;
+
+    /// f32 with a NaN-aware total order: NaN == NaN, NaN above everything, -0.0 == 0.0.
+    #[derive(Copy)]
+    pub struct OrderedF32(f32);
+
+    /// f64 with a NaN-aware total order: NaN == NaN, NaN above everything, -0.0 == 0.0.
+    #[derive(Copy)]
+    pub struct OrderedF64(f64);
+
+    impl Clone for OrderedF32 {
+        fn clone(&self) -> (clone: Self)
+            ensures clone == *self
+        {
+            *self
+        }
+    }
+
+    impl Clone for OrderedF64 {
+        fn clone(&self) -> (clone: Self)
+            ensures clone == *self
+        {
+            *self
+        }
+    }
+
+    impl View for OrderedF32 {
+        type V = u64;
+
+        open spec fn view(&self) -> u64 { f32_order_key(self.0) }
+    }
+
+    impl View for OrderedF64 {
+        type V = u64;
+
+        open spec fn view(&self) -> u64 { f64_order_key(self.0) }
+    }
+
+    /// Keys are injective on canonical floats, and the constructors only make canonical floats.
+    pub broadcast proof fn axiom_OrderedF32_view_injective(x: OrderedF32, y: OrderedF32)
+        requires
+            #[trigger] x@ == #[trigger] y@,
+        ensures
+            x == y,
+    {
+        admit();
+    }
+
+    /// Keys are injective on canonical floats, and the constructors only make canonical floats.
+    pub broadcast proof fn axiom_OrderedF64_view_injective(x: OrderedF64, y: OrderedF64)
+        requires
+            #[trigger] x@ == #[trigger] y@,
+        ensures
+            x == y,
+    {
+        admit();
+    }
+
+    pub open spec fn OrderedF32_feq_trigger() -> bool { true }
+
+    pub broadcast proof fn axiom_OrderedF32_feq()
+        requires #[trigger] OrderedF32_feq_trigger()
+        ensures obeys_feq_full::<OrderedF32>()
+    { admit(); }
+
+    pub open spec fn OrderedF64_feq_trigger() -> bool { true }
+
+    pub broadcast proof fn axiom_OrderedF64_feq()
+        requires #[trigger] OrderedF64_feq_trigger()
+        ensures obeys_feq_full::<OrderedF64>()
+    { admit(); }
+
+    pub broadcast group group_OrderedFloat_axioms {
+        axiom_OrderedF32_view_injective,
+        axiom_OrderedF64_view_injective,
+        axiom_OrderedF32_feq,
+        axiom_OrderedF64_feq,
+    }
+
+    #[cfg(verus_keep_ghost)]
+    impl FeqSpecImpl for OrderedF32 {
+        open spec fn obeys_feq() -> bool { obeys_feq_properties::<OrderedF32>() }
+    }
+
+    #[cfg(verus_keep_ghost)]
+    impl FeqSpecImpl for OrderedF64 {
+        open spec fn obeys_feq() -> bool { obeys_feq_properties::<OrderedF64>() }
+    }
+
+    // The Ord impls below are the same total order, so insSort and other Ord code may use them.
+    #[cfg(verus_keep_ghost)]
+    impl TotalOrdSpecImpl for OrderedF32 {
+        open spec fn obeys_total_ord() -> bool { true }
+    }
+
+    #[cfg(verus_keep_ghost)]
+    impl TotalOrdSpecImpl for OrderedF64 {
+        open spec fn obeys_total_ord() -> bool { true }
+    }
+
+    impl OrderedF32 {
+        #[verifier::external_body]
+        pub fn new(x: f32) -> (r: OrderedF32) {
+            if x.is_nan() { OrderedF32(f32::NAN) } else if x == 0.0 { OrderedF32(0.0) } else { OrderedF32(x) }
+        }
+
+        /// The canonical float: NaN for any NaN, 0.0 for -0.0.
+        pub fn get(&self) -> f32 {
+            self.0
+        }
+
+        #[verifier::external_body]
+        pub fn order_key(&self) -> (k: u64)
+            ensures k == self@
+        {
+            if self.0.is_nan() {
+                return u64::MAX;
+            }
+            let bits = self.0.to_bits() as u64;
+            if bits & (1 << 31) != 0 { !bits & 0xFFFF_FFFF } else { bits | (1 << 31) }
+        }
+    }
+
+    impl OrderedF64 {
+        #[verifier::external_body]
+        pub fn new(x: f64) -> (r: OrderedF64) {
+            if x.is_nan() { OrderedF64(f64::NAN) } else if x == 0.0 { OrderedF64(0.0) } else { OrderedF64(x) }
+        }
+
+        /// The canonical float: NaN for any NaN, 0.0 for -0.0.
+        pub fn get(&self) -> f64 {
+            self.0
+        }
+
+        #[verifier::external_body]
+        pub fn order_key(&self) -> (k: u64)
+            ensures k == self@
+        {
+            if self.0.is_nan() {
+                return u64::MAX;
+            }
+            let bits = self.0.to_bits();
+            if bits & (1 << 63) != 0 { !bits } else { bits | (1 << 63) }
+        }
+    }
+
+    impl TotalOrder for OrderedF32 {
+        open spec fn le(self, other: Self) -> bool {
+            self@ <= other@
+        }
+
+        proof fn reflexive(x: Self) {
+        }
+
+        proof fn transitive(x: Self, y: Self, z: Self) {
+        }
+
+        proof fn antisymmetric(x: Self, y: Self) {
+            axiom_OrderedF32_view_injective(x, y);
+        }
+
+        proof fn total(x: Self, y: Self) {
+        }
+
+        fn cmp(&self, other: &Self) -> (c: Ordering) {
+            let a = self.order_key();
+            let b = other.order_key();
+            if a < b {
+                Ordering::Less
+            } else if a == b {
+                proof { axiom_OrderedF32_view_injective(*self, *other); }
+                Ordering::Equal
+            } else {
+                Ordering::Greater
+            }
+        }
+    }
+
+    impl TotalOrder for OrderedF64 {
+        open spec fn le(self, other: Self) -> bool {
+            self@ <= other@
+        }
+
+        proof fn reflexive(x: Self) {
+        }
+
+        proof fn transitive(x: Self, y: Self, z: Self) {
+        }
+
+        proof fn antisymmetric(x: Self, y: Self) {
+            axiom_OrderedF64_view_injective(x, y);
+        }
+
+        proof fn total(x: Self, y: Self) {
+        }
+
+        fn cmp(&self, other: &Self) -> (c: Ordering) {
+            let a = self.order_key();
+            let b = other.order_key();
+            if a < b {
+                Ordering::Less
+            } else if a == b {
+                proof { axiom_OrderedF64_view_injective(*self, *other); }
+                Ordering::Equal
+            } else {
+                Ordering::Greater
+            }
+        }
+    }
+
+    impl MtT for OrderedF32 {
+        type Inner = OrderedF32;
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { *self == *other }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { *self == inner }
+        fn clone_mt(&self) -> (clone: Self) { *self }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
+    }
+
+    impl MtT for OrderedF64 {
+        type Inner = OrderedF64;
+        open spec fn spec_mt_same(&self, other: &Self) -> bool { *self == *other }
+        open spec fn spec_mt_holds(&self, inner: Self::Inner) -> bool { *self == inner }
+        fn clone_mt(&self) -> (clone: Self) { *self }
+        fn new_mt(inner: Self::Inner) -> (mt: Self) { inner }
+    }
+
+    } // verus!
+
+    impl From<f32> for OrderedF32 {
+        fn from(x: f32) -> Self { OrderedF32::new(x) }
+    }
+
+    impl From<f64> for OrderedF64 {
+        fn from(x: f64) -> Self { OrderedF64::new(x) }
+    }
+
+    impl PartialEq for OrderedF32 {
+        fn eq(&self, other: &Self) -> bool { self.order_key() == other.order_key() }
+    }
+
+    impl Eq for OrderedF32 {}
+
+    impl PartialEq for OrderedF64 {
+        fn eq(&self, other: &Self) -> bool { self.order_key() == other.order_key() }
+    }
+
+    impl Eq for OrderedF64 {}
+
+    impl PartialOrd for OrderedF32 {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(Ord::cmp(self, other)) }
+    }
+
+    impl Ord for OrderedF32 {
+        fn cmp(&self, other: &Self) -> Ordering { self.order_key().cmp(&other.order_key()) }
+    }
+
+    impl PartialOrd for OrderedF64 {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(Ord::cmp(self, other)) }
+    }
+
+    impl Ord for OrderedF64 {
+        fn cmp(&self, other: &Self) -> Ordering { self.order_key().cmp(&other.order_key()) }
+    }
+
+    impl Hash for OrderedF32 {
+        fn hash<H: Hasher>(&self, state: &mut H) { self.order_key().hash(state) }
+    }
+
+    impl Hash for OrderedF64 {
+        fn hash<H: Hasher>(&self, state: &mut H) { self.order_key().hash(state) }
+    }
+
+    impl Debug for OrderedF32 {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result { write!(f, "OrderedF32({:?})", self.0) }
+    }
+
+    impl Debug for OrderedF64 {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result { write!(f, "OrderedF64({:?})", self.0) }
+    }
+
+    impl Display for OrderedF32 {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result { Display::fmt(&self.0, f) }
+    }
+
+    impl Display for OrderedF64 {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result { Display::fmt(&self.0, f) }
+    }
+
+}
@@ -0,0 +1,90 @@
+//! para_pair - Verified fork-join (APAS's || operator) on std::thread::scope
+//!
+//! Threads are drawn from a process-wide budget of available_parallelism helpers; once the
+//! budget is spent, nested forks run sequentially on the calling thread, so deep parallel
+//! recursion cannot explode the thread count.
+
+pub mod para_pair {
+    use vstd::prelude::*;
+    use std::sync::OnceLock;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use crate::Types::Types::Pair;
+
+    static HELPERS_IN_USE: AtomicUsize = AtomicUsize::new(0);
+    static HELPER_BUDGET: OnceLock<usize> = OnceLock::new();
+
+    /// The most helper threads alive at once: one per available core, less the caller's own.
+    pub fn thread_budget() -> usize {
+        *HELPER_BUDGET.get_or_init(|| {
+            std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1).saturating_sub(1)
+        })
+    }
+
+    /// Helper threads currently forked and not yet joined.
+    pub fn helpers_in_use() -> usize {
+        HELPERS_IN_USE.load(Ordering::Acquire)
+    }
+
+    // Returns the reserved helper to the budget when the fork is joined, even on panic.
+    struct HelperGuard;
+
+    impl Drop for HelperGuard {
+        fn drop(&mut self) {
+            HELPERS_IN_USE.fetch_sub(1, Ordering::AcqRel);
+        }
+    }
+
+    verus! {
+
+    /// Reserve one helper thread from the budget; false when it is spent.
+    #[verifier::external_body]
+    fn try_reserve_helper() -> (reserved: bool) {
+        let budget = thread_budget();
+        HELPERS_IN_USE
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| if n < budget { Some(n + 1) } else { None })
+            .is_ok()
+    }
+
+    /// Run fa on a scoped helper thread and fb on this one, releasing the reserved helper.
+    /// Trusted: std::thread::scope has no Verus spec, but the closures run exactly once each.
+    #[verifier::external_body]
+    fn fork_join<A: Send, B: Send, FA: FnOnce() -> A + Send, FB: FnOnce() -> B>(fa: FA, fb: FB) -> (r: (A, B))
+        requires
+            fa.requires(()),
+            fb.requires(()),
+        ensures
+            fa.ensures((), r.0),
+            fb.ensures((), r.1),
+    {
+        let _guard = HelperGuard;
+        std::thread::scope(|s| {
+            let left = s.spawn(fa);
+            let b = fb();
+            match left.join() {
+                Ok(a) => (a, b),
+                Err(panic) => std::panic::resume_unwind(panic),
+            }
+        })
+    }
+
+    /// APAS: Work W(fa) + W(fb), Span max(S(fa), S(fb)) while helpers remain, else S(fa) + S(fb).
+    pub fn para_pair<A: Send, B: Send, FA: FnOnce() -> A + Send, FB: FnOnce() -> B>(fa: FA, fb: FB) -> (pair: Pair<A, B>)
+        requires
+            fa.requires(()),
+            fb.requires(()),
+        ensures
+            fa.ensures((), pair.0),
+            fb.ensures((), pair.1),
+    {
+        if try_reserve_helper() {
+            let (a, b) = fork_join(fa, fb);
+            Pair(a, b)
+        } else {
+            let a = fa();
+            let b = fb();
+            Pair(a, b)
+        }
+    }
+
+    } // verus!
+}
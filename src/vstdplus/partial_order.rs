@@ -6,12 +6,23 @@
 pub mod partial_order {
     use core::cmp::Ordering;
     use vstd::prelude::*;
+    #[cfg(verus_keep_ghost)]
+    use vstd::std_specs::cmp::OrdSpec;
+    use crate::vstdplus::total_order::total_order::{OrdTotal, ord_le, axiom_obeys_total_order};
+    use crate::Types::Types::{Pair, StT};
+    use crate::Chap05::SetStEph::SetStEph::*;
+    use std::hash::Hash;
+    #[cfg(verus_keep_ghost)]
+    use vstd::arithmetic::div_mod::{lemma_fundamental_div_mod, lemma_mod_multiples_basic, lemma_mod_self_0};
 
     verus! {
 
     pub trait PartialOrder: Sized {
         spec fn le(self, other: Self) -> bool;
 
+        /// What antisymmetric and compare need of the type, e.g. valid_key_type for sets.
+        spec fn spec_po_requires() -> bool { true }
+
         proof fn reflexive(x: Self)
             ensures
                 Self::le(x, x),
@@ -27,6 +38,7 @@ pub mod partial_order {
 
         proof fn antisymmetric(x: Self, y: Self)
             requires
+                Self::spec_po_requires(),
                 Self::le(x, y),
                 Self::le(y, x),
             ensures
@@ -34,6 +46,8 @@ pub mod partial_order {
         ;
 
         fn compare(&self, other: &Self) -> (c: Option<Ordering>)
+            requires
+                Self::spec_po_requires(),
             ensures
                 (match c {
                     Some(Ordering::Less) => self.le(*other) && self != other,
@@ -404,6 +418,387 @@ pub mod partial_order {
         }
     }
 
+    // Ord types that opt in through OrdTotal are totally, hence partially, ordered.
+    impl<T: OrdTotal> PartialOrder for T {
+        open spec fn le(self, other: Self) -> bool {
+            ord_le(self, other)
+        }
+
+        proof fn reflexive(x: Self) {
+            T::lemma_obeys_total_order();
+            axiom_obeys_total_order::<T>();
+        }
+
+        proof fn transitive(x: Self, y: Self, z: Self) {
+            T::lemma_obeys_total_order();
+            axiom_obeys_total_order::<T>();
+        }
+
+        proof fn antisymmetric(x: Self, y: Self) {
+            T::lemma_obeys_total_order();
+            axiom_obeys_total_order::<T>();
+            assert(y.cmp_spec(&x) != Ordering::Greater);
+        }
+
+        fn compare(&self, other: &Self) -> (c: Option<Ordering>) {
+            proof {
+                T::lemma_obeys_total_order();
+                axiom_obeys_total_order::<T>();
+            }
+            Some(Ord::cmp(self, other))
+        }
+    }
+
+    // Genuinely partial orders: incomparable values get None from compare.
+
+    /// Component-wise product order: (a, b) <= (c, d) iff a <= c and b <= d.
+    impl<A: PartialOrder, B: PartialOrder> PartialOrder for Pair<A, B> {
+        open spec fn le(self, other: Self) -> bool {
+            self.0.le(other.0) && self.1.le(other.1)
+        }
+
+        open spec fn spec_po_requires() -> bool { A::spec_po_requires() && B::spec_po_requires() }
+
+        proof fn reflexive(x: Self) {
+            A::reflexive(x.0);
+            B::reflexive(x.1);
+        }
+
+        proof fn transitive(x: Self, y: Self, z: Self) {
+            A::transitive(x.0, y.0, z.0);
+            B::transitive(x.1, y.1, z.1);
+        }
+
+        proof fn antisymmetric(x: Self, y: Self) {
+            A::antisymmetric(x.0, y.0);
+            B::antisymmetric(x.1, y.1);
+        }
+
+        fn compare(&self, other: &Self) -> (c: Option<Ordering>) {
+            proof {
+                A::reflexive(self.0);
+                B::reflexive(self.1);
+            }
+            let c0 = self.0.compare(&other.0);
+            let c1 = self.1.compare(&other.1);
+            match (c0, c1) {
+                (Some(Ordering::Equal), Some(Ordering::Equal)) => Some(Ordering::Equal),
+                (Some(Ordering::Less), Some(Ordering::Less)) => Some(Ordering::Less),
+                (Some(Ordering::Less), Some(Ordering::Equal)) => Some(Ordering::Less),
+                (Some(Ordering::Equal), Some(Ordering::Less)) => Some(Ordering::Less),
+                (Some(Ordering::Greater), Some(Ordering::Greater)) => Some(Ordering::Greater),
+                (Some(Ordering::Greater), Some(Ordering::Equal)) => Some(Ordering::Greater),
+                (Some(Ordering::Equal), Some(Ordering::Greater)) => Some(Ordering::Greater),
+                _ => None,
+            }
+        }
+    }
+
+    /// Subset order on sets.
+    impl<T: StT + Hash> PartialOrder for SetStEph<T> {
+        open spec fn le(self, other: Self) -> bool {
+            self@.subset_of(other@)
+        }
+
+        open spec fn spec_po_requires() -> bool { valid_key_type::<T>() }
+
+        proof fn reflexive(x: Self) {
+        }
+
+        proof fn transitive(x: Self, y: Self, z: Self) {
+        }
+
+        proof fn antisymmetric(x: Self, y: Self) {
+            assert(x@ =~= y@);
+            axiom_SetStEph_view_injective(x, y);
+        }
+
+        fn compare(&self, other: &Self) -> (c: Option<Ordering>) {
+            match (self.subset_of(other), other.subset_of(self)) {
+                (true, true) => {
+                    proof {
+                        assert(self@ =~= other@);
+                        axiom_SetStEph_view_injective(*self, *other);
+                    }
+                    Some(Ordering::Equal)
+                },
+                (true, false) => Some(Ordering::Less),
+                (false, true) => Some(Ordering::Greater),
+                (false, false) => None,
+            }
+        }
+    }
+
+    /// a divides b on naturals: 0 divides only 0, and everything divides 0.
+    pub open spec fn divides(a: int, b: int) -> bool {
+        if a == 0 { b == 0 } else { b % a == 0 }
+    }
+
+    pub proof fn lemma_divides_reflexive(a: int)
+        requires a >= 0,
+        ensures divides(a, a),
+    {
+        if a != 0 {
+            lemma_mod_self_0(a);
+        }
+    }
+
+    pub proof fn lemma_divides_transitive(a: int, b: int, c: int)
+        requires
+            a >= 0, b >= 0, c >= 0,
+            divides(a, b),
+            divides(b, c),
+        ensures divides(a, c),
+    {
+        if a != 0 && b != 0 {
+            let k = b / a;
+            let m = c / b;
+            lemma_fundamental_div_mod(b, a);
+            lemma_fundamental_div_mod(c, b);
+            assert(c == (k * m) * a) by (nonlinear_arith)
+                requires b == a * k, c == b * m;
+            lemma_mod_multiples_basic(k * m, a);
+        } else if a != 0 {
+            lemma_mod_multiples_basic(0, a);
+        }
+    }
+
+    pub proof fn lemma_divides_antisymmetric(a: int, b: int)
+        requires
+            a >= 0, b >= 0,
+            divides(a, b),
+            divides(b, a),
+        ensures a == b,
+    {
+        if a != 0 && b != 0 {
+            let k = b / a;
+            let m = a / b;
+            lemma_fundamental_div_mod(b, a);
+            lemma_fundamental_div_mod(a, b);
+            assert(k >= 1) by (nonlinear_arith)
+                requires b == a * k, a > 0, b > 0;
+            assert(m >= 1) by (nonlinear_arith)
+                requires a == b * m, a > 0, b > 0;
+            assert(b >= a) by (nonlinear_arith)
+                requires b == a * k, k >= 1, a > 0;
+            assert(a >= b) by (nonlinear_arith)
+                requires a == b * m, m >= 1, b > 0;
+        }
+    }
+
+    /// Unsigned integers ordered by divisibility, e.g. 2 and 3 are incomparable.
+    #[derive(Debug, Copy, PartialEq, Eq)]
+    pub struct Divides<T>(pub T);
+
+    impl<T: Copy> Clone for Divides<T> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl PartialOrder for Divides<u8> {
+        open spec fn le(self, other: Self) -> bool {
+            divides(self.0 as int, other.0 as int)
+        }
+
+        proof fn reflexive(x: Self) {
+            lemma_divides_reflexive(x.0 as int);
+        }
+
+        proof fn transitive(x: Self, y: Self, z: Self) {
+            lemma_divides_transitive(x.0 as int, y.0 as int, z.0 as int);
+        }
+
+        proof fn antisymmetric(x: Self, y: Self) {
+            lemma_divides_antisymmetric(x.0 as int, y.0 as int);
+        }
+
+        fn compare(&self, other: &Self) -> (c: Option<Ordering>) {
+            let a = self.0;
+            let b = other.0;
+            let a_divides_b = if a == 0 { b == 0 } else { b % a == 0 };
+            let b_divides_a = if b == 0 { a == 0 } else { a % b == 0 };
+            if a == b {
+                Some(Ordering::Equal)
+            } else if a_divides_b {
+                Some(Ordering::Less)
+            } else if b_divides_a {
+                Some(Ordering::Greater)
+            } else {
+                None
+            }
+        }
+    }
+
+    impl PartialOrder for Divides<u16> {
+        open spec fn le(self, other: Self) -> bool {
+            divides(self.0 as int, other.0 as int)
+        }
+
+        proof fn reflexive(x: Self) {
+            lemma_divides_reflexive(x.0 as int);
+        }
+
+        proof fn transitive(x: Self, y: Self, z: Self) {
+            lemma_divides_transitive(x.0 as int, y.0 as int, z.0 as int);
+        }
+
+        proof fn antisymmetric(x: Self, y: Self) {
+            lemma_divides_antisymmetric(x.0 as int, y.0 as int);
+        }
+
+        fn compare(&self, other: &Self) -> (c: Option<Ordering>) {
+            let a = self.0;
+            let b = other.0;
+            let a_divides_b = if a == 0 { b == 0 } else { b % a == 0 };
+            let b_divides_a = if b == 0 { a == 0 } else { a % b == 0 };
+            if a == b {
+                Some(Ordering::Equal)
+            } else if a_divides_b {
+                Some(Ordering::Less)
+            } else if b_divides_a {
+                Some(Ordering::Greater)
+            } else {
+                None
+            }
+        }
+    }
+
+    impl PartialOrder for Divides<u32> {
+        open spec fn le(self, other: Self) -> bool {
+            divides(self.0 as int, other.0 as int)
+        }
+
+        proof fn reflexive(x: Self) {
+            lemma_divides_reflexive(x.0 as int);
+        }
+
+        proof fn transitive(x: Self, y: Self, z: Self) {
+            lemma_divides_transitive(x.0 as int, y.0 as int, z.0 as int);
+        }
+
+        proof fn antisymmetric(x: Self, y: Self) {
+            lemma_divides_antisymmetric(x.0 as int, y.0 as int);
+        }
+
+        fn compare(&self, other: &Self) -> (c: Option<Ordering>) {
+            let a = self.0;
+            let b = other.0;
+            let a_divides_b = if a == 0 { b == 0 } else { b % a == 0 };
+            let b_divides_a = if b == 0 { a == 0 } else { a % b == 0 };
+            if a == b {
+                Some(Ordering::Equal)
+            } else if a_divides_b {
+                Some(Ordering::Less)
+            } else if b_divides_a {
+                Some(Ordering::Greater)
+            } else {
+                None
+            }
+        }
+    }
+
+    impl PartialOrder for Divides<u64> {
+        open spec fn le(self, other: Self) -> bool {
+            divides(self.0 as int, other.0 as int)
+        }
+
+        proof fn reflexive(x: Self) {
+            lemma_divides_reflexive(x.0 as int);
+        }
+
+        proof fn transitive(x: Self, y: Self, z: Self) {
+            lemma_divides_transitive(x.0 as int, y.0 as int, z.0 as int);
+        }
+
+        proof fn antisymmetric(x: Self, y: Self) {
+            lemma_divides_antisymmetric(x.0 as int, y.0 as int);
+        }
+
+        fn compare(&self, other: &Self) -> (c: Option<Ordering>) {
+            let a = self.0;
+            let b = other.0;
+            let a_divides_b = if a == 0 { b == 0 } else { b % a == 0 };
+            let b_divides_a = if b == 0 { a == 0 } else { a % b == 0 };
+            if a == b {
+                Some(Ordering::Equal)
+            } else if a_divides_b {
+                Some(Ordering::Less)
+            } else if b_divides_a {
+                Some(Ordering::Greater)
+            } else {
+                None
+            }
+        }
+    }
+
+    impl PartialOrder for Divides<u128> {
+        open spec fn le(self, other: Self) -> bool {
+            divides(self.0 as int, other.0 as int)
+        }
+
+        proof fn reflexive(x: Self) {
+            lemma_divides_reflexive(x.0 as int);
+        }
+
+        proof fn transitive(x: Self, y: Self, z: Self) {
+            lemma_divides_transitive(x.0 as int, y.0 as int, z.0 as int);
+        }
+
+        proof fn antisymmetric(x: Self, y: Self) {
+            lemma_divides_antisymmetric(x.0 as int, y.0 as int);
+        }
+
+        fn compare(&self, other: &Self) -> (c: Option<Ordering>) {
+            let a = self.0;
+            let b = other.0;
+            let a_divides_b = if a == 0 { b == 0 } else { b % a == 0 };
+            let b_divides_a = if b == 0 { a == 0 } else { a % b == 0 };
+            if a == b {
+                Some(Ordering::Equal)
+            } else if a_divides_b {
+                Some(Ordering::Less)
+            } else if b_divides_a {
+                Some(Ordering::Greater)
+            } else {
+                None
+            }
+        }
+    }
+
+    impl PartialOrder for Divides<usize> {
+        open spec fn le(self, other: Self) -> bool {
+            divides(self.0 as int, other.0 as int)
+        }
+
+        proof fn reflexive(x: Self) {
+            lemma_divides_reflexive(x.0 as int);
+        }
+
+        proof fn transitive(x: Self, y: Self, z: Self) {
+            lemma_divides_transitive(x.0 as int, y.0 as int, z.0 as int);
+        }
+
+        proof fn antisymmetric(x: Self, y: Self) {
+            lemma_divides_antisymmetric(x.0 as int, y.0 as int);
+        }
+
+        fn compare(&self, other: &Self) -> (c: Option<Ordering>) {
+            let a = self.0;
+            let b = other.0;
+            let a_divides_b = if a == 0 { b == 0 } else { b % a == 0 };
+            let b_divides_a = if b == 0 { a == 0 } else { a % b == 0 };
+            if a == b {
+                Some(Ordering::Equal)
+            } else if a_divides_b {
+                Some(Ordering::Less)
+            } else if b_divides_a {
+                Some(Ordering::Greater)
+            } else {
+                None
+            }
+        }
+    }
+
     } // verus!
 }
-
@@ -384,5 +384,57 @@ pub proof fn lemma_take_one_more_intersect<T: View>(seq: Seq<T>, s2: Set<T::V>,
     }
 }
 
-} // verus!
+/// After taking n elements and mapping through view, subtracting a set s2,
+/// extending to n+1 either adds seq[n]@ (if not in s2) or keeps the difference unchanged.
+pub proof fn lemma_take_one_more_difference<T: View>(seq: Seq<T>, s2: Set<T::V>, n: int)
+    requires
+        0 <= n < seq.len(),
+    ensures
+        seq.take(n+1).map(|i: int, k: T| k@).to_set().difference(s2) ==
+            if s2.contains(seq[n]@) {
+                seq.take(n).map(|i: int, k: T| k@).to_set().difference(s2)
+            } else {
+                seq.take(n).map(|i: int, k: T| k@).to_set().difference(s2).insert(seq[n]@)
+            },
+{
+    broadcast use vstd::seq_lib::group_seq_properties;
+    broadcast use vstd::set::group_set_axioms;
+
+    let set_n = seq.take(n).map(|i: int, k: T| k@).to_set();
+    let set_n_plus_1 = seq.take(n+1).map(|i: int, k: T| k@).to_set();
+
+    lemma_take_one_more_extends_the_seq_set_with_view(seq, n);
+    assert(set_n_plus_1 == set_n.insert(seq[n]@));
 
+    if s2.contains(seq[n]@) {
+        // (A ∪ {x}) \ B = A \ B when x ∈ B
+        assert(set_n_plus_1.difference(s2) =~= set_n.difference(s2));
+    } else {
+        // (A ∪ {x}) \ B = (A \ B) ∪ {x} when x ∉ B
+        assert(set_n_plus_1.difference(s2) =~= set_n.difference(s2).insert(seq[n]@));
+    }
+}
+
+/// In a sequence without duplicates whose view is injective, the view of seq[n]
+/// is not among the views of the first n elements.
+pub proof fn lemma_no_duplicates_view_not_in_take<T: View>(seq: Seq<T>, n: int)
+    requires
+        0 <= n < seq.len(),
+        seq.no_duplicates(),
+        forall |x: T, y: T| #[trigger] x@ == #[trigger] y@ ==> x == y,
+    ensures
+        !seq.take(n).map(|i: int, k: T| k@).to_set().contains(seq[n]@),
+{
+    broadcast use vstd::seq_lib::group_seq_properties;
+    broadcast use vstd::set::group_set_axioms;
+
+    let mapped = seq.take(n).map(|i: int, k: T| k@);
+    if mapped.to_set().contains(seq[n]@) {
+        let idx = mapped.lemma_contains_to_index(seq[n]@);
+        assert(seq.take(n)[idx] == seq[idx]);
+        assert(seq[idx]@ == seq[n]@);
+        assert(seq[idx] == seq[n]);
+    }
+}
+
+} // verus!
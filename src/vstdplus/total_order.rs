@@ -2,6 +2,9 @@
 pub mod total_order {
     use core::cmp::Ordering;
     use vstd::prelude::*;
+    use crate::Types::Types::{Pair, Triple};
+    #[cfg(verus_keep_ghost)]
+    use vstd::std_specs::cmp::OrdSpec;
 
     verus! {
 
@@ -44,8 +47,8 @@ pub mod total_order {
     ;
 }
 
-// Note: A blanket impl for all T: Ord would be nice, but Verus has limitations
-// with generic comparison operators in exec code. Individual impls work fine.
+// A blanket impl for all T: Ord would overlap the concrete impls below, so Ord types
+// opt in through OrdTotal (see the end of this file) and get TotalOrder from there.
 
 impl TotalOrder for u8 {
     open spec fn le(self, other: Self) -> bool {
@@ -383,5 +386,452 @@ impl TotalOrder for isize {
     }
 }
 
+impl TotalOrder for bool {
+    /// false < true
+    open spec fn le(self, other: Self) -> bool {
+        !self || other
+    }
+
+    proof fn reflexive(x: Self) {
+    }
+
+    proof fn transitive(x: Self, y: Self, z: Self) {
+    }
+
+    proof fn antisymmetric(x: Self, y: Self) {
+    }
+
+    proof fn total(x: Self, y: Self) {
+    }
+
+    fn cmp(&self, other: &Self) -> (c: Ordering) {
+        if !*self && *other {
+            Ordering::Less
+        } else if *self == *other {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+impl TotalOrder for char {
+    open spec fn le(self, other: Self) -> bool {
+        self <= other
+    }
+
+    proof fn reflexive(x: Self) {
+    }
+
+    proof fn transitive(x: Self, y: Self, z: Self) {
+    }
+
+    proof fn antisymmetric(x: Self, y: Self) {
+    }
+
+    proof fn total(x: Self, y: Self) {
+    }
+
+    fn cmp(&self, other: &Self) -> (c: Ordering) {
+        if self < other {
+            Ordering::Less
+        } else if self == other {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+/// Lexicographic order on character sequences; a proper prefix is smaller.
+pub open spec fn lex_le(a: Seq<char>, b: Seq<char>) -> bool
+    decreases a.len(),
+{
+    if a.len() == 0 {
+        true
+    } else if b.len() == 0 {
+        false
+    } else if a[0] != b[0] {
+        a[0] < b[0]
+    } else {
+        lex_le(a.drop_first(), b.drop_first())
+    }
+}
+
+pub proof fn lemma_lex_le_reflexive(a: Seq<char>)
+    ensures lex_le(a, a),
+    decreases a.len(),
+{
+    if a.len() > 0 {
+        lemma_lex_le_reflexive(a.drop_first());
+    }
+}
+
+pub proof fn lemma_lex_le_transitive(a: Seq<char>, b: Seq<char>, c: Seq<char>)
+    requires lex_le(a, b), lex_le(b, c),
+    ensures lex_le(a, c),
+    decreases a.len(),
+{
+    if a.len() > 0 && b.len() > 0 && c.len() > 0 && a[0] == b[0] && b[0] == c[0] {
+        lemma_lex_le_transitive(a.drop_first(), b.drop_first(), c.drop_first());
+    }
+}
+
+pub proof fn lemma_lex_le_antisymmetric(a: Seq<char>, b: Seq<char>)
+    requires lex_le(a, b), lex_le(b, a),
+    ensures a == b,
+    decreases a.len(),
+{
+    if a.len() > 0 && b.len() > 0 {
+        lemma_lex_le_antisymmetric(a.drop_first(), b.drop_first());
+        assert(a =~= seq![a[0]] + a.drop_first());
+        assert(b =~= seq![b[0]] + b.drop_first());
+    } else {
+        assert(a =~= b);
+    }
+}
+
+pub proof fn lemma_lex_le_total(a: Seq<char>, b: Seq<char>)
+    ensures lex_le(a, b) || lex_le(b, a),
+    decreases a.len(),
+{
+    if a.len() > 0 && b.len() > 0 && a[0] == b[0] {
+        lemma_lex_le_total(a.drop_first(), b.drop_first());
+    }
+}
+
+/// Strings with the same characters are the same string.
+pub broadcast proof fn axiom_String_view_injective(s1: String, s2: String)
+    requires #[trigger] s1@ == #[trigger] s2@,
+    ensures s1 == s2,
+{
+    admit();
+}
+
+impl TotalOrder for String {
+    open spec fn le(self, other: Self) -> bool {
+        lex_le(self@, other@)
+    }
+
+    proof fn reflexive(x: Self) {
+        lemma_lex_le_reflexive(x@);
+    }
+
+    proof fn transitive(x: Self, y: Self, z: Self) {
+        lemma_lex_le_transitive(x@, y@, z@);
+    }
+
+    proof fn antisymmetric(x: Self, y: Self) {
+        lemma_lex_le_antisymmetric(x@, y@);
+        axiom_String_view_injective(x, y);
+    }
+
+    proof fn total(x: Self, y: Self) {
+        lemma_lex_le_total(x@, y@);
+    }
+
+    /// UTF-8 byte order agrees with code point order, so std's byte comparison is lex_le.
+    #[verifier::external_body]
+    fn cmp(&self, other: &Self) -> (c: Ordering) {
+        Ord::cmp(self, other)
+    }
+}
+
+/// String slices with the same characters are the same slice value.
+pub broadcast proof fn axiom_str_view_injective<'a>(s1: &'a str, s2: &'a str)
+    requires #[trigger] s1@ == #[trigger] s2@,
+    ensures s1 == s2,
+{
+    admit();
+}
+
+impl<'a> TotalOrder for &'a str {
+    open spec fn le(self, other: Self) -> bool {
+        lex_le(self@, other@)
+    }
+
+    proof fn reflexive(x: Self) {
+        lemma_lex_le_reflexive(x@);
+    }
+
+    proof fn transitive(x: Self, y: Self, z: Self) {
+        lemma_lex_le_transitive(x@, y@, z@);
+    }
+
+    proof fn antisymmetric(x: Self, y: Self) {
+        lemma_lex_le_antisymmetric(x@, y@);
+        axiom_str_view_injective(x, y);
+    }
+
+    proof fn total(x: Self, y: Self) {
+        lemma_lex_le_total(x@, y@);
+    }
+
+    /// UTF-8 byte order agrees with code point order, so std's byte comparison is lex_le.
+    #[verifier::external_body]
+    fn cmp(&self, other: &Self) -> (c: Ordering) {
+        Ord::cmp(*self, *other)
+    }
+}
+
+/// Lexicographic: compare first components, then second.
+impl<A: TotalOrder, B: TotalOrder> TotalOrder for Pair<A, B> {
+    open spec fn le(self, other: Self) -> bool {
+        if self.0 != other.0 { self.0.le(other.0) } else { self.1.le(other.1) }
+    }
+
+    proof fn reflexive(x: Self) {
+        B::reflexive(x.1);
+    }
+
+    proof fn transitive(x: Self, y: Self, z: Self) {
+        if x.0 == y.0 && y.0 == z.0 {
+            B::transitive(x.1, y.1, z.1);
+        } else if x.0 != y.0 && y.0 != z.0 {
+            A::transitive(x.0, y.0, z.0);
+            if x.0 == z.0 {
+                A::antisymmetric(x.0, y.0);
+            }
+        }
+    }
+
+    proof fn antisymmetric(x: Self, y: Self) {
+        if x.0 == y.0 {
+            B::antisymmetric(x.1, y.1);
+        } else {
+            A::antisymmetric(x.0, y.0);
+        }
+    }
+
+    proof fn total(x: Self, y: Self) {
+        A::total(x.0, y.0);
+        B::total(x.1, y.1);
+    }
+
+    fn cmp(&self, other: &Self) -> (c: Ordering) {
+        match self.0.cmp(&other.0) {
+            Ordering::Equal => self.1.cmp(&other.1),
+            c => c,
+        }
+    }
+}
+
+/// Lexicographic: compare first, then second, then third components.
+impl<A: TotalOrder, B: TotalOrder, C: TotalOrder> TotalOrder for Triple<A, B, C> {
+    open spec fn le(self, other: Self) -> bool {
+        if self.0 != other.0 { self.0.le(other.0) }
+        else if self.1 != other.1 { self.1.le(other.1) }
+        else { self.2.le(other.2) }
+    }
+
+    proof fn reflexive(x: Self) {
+        C::reflexive(x.2);
+    }
+
+    proof fn transitive(x: Self, y: Self, z: Self) {
+        if x.0 != y.0 || y.0 != z.0 {
+            if x.0 != y.0 && y.0 != z.0 {
+                A::transitive(x.0, y.0, z.0);
+                if x.0 == z.0 {
+                    A::antisymmetric(x.0, y.0);
+                }
+            }
+        } else if x.1 != y.1 || y.1 != z.1 {
+            if x.1 != y.1 && y.1 != z.1 {
+                B::transitive(x.1, y.1, z.1);
+                if x.1 == z.1 {
+                    B::antisymmetric(x.1, y.1);
+                }
+            }
+        } else {
+            C::transitive(x.2, y.2, z.2);
+        }
+    }
+
+    proof fn antisymmetric(x: Self, y: Self) {
+        if x.0 != y.0 {
+            A::antisymmetric(x.0, y.0);
+        } else if x.1 != y.1 {
+            B::antisymmetric(x.1, y.1);
+        } else {
+            C::antisymmetric(x.2, y.2);
+        }
+    }
+
+    proof fn total(x: Self, y: Self) {
+        A::total(x.0, y.0);
+        B::total(x.1, y.1);
+        C::total(x.2, y.2);
+    }
+
+    fn cmp(&self, other: &Self) -> (c: Ordering) {
+        match self.0.cmp(&other.0) {
+            Ordering::Equal => match self.1.cmp(&other.1) {
+                Ordering::Equal => self.2.cmp(&other.2),
+                c => c,
+            },
+            c => c,
+        }
+    }
+}
+
+// Bridge from Ord. Extend Ord with obeys_total_order using the same
+// external_trait_extension pattern as vstdplus::feq; a type that claims it gets
+// the total order laws on vstd's cmp_spec, and OrdTotal turns those into TotalOrder.
+
+#[cfg(verus_keep_ghost)]
+#[verifier::external_trait_specification]
+#[verifier::external_trait_extension(TotalOrdSpec via TotalOrdSpecImpl)]
+pub trait ExTotalOrd: Eq + PartialOrd {
+    type ExternalTraitSpecificationFor: Ord;
+
+    // Whether this type's cmp is a total order consistent with ==
+    spec fn obeys_total_ord() -> bool;
+}
+
+/// Spec-level <= read off Ord's cmp_spec.
+pub open spec fn ord_le<T: Ord>(x: T, y: T) -> bool {
+    x.cmp_spec(&y) != Ordering::Greater
+}
+
+pub open spec fn total_order_laws<T: Ord>() -> bool {
+    &&& T::obeys_cmp_spec()
+    &&& forall|x: T| #[trigger] x.cmp_spec(&x) == Ordering::Equal
+    &&& forall|x: T, y: T| #[trigger] x.cmp_spec(&y) == Ordering::Equal ==> x == y
+    &&& forall|x: T, y: T| #![trigger x.cmp_spec(&y)]
+            (x.cmp_spec(&y) == Ordering::Less) == (y.cmp_spec(&x) == Ordering::Greater)
+    &&& forall|x: T, y: T, z: T| #[trigger] ord_le(x, y) && #[trigger] ord_le(y, z) ==> ord_le(x, z)
+}
+
+pub open spec fn obeys_total_order<T: Ord>() -> bool {
+    T::obeys_total_ord()
+}
+
+// A type's claim to obey the total order laws is trusted, as with obeys_feq.
+pub broadcast proof fn axiom_obeys_total_order<T: Ord>()
+    requires #[trigger] obeys_total_order::<T>()
+    ensures total_order_laws::<T>()
+{
+    admit();
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for bool {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for char {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for u8 {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for u16 {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for u32 {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for u64 {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for u128 {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for usize {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for i8 {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for i16 {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for i32 {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for i64 {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for i128 {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for isize {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+#[cfg(verus_keep_ghost)]
+impl TotalOrdSpecImpl for String {
+    open spec fn obeys_total_ord() -> bool { true }
+}
+
+/// Opt-in bridge: an Ord type that proves obeys_total_order gets TotalOrder and
+/// PartialOrder from its own cmp, with no hand-written impl.
+pub trait OrdTotal: Ord + Sized {
+    proof fn lemma_obeys_total_order()
+        ensures obeys_total_order::<Self>();
+}
+
+impl<T: OrdTotal> TotalOrder for T {
+    open spec fn le(self, other: Self) -> bool {
+        ord_le(self, other)
+    }
+
+    proof fn reflexive(x: Self) {
+        T::lemma_obeys_total_order();
+        axiom_obeys_total_order::<T>();
+    }
+
+    proof fn transitive(x: Self, y: Self, z: Self) {
+        T::lemma_obeys_total_order();
+        axiom_obeys_total_order::<T>();
+    }
+
+    proof fn antisymmetric(x: Self, y: Self) {
+        T::lemma_obeys_total_order();
+        axiom_obeys_total_order::<T>();
+        assert(y.cmp_spec(&x) != Ordering::Greater);
+    }
+
+    proof fn total(x: Self, y: Self) {
+        T::lemma_obeys_total_order();
+        axiom_obeys_total_order::<T>();
+        assert((x.cmp_spec(&y) == Ordering::Greater) ==> (y.cmp_spec(&x) == Ordering::Less));
+    }
+
+    fn cmp(&self, other: &Self) -> (c: Ordering) {
+        proof {
+            T::lemma_obeys_total_order();
+            axiom_obeys_total_order::<T>();
+        }
+        Ord::cmp(self, other)
+    }
+}
+
 } // verus!
 }
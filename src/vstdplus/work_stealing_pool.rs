@@ -0,0 +1,382 @@
+//! work_stealing_pool - Fixed-size work-stealing thread pool for Mt code
+//!
+//! Each worker owns a deque: it pushes and pops forked jobs at the back, and thieves take the
+//! older half from the front. join(a, b) forks b, runs a, and then helps with whatever work it
+//! can find (b itself first, if nobody stole it) until b is done, so a fixed number of workers
+//! never deadlocks on nested joins. par_for and par_reduce split an index range with join.
+//!
+//! This is scheduler plumbing outside verus!, like the Debug/Display impls; the verified
+//! fork-join primitive is vstdplus::para_pair.
+
+pub mod work_stealing_pool {
+    use std::cell::{Cell, UnsafeCell};
+    use std::collections::VecDeque;
+    use std::ops::Range;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Condvar, Mutex, MutexGuard, OnceLock};
+    use std::thread::{self, JoinHandle, Thread};
+
+    // A type-erased pointer to a job that lives on the stack of the thread waiting for it.
+    #[derive(Clone, Copy)]
+    struct JobRef {
+        data: *const (),
+        execute: unsafe fn(*const ()),
+    }
+
+    // The waiting thread keeps the job alive until it is done, so any thread may run it.
+    unsafe impl Send for JobRef {}
+
+    impl JobRef {
+        unsafe fn run(self) {
+            unsafe { (self.execute)(self.data) }
+        }
+    }
+
+    // Who waits for a job: a thread outside the pool, parked until the executor unparks it, or
+    // the worker that forked it, which sleeps on the pool's condvar when it has nothing to help with.
+    #[derive(Clone)]
+    enum Waiter {
+        Thread(Thread),
+        Worker(usize),
+    }
+
+    // A forked closure and its result slot. The frame that owns it does not return until
+    // done is set, and the executor touches nothing of the job after setting it.
+    struct StackJob<F, R> {
+        func: UnsafeCell<Option<F>>,
+        result: UnsafeCell<Option<thread::Result<R>>>,
+        waiter: Waiter,
+        done: AtomicBool,
+    }
+
+    impl<F: FnOnce() -> R, R> StackJob<F, R> {
+        fn new(func: F, waiter: Waiter) -> Self {
+            StackJob { func: UnsafeCell::new(Some(func)), result: UnsafeCell::new(None), waiter, done: AtomicBool::new(false) }
+        }
+
+        fn as_job_ref(&self) -> JobRef {
+            JobRef { data: self as *const Self as *const (), execute: Self::execute }
+        }
+
+        unsafe fn execute(data: *const ()) {
+            let job = unsafe { &*(data as *const Self) };
+            let func = unsafe { (*job.func.get()).take() }.expect("work_stealing_pool: job run twice");
+            let result = panic::catch_unwind(AssertUnwindSafe(func));
+            unsafe { *job.result.get() = Some(result) };
+            let waiter = job.waiter.clone();
+            job.done.store(true, Ordering::Release);
+            match waiter {
+                Waiter::Thread(thread) => thread.unpark(),
+                Waiter::Worker(owner) => {
+                    // Only a thief's worker can differ from the owner, and only then may the owner sleep.
+                    // Jobs of a pool run on its workers, so WORKER names the live Shared of this pool.
+                    let (pool, index) = WORKER.with(|w| w.get());
+                    if index != owner {
+                        unsafe { &*(pool as *const Shared) }.notify_all();
+                    }
+                }
+            }
+        }
+
+        fn is_done(&self) -> bool {
+            self.done.load(Ordering::Acquire)
+        }
+
+        fn into_result(self) -> thread::Result<R> {
+            self.result.into_inner().expect("work_stealing_pool: job never ran")
+        }
+    }
+
+    struct Shared {
+        deques: Vec<Mutex<VecDeque<JobRef>>>,
+        injector: Mutex<VecDeque<JobRef>>,
+        sleep: Mutex<()>,
+        wake: Condvar,
+        shutdown: AtomicBool,
+    }
+
+    fn lock<T>(m: &Mutex<T>) -> MutexGuard<'_, T> {
+        // Jobs run under catch_unwind, so a poisoned lock only means a panic between pushes.
+        m.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    impl Shared {
+        // Sleepers check for work and wait while holding sleep, so notifying under it cannot
+        // fall between a sleeper's check and its wait.
+        fn notify_one(&self) {
+            let _sleep = lock(&self.sleep);
+            self.wake.notify_one();
+        }
+
+        fn notify_all(&self) {
+            let _sleep = lock(&self.sleep);
+            self.wake.notify_all();
+        }
+
+        fn push(&self, worker: usize, job: JobRef) {
+            lock(&self.deques[worker]).push_back(job);
+            self.notify_one();
+        }
+
+        fn inject(&self, job: JobRef) {
+            lock(&self.injector).push_back(job);
+            self.notify_all();
+        }
+
+        /// Some deque or the injector holds a job. Takes no job, so it is safe under sleep.
+        fn has_work(&self) -> bool {
+            !lock(&self.injector).is_empty() || self.deques.iter().any(|deque| !lock(deque).is_empty())
+        }
+
+        /// Block on the condvar until notified, unless there is work or keep_awake holds.
+        fn sleep_unless(&self, keep_awake: impl Fn() -> bool) {
+            let sleep = lock(&self.sleep);
+            if keep_awake() || self.has_work() {
+                return;
+            }
+            drop(self.wake.wait(sleep).unwrap_or_else(|poisoned| poisoned.into_inner()));
+        }
+
+        /// Own newest job first, then injected work, then steal half of some other deque.
+        fn find_work(&self, worker: usize) -> Option<JobRef> {
+            if let Some(job) = lock(&self.deques[worker]).pop_back() {
+                return Some(job);
+            }
+            if let Some(job) = lock(&self.injector).pop_front() {
+                return Some(job);
+            }
+            self.steal_half(worker)
+        }
+
+        fn steal_half(&self, thief: usize) -> Option<JobRef> {
+            let n = self.deques.len();
+            for offset in 1..n {
+                let victim = (thief + offset) % n;
+                let mut stolen: Vec<JobRef> = {
+                    let mut deque = lock(&self.deques[victim]);
+                    let take = deque.len().div_ceil(2);
+                    deque.drain(..take).collect()
+                };
+                if stolen.is_empty() {
+                    continue;
+                }
+                // Run the newest stolen job now and keep the rest, oldest at the front.
+                let job = stolen.pop();
+                if !stolen.is_empty() {
+                    lock(&self.deques[thief]).extend(stolen);
+                    self.notify_one();
+                }
+                return job;
+            }
+            None
+        }
+    }
+
+    thread_local! {
+        // The pool (by its shared state) and index of the worker running on this thread.
+        static WORKER: Cell<(*const (), usize)> = const { Cell::new((std::ptr::null(), 0)) };
+    }
+
+    fn worker_main(shared: Arc<Shared>, index: usize) {
+        WORKER.with(|w| w.set((Arc::as_ptr(&shared) as *const (), index)));
+        loop {
+            if let Some(job) = shared.find_work(index) {
+                unsafe { job.run() };
+                continue;
+            }
+            if shared.shutdown.load(Ordering::Acquire) {
+                break;
+            }
+            shared.sleep_unless(|| shared.shutdown.load(Ordering::Acquire));
+        }
+    }
+
+    fn resume<R>(result: thread::Result<R>) -> R {
+        match result {
+            Ok(r) => r,
+            Err(panic) => panic::resume_unwind(panic),
+        }
+    }
+
+    /// A fixed set of worker threads with per-worker deques.
+    pub struct Pool {
+        shared: Arc<Shared>,
+        workers: Vec<JoinHandle<()>>,
+    }
+
+    impl Pool {
+        /// A pool of num_workers threads (at least one).
+        pub fn new(num_workers: usize) -> Pool {
+            let num_workers = num_workers.max(1);
+            let shared = Arc::new(Shared {
+                deques: (0..num_workers).map(|_| Mutex::new(VecDeque::new())).collect(),
+                injector: Mutex::new(VecDeque::new()),
+                sleep: Mutex::new(()),
+                wake: Condvar::new(),
+                shutdown: AtomicBool::new(false),
+            });
+            let workers = (0..num_workers)
+                .map(|index| {
+                    let shared = Arc::clone(&shared);
+                    thread::Builder::new()
+                        .name(format!("work-stealing-{index}"))
+                        .spawn(move || worker_main(shared, index))
+                        .expect("work_stealing_pool: failed to spawn worker")
+                })
+                .collect();
+            Pool { shared, workers }
+        }
+
+        /// The process-wide pool, one worker per available core.
+        pub fn global() -> &'static Pool {
+            static GLOBAL: OnceLock<Pool> = OnceLock::new();
+            GLOBAL.get_or_init(|| Pool::new(thread::available_parallelism().map(|n| n.get()).unwrap_or(1)))
+        }
+
+        pub fn num_workers(&self) -> usize {
+            self.workers.len()
+        }
+
+        fn current_worker(&self) -> Option<usize> {
+            let (pool, index) = WORKER.with(|w| w.get());
+            if pool == Arc::as_ptr(&self.shared) as *const () { Some(index) } else { None }
+        }
+
+        /// Run f on a worker of this pool and wait for it; runs inline when already on one.
+        pub fn install<R: Send, F: FnOnce() -> R + Send>(&self, f: F) -> R {
+            if self.current_worker().is_some() {
+                return f();
+            }
+            let job = StackJob::new(f, Waiter::Thread(thread::current()));
+            self.shared.inject(job.as_job_ref());
+            // The executor unparks this thread after setting done; park may also return spuriously.
+            while !job.is_done() {
+                thread::park();
+            }
+            resume(job.into_result())
+        }
+
+        /// APAS: Work W(a) + W(b), Span max(S(a), S(b)).
+        /// Fork b, run a, then help until b is done; panics in either are re-raised after both finish.
+        pub fn join<A, B, RA, RB>(&self, a: A, b: B) -> (RA, RB)
+        where
+            A: FnOnce() -> RA + Send,
+            B: FnOnce() -> RB + Send,
+            RA: Send,
+            RB: Send,
+        {
+            let Some(worker) = self.current_worker() else {
+                return self.install(|| self.join(a, b));
+            };
+            let job_b = StackJob::new(b, Waiter::Worker(worker));
+            self.shared.push(worker, job_b.as_job_ref());
+            let ra = panic::catch_unwind(AssertUnwindSafe(a));
+            while !job_b.is_done() {
+                match self.shared.find_work(worker) {
+                    Some(job) => unsafe { job.run() },
+                    // A thief is running b; sleep until it finishes b or new work appears.
+                    None => self.shared.sleep_unless(|| job_b.is_done()),
+                }
+            }
+            let rb = job_b.into_result();
+            (resume(ra), resume(rb))
+        }
+
+        fn grain(&self, len: usize) -> usize {
+            (len / (4 * self.num_workers())).max(1)
+        }
+
+        /// Call f(i) for every i in range, in parallel. A reversed range is empty, as in std.
+        pub fn par_for<F: Fn(usize) + Sync>(&self, range: Range<usize>, f: F) {
+            if range.is_empty() {
+                return;
+            }
+            let grain = self.grain(range.len());
+            self.install(|| self.for_range(range.start, range.end, grain, &f));
+        }
+
+        fn for_range<F: Fn(usize) + Sync>(&self, lo: usize, hi: usize, grain: usize, f: &F) {
+            if hi - lo <= grain {
+                for i in lo..hi {
+                    f(i);
+                }
+                return;
+            }
+            let mid = lo + (hi - lo) / 2;
+            self.join(|| self.for_range(lo, mid, grain, f), || self.for_range(mid, hi, grain, f));
+        }
+
+        /// Reduce map(i) over range with an associative reduce whose identity is id.
+        /// A reversed range is empty, as in std, and reduces to id.
+        pub fn par_reduce<V, M, R>(&self, range: Range<usize>, id: V, map: M, reduce: R) -> V
+        where
+            V: Clone + Send + Sync,
+            M: Fn(usize) -> V + Sync,
+            R: Fn(&V, &V) -> V + Sync,
+        {
+            if range.is_empty() {
+                return id;
+            }
+            let grain = self.grain(range.len());
+            self.install(|| self.reduce_range(range.start, range.end, grain, &id, &map, &reduce))
+        }
+
+        fn reduce_range<V, M, R>(&self, lo: usize, hi: usize, grain: usize, id: &V, map: &M, reduce: &R) -> V
+        where
+            V: Clone + Send + Sync,
+            M: Fn(usize) -> V + Sync,
+            R: Fn(&V, &V) -> V + Sync,
+        {
+            if hi - lo <= grain {
+                let mut acc = id.clone();
+                for i in lo..hi {
+                    acc = reduce(&acc, &map(i));
+                }
+                return acc;
+            }
+            let mid = lo + (hi - lo) / 2;
+            let (left, right) = self.join(
+                || self.reduce_range(lo, mid, grain, id, map, reduce),
+                || self.reduce_range(mid, hi, grain, id, map, reduce),
+            );
+            reduce(&left, &right)
+        }
+    }
+
+    impl Drop for Pool {
+        fn drop(&mut self) {
+            self.shared.shutdown.store(true, Ordering::Release);
+            self.shared.notify_all();
+            for worker in self.workers.drain(..) {
+                let _ = worker.join();
+            }
+        }
+    }
+
+    /// join on the global pool.
+    pub fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+    where
+        A: FnOnce() -> RA + Send,
+        B: FnOnce() -> RB + Send,
+        RA: Send,
+        RB: Send,
+    {
+        Pool::global().join(a, b)
+    }
+
+    /// par_for on the global pool.
+    pub fn par_for<F: Fn(usize) + Sync>(range: Range<usize>, f: F) {
+        Pool::global().par_for(range, f)
+    }
+
+    /// par_reduce on the global pool.
+    pub fn par_reduce<V, M, R>(range: Range<usize>, id: V, map: M, reduce: R) -> V
+    where
+        V: Clone + Send + Sync,
+        M: Fn(usize) -> V + Sync,
+        R: Fn(&V, &V) -> V + Sync,
+    {
+        Pool::global().par_reduce(range, id, map, reduce)
+    }
+}
@@ -49,3 +49,20 @@ fn insertion_sort_large_input_stress_test() {
     }
 }
 
+#[test]
+fn insertion_sort_strings() {
+    let mut data = vec!["pear".to_string(), "apple".to_string(), "fig".to_string(), "apple".to_string()];
+    String::insSort(&mut data);
+    assert_eq!(data, vec!["apple", "apple", "fig", "pear"]);
+}
+
+#[test]
+fn insertion_sort_chars_and_bools() {
+    let mut chars = vec!['d', 'a', 'c', 'b'];
+    char::insSort(&mut chars);
+    assert_eq!(chars, vec!['a', 'b', 'c', 'd']);
+
+    let mut bools = vec![true, false, true, false];
+    bool::insSort(&mut bools);
+    assert_eq!(bools, vec![false, false, true, true]);
+}
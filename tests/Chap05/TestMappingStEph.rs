@@ -4,8 +4,9 @@
 use verus_test::Chap05::MappingStEph::MappingStEph::*;
 use verus_test::Chap05::RelationStEph::RelationStEph::*;
 use verus_test::Chap05::SetStEph::SetStEph::*;
-use verus_test::{MappingLit, PairLit, SetLit};
+use verus_test::{MappingLit, PairLit, RelationLit, SetLit};
 use verus_test::Types::Types::*;
+use vstd::prelude::Ghost;
 
 #[test]
 fn test_mappinglit_macro_functionality() {
@@ -54,18 +55,87 @@ fn test_from_vec_duplicate_keys() {
     let _m = MappingLit![(1, "first"), (2, "two"), (1, "second")];
 }
 
+#[test]
+#[should_panic(expected = "MappingLit!: duplicate domain element 1 maps to both")]
+fn test_mappinglit_reports_conflicting_values() {
+    let _m = MappingLit![(1, "first"), (1, "second")];
+}
+
+#[test]
+fn test_mappinglit_repeated_pair_is_functional() {
+    let m = MappingLit![(1, "one"), (2, "two"), (1, "one")];
+    assert_eq!(m.size(), 2);
+    assert!(m.mem(&Pair(1, "one")));
+}
+
+#[test]
+fn test_try_from_vec() {
+    let ok = <MappingStEph<N, &str> as MappingStEphTrait<N, &str>>::try_from_vec(
+        vec![Pair(1, "one"), Pair(2, "two")]).unwrap();
+    assert_eq!(ok.size(), 2);
+    assert!(ok.mem(&Pair(2, "two")));
+
+    let err = <MappingStEph<N, &str> as MappingStEphTrait<N, &str>>::try_from_vec(
+        vec![Pair(1, "one"), Pair(2, "two"), Pair(1, "uno")]).unwrap_err();
+    assert_eq!(err.key, 1);
+    assert_ne!(err.first, err.second);
+    assert!(err.first == "one" || err.first == "uno");
+    assert!(err.second == "one" || err.second == "uno");
+}
+
+#[test]
+fn test_try_from_relation() {
+    let functional = RelationLit![(1, "one"), (2, "two")];
+    let m = <MappingStEph<N, &str> as MappingStEphTrait<N, &str>>::try_from_relation(&functional).unwrap();
+    assert_eq!(m.size(), 2);
+    assert!(m.mem(&Pair(1, "one")));
+
+    let not_functional = RelationLit![(1, "one"), (2, "two"), (2, "dos")];
+    let err = <MappingStEph<N, &str> as MappingStEphTrait<N, &str>>::try_from_relation(&not_functional).unwrap_err();
+    assert_eq!(err.key, 2);
+    assert_ne!(err.first, err.second);
+    assert!(!format!("{}", err).is_empty());
+}
+
 #[test]
 fn test_from_relation() {
-    // Note: FromRelation wraps the relation as-is; it doesn't collapse duplicate keys
-    let pairs_set = SetLit![PairLit!(1, "one"), PairLit!(2, "two"), PairLit!(1, "uno")];
+    let pairs_set = SetLit![PairLit!(1, "one"), PairLit!(2, "two"), PairLit!(3, "three")];
     let rel = <RelationStEph<N, &str> as RelationStEphTrait<N, &str>>::FromSet(pairs_set);
     let m = <MappingStEph<N, &str> as MappingStEphTrait<N, &str>>::FromRelation(&rel);
 
-    // The underlying relation has 3 pairs (HashSet treats (1,"one") and (1,"uno") as distinct)
     assert_eq!(m.size(), 3);
     assert!(m.mem(&Pair(1, "one")));
-    assert!(m.mem(&Pair(1, "uno")));
     assert!(m.mem(&Pair(2, "two")));
+    assert!(m.mem(&Pair(3, "three")));
+    assert!(!m.mem(&Pair(1, "two")));
+}
+
+#[test]
+fn test_is_functional_relation() {
+    let functional = RelationLit![(1, "one"), (2, "two"), (3, "one")];
+    assert!(<MappingStEph<N, &str> as MappingStEphTrait<N, &str>>::is_functional_RelationStEph(&functional));
+
+    let not_functional = RelationLit![(1, "one"), (2, "two"), (1, "uno")];
+    assert!(!<MappingStEph<N, &str> as MappingStEphTrait<N, &str>>::is_functional_RelationStEph(&not_functional));
+
+    let empty: RelationStEph<N, &str> = RelationLit![];
+    assert!(<MappingStEph<N, &str> as MappingStEphTrait<N, &str>>::is_functional_RelationStEph(&empty));
+}
+
+#[test]
+fn test_mapping_hash_ignores_insertion_order() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let m1 = MappingLit![(1, "one"), (2, "two"), (3, "three")];
+    let m2 = MappingLit![(3, "three"), (1, "one"), (2, "two")];
+    assert_eq!(m1, m2);
+
+    let mut h1 = DefaultHasher::new();
+    m1.hash(&mut h1);
+    let mut h2 = DefaultHasher::new();
+    m2.hash(&mut h2);
+    assert_eq!(h1.finish(), h2.finish());
 }
 
 #[test]
@@ -237,3 +307,155 @@ fn test_mapping_clone() {
     assert!(m2.mem(&Pair(1, "one")));
     assert!(m2.mem(&Pair(2, "two")));
 }
+
+#[test]
+fn test_mapping_is_injective() {
+    let injective = MappingLit![(1, "one"), (2, "two"), (3, "three")];
+    assert!(injective.is_injective());
+
+    let collapsing = MappingLit![(1, "odd"), (2, "even"), (3, "odd")];
+    assert!(!collapsing.is_injective());
+
+    let empty: MappingStEph<i32, i32> = MappingLit![];
+    assert!(empty.is_injective());
+}
+
+#[test]
+fn test_mapping_is_surjective_onto() {
+    let m = MappingLit![(1, 'a'), (2, 'b'), (3, 'a')];
+    assert!(m.is_surjective_onto(&SetLit!['a', 'b']));
+    assert!(m.is_surjective_onto(&SetLit!['a']));
+    assert!(!m.is_surjective_onto(&SetLit!['a', 'b', 'c']));
+    assert!(m.is_surjective_onto(&SetLit![]));
+}
+
+#[test]
+fn test_mapping_is_bijection_onto() {
+    let m = MappingLit![(1, 'a'), (2, 'b')];
+    assert!(m.is_bijection_onto(&SetLit!['a', 'b']));
+    assert!(!m.is_bijection_onto(&SetLit!['a']));
+    assert!(!m.is_bijection_onto(&SetLit!['a', 'b', 'c']));
+
+    let collapsing = MappingLit![(1, 'a'), (2, 'a')];
+    assert!(!collapsing.is_bijection_onto(&SetLit!['a']));
+}
+
+#[test]
+fn test_mapping_inverse() {
+    let m = MappingLit![(1, "one"), (2, "two"), (3, "three")];
+    let inv = m.inverse().expect("injective mapping has an inverse");
+    assert_eq!(inv.size(), 3);
+    assert!(inv.mem(&Pair("one", 1)));
+    assert!(inv.mem(&Pair("two", 2)));
+    assert!(inv.mem(&Pair("three", 3)));
+    assert_eq!(inv.domain(), m.range());
+    assert_eq!(inv.range(), m.domain());
+    assert_eq!(inv.inverse(), Some(m));
+
+    let collapsing = MappingLit![(1, "odd"), (2, "even"), (3, "odd")];
+    assert_eq!(collapsing.inverse(), None);
+}
+
+#[test]
+fn test_mapping_compose() {
+    let f = MappingLit![(1, 'a'), (2, 'b'), (3, 'z')];
+    let g = MappingLit![('a', "alpha"), ('b', "beta"), ('c', "gamma")];
+    let gf = f.compose(&g);
+
+    // 3 maps to 'z', which is outside g's domain.
+    assert_eq!(gf.size(), 2);
+    assert!(gf.mem(&Pair(1, "alpha")));
+    assert!(gf.mem(&Pair(2, "beta")));
+    assert_eq!(gf, MappingLit![(1, "alpha"), (2, "beta")]);
+
+    let empty: MappingStEph<char, &str> = MappingLit![];
+    assert_eq!(f.compose(&empty).size(), 0);
+}
+
+#[test]
+fn test_mapping_compose_with_inverse_is_identity() {
+    let f = MappingLit![(1, "one"), (2, "two")];
+    let inv = f.inverse().expect("injective");
+    assert_eq!(f.compose(&inv), MappingLit![(1, 1), (2, 2)]);
+}
+
+#[test]
+fn test_mapping_tabulate() {
+    let squares = MappingStEph::tabulate(&SetLit![1, 2, 3], &|x: &i32| x * x, Ghost::assume_new());
+    assert_eq!(squares, MappingLit![(1, 1), (2, 4), (3, 9)]);
+
+    let empty: SetStEph<i32> = SetLit![];
+    let none = MappingStEph::tabulate(&empty, &|x: &i32| x + 1, Ghost::assume_new());
+    assert_eq!(none.size(), 0);
+}
+
+#[test]
+fn test_mapping_union_with() {
+    let a = MappingLit![("apples", 3), ("pears", 1)];
+    let b = MappingLit![("pears", 4), ("plums", 2)];
+    let merged = a.union_with(&b, &|x: &i32, y: &i32| x + y, Ghost::assume_new());
+    assert_eq!(merged, MappingLit![("apples", 3), ("pears", 5), ("plums", 2)]);
+
+    // Overlay: the right-hand value wins on shared keys.
+    let overlay = a.union_with(&b, &|_x: &i32, y: &i32| *y, Ghost::assume_new());
+    assert_eq!(overlay, MappingLit![("apples", 3), ("pears", 4), ("plums", 2)]);
+
+    let empty: MappingStEph<&str, i32> = MappingLit![];
+    assert_eq!(a.union_with(&empty, &|x: &i32, y: &i32| x + y, Ghost::assume_new()), a);
+    assert_eq!(empty.union_with(&a, &|x: &i32, y: &i32| x + y, Ghost::assume_new()), a);
+}
+
+#[test]
+fn test_mapping_intersect_with() {
+    let a = MappingLit![(1, 10), (2, 20), (3, 30)];
+    let b = MappingLit![(2, 2), (3, 3), (4, 4)];
+    let both = a.intersect_with(&b, &|x: &i32, y: &i32| x * y, Ghost::assume_new());
+    assert_eq!(both, MappingLit![(2, 40), (3, 90)]);
+
+    let disjoint = MappingLit![(7, 7)];
+    assert_eq!(a.intersect_with(&disjoint, &|x: &i32, _y: &i32| *x, Ghost::assume_new()).size(), 0);
+}
+
+#[test]
+fn test_mapping_difference() {
+    let a = MappingLit![(1, 'a'), (2, 'b'), (3, 'c')];
+    // Only keys matter; the value 'z' at 2 still removes 2.
+    let b = MappingLit![(2, 'z'), (4, 'd')];
+    assert_eq!(a.difference(&b), MappingLit![(1, 'a'), (3, 'c')]);
+    assert_eq!(a.difference(&a).size(), 0);
+
+    let empty: MappingStEph<i32, char> = MappingLit![];
+    assert_eq!(a.difference(&empty), a);
+}
+
+#[test]
+fn test_mapping_filter() {
+    let m = MappingLit![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')];
+    let even_keys = m.filter(&|x: &i32, _y: &char| x % 2 == 0, Ghost::assume_new());
+    assert_eq!(even_keys, MappingLit![(2, 'b'), (4, 'd')]);
+
+    let vowels = m.filter(&|_x: &i32, y: &char| "aeiou".contains(*y), Ghost::assume_new());
+    assert_eq!(vowels, MappingLit![(1, 'a')]);
+
+    assert_eq!(m.filter(&|_x: &i32, _y: &char| true, Ghost::assume_new()), m);
+    assert_eq!(m.filter(&|_x: &i32, _y: &char| false, Ghost::assume_new()).size(), 0);
+}
+
+#[test]
+fn test_mapping_map_values() {
+    let m = MappingLit![(1, 2), (2, 2), (3, 5)];
+    let doubled = m.map_values(&|y: &i32| y * 2, Ghost::assume_new());
+    assert_eq!(doubled, MappingLit![(1, 4), (2, 4), (3, 10)]);
+    assert_eq!(doubled.domain(), m.domain());
+
+    let labels = m.map_values(&|y: &i32| if *y > 2 { "big" } else { "small" }, Ghost::assume_new());
+    assert_eq!(labels, MappingLit![(1, "small"), (2, "small"), (3, "big")]);
+}
+
+#[test]
+fn test_mapping_restrict() {
+    let m = MappingLit![(1, 'a'), (2, 'b'), (3, 'c')];
+    assert_eq!(m.restrict(&SetLit![1, 3, 5]), MappingLit![(1, 'a'), (3, 'c')]);
+    assert_eq!(m.restrict(&SetLit![]).size(), 0);
+    assert_eq!(m.restrict(&m.domain()), m);
+}
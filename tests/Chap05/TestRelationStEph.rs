@@ -2,9 +2,11 @@
 
 use verus_test::Chap05::RelationStEph::RelationStEph::*;
 use verus_test::Chap05::SetStEph::SetStEph::*;
+use verus_test::Chap05::MappingStEph::MappingStEph::*;
 use verus_test::{RelationLit, SetLit};
 use verus_test::Types::Types::*;
 use verus_test::*;
+use vstd::prelude::Ghost;
 
 #[test]
 fn test_relationlit_macro_functionality() {
@@ -327,3 +329,197 @@ fn test_relation_clone() {
     assert_eq!(rel1, rel2);
     assert_eq!(rel1.size(), rel2.size());
 }
+
+#[test]
+fn test_relation_select() {
+    let rel = RelationLit![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')];
+    let even = rel.select(&|p: &Pair<i32, char>| p.0 % 2 == 0, Ghost::assume_new());
+
+    assert_eq!(even.size(), 2);
+    assert!(even.mem(&2, &'b'));
+    assert!(even.mem(&4, &'d'));
+    assert!(!even.mem(&1, &'a'));
+
+    let none = rel.select(&|_p: &Pair<i32, char>| false, Ghost::assume_new());
+    assert_eq!(none.size(), 0);
+}
+
+#[test]
+fn test_relation_restrict_domain_and_range() {
+    let rel = RelationLit![(1, 'a'), (1, 'b'), (2, 'b'), (3, 'c')];
+
+    let by_domain = rel.restrict_domain(&SetLit![1, 3, 99]);
+    assert_eq!(by_domain, RelationLit![(1, 'a'), (1, 'b'), (3, 'c')]);
+
+    let by_range = rel.restrict_range(&SetLit!['b']);
+    assert_eq!(by_range, RelationLit![(1, 'b'), (2, 'b')]);
+
+    let empty_keep: SetStEph<i32> = SetLit![];
+    assert_eq!(rel.restrict_domain(&empty_keep).size(), 0);
+}
+
+#[test]
+fn test_relation_projections() {
+    let rel = RelationLit![(1, 'a'), (1, 'b'), (2, 'b')];
+    assert_eq!(rel.project_domain(), SetLit![1, 2]);
+    assert_eq!(rel.project_range(), SetLit!['a', 'b']);
+    assert_eq!(rel.project_domain(), rel.domain());
+}
+
+#[test]
+fn test_relation_join() {
+    // employee -> department, department -> floor
+    let works_in = RelationLit![("ann", 10), ("bob", 10), ("cat", 20), ("dan", 30)];
+    let located_on = RelationLit![(10, 'A'), (10, 'B'), (20, 'C'), (40, 'D')];
+
+    let joined = works_in.join(&located_on);
+    assert_eq!(joined.size(), 5);
+    assert!(joined.mem(&"ann", &Pair(10, 'A')));
+    assert!(joined.mem(&"ann", &Pair(10, 'B')));
+    assert!(joined.mem(&"bob", &Pair(10, 'A')));
+    assert!(joined.mem(&"bob", &Pair(10, 'B')));
+    assert!(joined.mem(&"cat", &Pair(20, 'C')));
+    // dan's department 30 has no floor; floor D's department 40 has no employees.
+    assert!(!joined.domain().mem(&"dan"));
+
+    let nobody: RelationStEph<&str, i32> = RelationLit![];
+    assert_eq!(nobody.join(&located_on).size(), 0);
+}
+
+#[test]
+fn test_relation_insert_and_delete() {
+    let mut rel: RelationStEph<i32, char> = RelationLit![];
+    assert!(rel.insert(1, 'a'));
+    assert!(rel.insert(1, 'b'));
+    assert!(!rel.insert(1, 'a')); // duplicate pair
+    assert_eq!(rel.size(), 2);
+    assert!(rel.mem(&1, &'a'));
+    assert!(rel.mem(&1, &'b'));
+
+    assert!(rel.delete(&1, &'a'));
+    assert!(!rel.delete(&1, &'a'));
+    assert!(!rel.delete(&2, &'b'));
+    assert_eq!(rel.size(), 1);
+    assert!(!rel.mem(&1, &'a'));
+    assert!(rel.mem(&1, &'b'));
+}
+
+#[test]
+fn test_relation_union_intersection_difference() {
+    let r = RelationLit![(1, 'a'), (2, 'b'), (3, 'c')];
+    let s = RelationLit![(2, 'b'), (3, 'x'), (4, 'd')];
+
+    let u = r.union(&s);
+    assert_eq!(u, RelationLit![(1, 'a'), (2, 'b'), (3, 'c'), (3, 'x'), (4, 'd')]);
+
+    let i = r.intersection(&s);
+    assert_eq!(i, RelationLit![(2, 'b')]);
+
+    let d = r.difference(&s);
+    assert_eq!(d, RelationLit![(1, 'a'), (3, 'c')]);
+
+    let empty: RelationStEph<i32, char> = RelationLit![];
+    assert_eq!(r.union(&empty), r);
+    assert_eq!(r.intersection(&empty).size(), 0);
+    assert_eq!(r.difference(&empty), r);
+    assert_eq!(empty.difference(&r).size(), 0);
+}
+
+#[test]
+fn test_relation_group_by_domain() {
+    let r = RelationLit![(1, 'a'), (1, 'b'), (2, 'c'), (3, 'a')];
+    let g = r.group_by_domain();
+    assert_eq!(g.size(), 3);
+    assert!(g.mem(&Pair(1, SetLit!['a', 'b'])));
+    assert!(g.mem(&Pair(2, SetLit!['c'])));
+    assert!(g.mem(&Pair(3, SetLit!['a'])));
+    assert!(!g.mem(&Pair(1, SetLit!['a'])));
+
+    let empty: RelationStEph<i32, char> = RelationLit![];
+    assert_eq!(empty.group_by_domain().size(), 0);
+}
+
+#[test]
+fn test_relation_flatten_round_trip() {
+    let r = RelationLit![(1, 'a'), (1, 'b'), (2, 'c'), (3, 'a')];
+    let g = r.group_by_domain();
+    let back = RelationStEph::flatten(&g);
+    assert_eq!(back, r);
+}
+
+#[test]
+fn test_relation_group_reduce() {
+    let r = RelationLit![(1, 10), (1, 20), (1, 30), (2, 5), (3, 7), (3, 8)];
+    let sum = |a: &i32, b: &i32| -> i32 { *a + *b };
+    let totals = r.group_reduce(&sum, Ghost::assume_new());
+    assert_eq!(totals.size(), 3);
+    assert!(totals.mem(&Pair(1, 60)));
+    assert!(totals.mem(&Pair(2, 5)));
+    assert!(totals.mem(&Pair(3, 15)));
+
+    let max = |a: &i32, b: &i32| -> i32 { if *a > *b { *a } else { *b } };
+    let maxes = r.group_reduce(&max, Ghost::assume_new());
+    assert!(maxes.mem(&Pair(1, 30)));
+    assert!(maxes.mem(&Pair(3, 8)));
+}
+
+#[test]
+fn test_relation_linear_extension() {
+    // socks < shoes, pants < shoes, pants < belt, shirt < belt, shirt < tie
+    let r = RelationLit![(1, 3), (2, 3), (2, 4), (5, 4), (5, 6)];
+    let universe = SetLit![1, 2, 3, 4, 5, 6, 7];
+    let order = r.linear_extension(&universe).unwrap();
+    assert_eq!(order.len(), 7);
+    let pos = |x: i32| order.iter().position(|y| *y == x).unwrap();
+    for p in r.iter() {
+        assert!(pos(p.0) < pos(p.1));
+    }
+    for x in universe.iter() {
+        assert!(order.contains(x));
+    }
+
+    let empty: RelationStEph<i32, i32> = RelationLit![];
+    assert_eq!(empty.linear_extension(&SetLit![]).unwrap().len(), 0);
+    assert_eq!(empty.linear_extension(&SetLit![1, 2]).unwrap().len(), 2);
+}
+
+#[test]
+fn test_relation_linear_extension_cycle() {
+    let r = RelationLit![(1, 2), (2, 3), (3, 1), (3, 4)];
+    let cycle = r.linear_extension(&SetLit![1, 2, 3, 4]).unwrap_err();
+    let vs = &cycle.vertices;
+    assert_eq!(vs.len(), 3);
+    for i in 0..vs.len() {
+        assert!(r.mem(&vs[i], &vs[(i + 1) % vs.len()]));
+    }
+
+    let self_loop = RelationLit![(1, 2), (2, 2)];
+    let cycle = self_loop.linear_extension(&SetLit![1, 2]).unwrap_err();
+    assert_eq!(cycle.vertices, vec![2]);
+}
+
+#[test]
+fn test_relation_transitive_reduction() {
+    // The strict order on {1, 2, 3, 4} reduces to the chain 1 < 2 < 3 < 4.
+    let lt = RelationLit![(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)];
+    assert_eq!(lt.transitive_reduction(), RelationLit![(1, 2), (2, 3), (3, 4)]);
+
+    // Divisibility on {1, 2, 3, 4, 6, 12}.
+    let divides = RelationLit![
+        (1, 2), (1, 3), (1, 4), (1, 6), (1, 12),
+        (2, 4), (2, 6), (2, 12), (3, 6), (3, 12), (4, 12), (6, 12)
+    ];
+    assert_eq!(
+        divides.transitive_reduction(),
+        RelationLit![(1, 2), (1, 3), (2, 4), (2, 6), (3, 6), (4, 12), (6, 12)]
+    );
+}
+
+#[test]
+fn test_relation_transitive_reduction_already_reduced() {
+    let diamond = RelationLit![(1, 2), (1, 3), (2, 4), (3, 4)];
+    assert_eq!(diamond.transitive_reduction(), diamond);
+
+    let empty: RelationStEph<i32, i32> = RelationLit![];
+    assert_eq!(empty.transitive_reduction().size(), 0);
+}
@@ -162,6 +162,50 @@ fn test_set_intersection() {
     assert!(!intersect_set.mem(&5));
 }
 
+#[test]
+fn test_set_difference() {
+    let set1 = SetLit![1, 2, 3, 4];
+    let set2 = SetLit![3, 4, 5, 6];
+    let diff_set = set1.difference(&set2);
+
+    assert_eq!(diff_set.size(), 2);
+    assert!(diff_set.mem(&1));
+    assert!(diff_set.mem(&2));
+    assert!(!diff_set.mem(&3));
+    assert!(!diff_set.mem(&5));
+
+    let empty: SetStEph<i32> = SetLit![];
+    assert_eq!(set1.difference(&empty), set1);
+    assert_eq!(empty.difference(&set1).size(), 0);
+    assert_eq!(set1.difference(&set1).size(), 0);
+}
+
+#[test]
+fn test_set_subset_of() {
+    let small = SetLit![1, 2];
+    let big = SetLit![1, 2, 3];
+    let empty: SetStEph<i32> = SetLit![];
+
+    assert!(small.subset_of(&big));
+    assert!(!big.subset_of(&small));
+    assert!(big.subset_of(&big));
+    assert!(empty.subset_of(&small));
+    assert!(!small.subset_of(&empty));
+}
+
+#[test]
+fn test_set_delete() {
+    let mut set = SetLit![1, 2, 3];
+    assert!(set.delete(&2));
+    assert!(!set.delete(&2)); // already gone
+    assert!(!set.delete(&99));
+
+    assert_eq!(set.size(), 2);
+    assert!(set.mem(&1));
+    assert!(!set.mem(&2));
+    assert!(set.mem(&3));
+}
+
 #[test]
 fn test_set_insert() {
     let mut set = SetStEph::empty();
@@ -549,3 +593,31 @@ fn test_display_trait() {
     // Display shows "Set(count)" format
     assert!(display_str.contains("Set(3)"));
 }
+
+fn hash_of<T: std::hash::Hash>(x: &T) -> u64 {
+    use std::hash::Hasher;
+    let mut h = std::collections::hash_map::DefaultHasher::new();
+    x.hash(&mut h);
+    h.finish()
+}
+
+#[test]
+fn test_hash_independent_of_insertion_order() {
+    for _ in 0..100 {
+        let forward = SetStEph::FromVec((0..32).collect::<Vec<i32>>());
+        let backward = SetStEph::FromVec((0..32).rev().collect::<Vec<i32>>());
+        assert_eq!(forward, backward);
+        assert_eq!(hash_of(&forward), hash_of(&backward));
+    }
+    assert_eq!(hash_of(&SetLit!['a', 'b']), hash_of(&SetLit!['b', 'a']));
+    assert_ne!(hash_of(&SetLit![1, 2]), hash_of(&SetLit![1, 2, 3]));
+}
+
+#[test]
+fn test_equal_sets_as_set_elements() {
+    for _ in 0..100 {
+        let outer = SetLit![SetLit!['a', 'b', 'c', 'd'], SetLit!['e']];
+        assert!(outer.mem(&SetLit!['d', 'c', 'b', 'a']));
+        assert!(!outer.mem(&SetLit!['a', 'b']));
+    }
+}
@@ -0,0 +1,63 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+use verus_test::Chap05::SetStEph::SetStEph::*;
+use verus_test::Chap06::AdjTableGraphStEph::AdjTableGraphStEph::*;
+use verus_test::Chap06::DirGraphStEph::DirGraphStEph::*;
+use verus_test::Chap06::GraphStEph::GraphStEph::GraphStEphTrait;
+use verus_test::{DirGraphLit, SetLit};
+
+fn apas_digraph() -> DirGraphStEph<char> {
+    DirGraphLit!(V: ['A', 'B', 'C', 'D'], A: [('A', 'B'), ('A', 'C'), ('B', 'C'), ('C', 'D'), ('D', 'B')])
+}
+
+#[test]
+fn test_empty_table() {
+    let g: AdjTableGraphStEph<u32> = AdjTableGraphStEph::empty();
+    assert_eq!(g.num_vertices(), 0);
+    assert!(!g.has_vertex(&1));
+    assert!(g.adjacent(&1).is_none());
+    assert_eq!(g.out_neighbors(&1), SetLit![]);
+}
+
+#[test]
+fn test_insert_vertex_and_arc() {
+    let mut g: AdjTableGraphStEph<u32> = AdjTableGraphStEph::empty();
+    g.insert_vertex(9);
+    g.insert_arc(1, 2);
+    g.insert_arc(1, 3);
+    g.insert_arc(1, 2);
+    assert_eq!(g.vertex_set(), SetLit![1, 2, 3, 9]);
+    assert_eq!(g.adjacent(&1), Some(&SetLit![2, 3]));
+    assert_eq!(g.adjacent(&2), Some(&SetLit![]));
+    assert!(g.has_arc(&1, &3));
+    assert!(!g.has_arc(&3, &1));
+    assert_eq!(format!("{}", g), "AdjTableGraph(V: 4, A: 2)");
+}
+
+#[test]
+fn test_from_dir_graph_matches_edge_set_queries() {
+    let d = apas_digraph();
+    let g = AdjTableGraphStEph::FromDirGraph(&d);
+    assert_eq!(g.vertex_set(), d.vertex_set());
+    for v in ['A', 'B', 'C', 'D', 'Z'] {
+        assert_eq!(g.out_neighbors(&v), d.NPlus(&v));
+        for w in ['A', 'B', 'C', 'D'] {
+            assert_eq!(g.has_arc(&v, &w), d.Neighbor(&v, &w));
+        }
+    }
+}
+
+#[test]
+fn test_dir_graph_round_trip() {
+    let d = apas_digraph();
+    let g = AdjTableGraphStEph::FromDirGraph(&d);
+    assert_eq!(g.to_dir_graph(), d);
+    assert_eq!(g.clone(), g);
+}
+
+#[test]
+fn test_isolated_vertex_survives_conversion() {
+    let d = DirGraphLit!(V: [1, 2, 3], A: [(1, 2)]);
+    let g = AdjTableGraphStEph::FromDirGraph(&d);
+    assert_eq!(g.adjacent(&3), Some(&SetLit![]));
+    assert_eq!(g.to_dir_graph(), d);
+}
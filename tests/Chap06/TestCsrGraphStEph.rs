@@ -0,0 +1,58 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+use verus_test::Chap05::SetStEph::SetStEph::*;
+use verus_test::Chap06::AdjTableGraphStEph::AdjTableGraphStEph::*;
+use verus_test::Chap06::CsrGraphStEph::CsrGraphStEph::*;
+use verus_test::Chap06::DirGraphStEph::DirGraphStEph::*;
+use verus_test::Chap06::GraphStEph::GraphStEph::GraphStEphTrait;
+use verus_test::{DirGraphLit, SetLit};
+
+// 0 -> 1, 0 -> 2, 1 -> 2, 2 -> 0, 3 isolated.
+fn table() -> AdjTableGraphStEph<usize> {
+    AdjTableGraphStEph::FromDirGraph(&DirGraphLit!(V: [0, 1, 2, 3], A: [(0, 1), (0, 2), (1, 2), (2, 0)]))
+}
+
+#[test]
+fn test_empty_csr() {
+    let g = CsrGraphStEph::empty();
+    assert_eq!(g.num_vertices(), 0);
+    assert_eq!(g.num_entries(), 0);
+    assert!(!g.has_vertex(&0));
+    assert_eq!(g.out_neighbors(&0), SetLit![]);
+}
+
+#[test]
+fn test_from_adj_table_layout() {
+    let g = CsrGraphStEph::from_adj_table(&table(), 4);
+    assert_eq!(g.num_vertices(), 4);
+    assert_eq!(g.num_entries(), 4);
+    assert_eq!(g.offsets(), &vec![0, 2, 3, 4, 4]);
+    assert_eq!(g.targets().len(), 4);
+    assert_eq!(g.out_degree(0), 2);
+    assert_eq!(g.out_degree(3), 0);
+    assert_eq!(g.out_neighbor_at(1, 0), 2);
+    assert_eq!(format!("{}", g), "CsrGraph(V: 4, entries: 4)");
+}
+
+#[test]
+fn test_same_queries_as_adj_table() {
+    let t = table();
+    let g = CsrGraphStEph::from_adj_table(&t, 4);
+    assert_eq!(g.vertex_set(), t.vertex_set());
+    for v in 0..5usize {
+        assert_eq!(g.has_vertex(&v), t.has_vertex(&v));
+        assert_eq!(g.out_neighbors(&v), t.out_neighbors(&v));
+        for w in 0..4usize {
+            assert_eq!(g.has_arc(&v, &w), t.has_arc(&v, &w));
+        }
+    }
+}
+
+#[test]
+fn test_round_trips() {
+    let t = table();
+    let g = CsrGraphStEph::from_adj_table(&t, 4);
+    assert_eq!(g.to_adj_table(), t);
+    let d: DirGraphStEph<usize> = g.to_adj_table().to_dir_graph();
+    assert_eq!(d, t.to_dir_graph());
+    assert_eq!(g.clone(), g);
+}
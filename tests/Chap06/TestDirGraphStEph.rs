@@ -0,0 +1,88 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+use verus_test::Chap05::SetStEph::SetStEph::*;
+use verus_test::Chap06::DirGraphStEph::DirGraphStEph::*;
+use verus_test::Types::Types::*;
+use verus_test::{DirGraphLit, SetLit};
+
+// A small Chapter 6 style digraph: A -> B, A -> C, B -> C, C -> D, D -> B.
+fn apas_digraph() -> DirGraphStEph<char> {
+    DirGraphLit!(V: ['A', 'B', 'C', 'D'], A: [('A', 'B'), ('A', 'C'), ('B', 'C'), ('C', 'D'), ('D', 'B')])
+}
+
+#[test]
+fn test_empty_graph() {
+    let g: DirGraphStEph<u32> = DirGraphLit!();
+    assert_eq!(g.sizeV(), 0);
+    assert_eq!(g.sizeA(), 0);
+    assert_eq!(g.NPlus(&1).size(), 0);
+}
+
+#[test]
+fn test_from_sets_vertices_arcs() {
+    let v: SetStEph<u32> = SetLit![1, 2, 3];
+    let a: SetStEph<Edge<u32>> = SetLit![Edge(1, 2), Edge(2, 3)];
+    let g = DirGraphStEph::FromSets(v.clone(), a.clone());
+    assert_eq!(g.vertices(), &v);
+    assert_eq!(g.arcs(), &a);
+    assert_eq!(g.sizeV(), 3);
+    assert_eq!(g.sizeA(), 2);
+}
+
+#[test]
+fn test_neighbor_is_directed() {
+    let g = apas_digraph();
+    assert!(g.Neighbor(&'A', &'B'));
+    assert!(!g.Neighbor(&'B', &'A'));
+    assert!(g.Neighbor(&'D', &'B'));
+    assert!(!g.Neighbor(&'A', &'D'));
+}
+
+#[test]
+fn test_n_plus_and_n_minus() {
+    let g = apas_digraph();
+    assert_eq!(g.NPlus(&'A'), SetLit!['B', 'C']);
+    assert_eq!(g.NPlus(&'C'), SetLit!['D']);
+    assert_eq!(g.NMinus(&'B'), SetLit!['A', 'D']);
+    assert_eq!(g.NMinus(&'A'), SetLit![]);
+    assert_eq!(g.NPlus(&'Z'), SetLit![]);
+}
+
+#[test]
+fn test_degrees() {
+    let g = apas_digraph();
+    assert_eq!(g.OutDegree(&'A'), 2);
+    assert_eq!(g.InDegree(&'A'), 0);
+    assert_eq!(g.OutDegree(&'B'), 1);
+    assert_eq!(g.InDegree(&'B'), 2);
+    assert_eq!(g.InDegree(&'C'), 2);
+    let total_out: usize = ['A', 'B', 'C', 'D'].iter().map(|v| g.OutDegree(v)).sum();
+    let total_in: usize = ['A', 'B', 'C', 'D'].iter().map(|v| g.InDegree(v)).sum();
+    assert_eq!(total_out, g.sizeA());
+    assert_eq!(total_in, g.sizeA());
+}
+
+#[test]
+fn test_incident() {
+    let g = apas_digraph();
+    assert!(g.Incident(&Edge('A', 'B'), &'A'));
+    assert!(g.Incident(&Edge('A', 'B'), &'B'));
+    assert!(!g.Incident(&Edge('A', 'B'), &'C'));
+}
+
+#[test]
+fn test_self_loop() {
+    let g = DirGraphLit!(V: [1, 2], A: [(1, 1), (1, 2)]);
+    assert_eq!(g.NPlus(&1), SetLit![1, 2]);
+    assert_eq!(g.NMinus(&1), SetLit![1]);
+    assert_eq!(g.OutDegree(&1), 2);
+    assert_eq!(g.InDegree(&1), 1);
+}
+
+#[test]
+fn test_clone_and_eq() {
+    let g = apas_digraph();
+    let h = g.clone();
+    assert_eq!(g, h);
+    assert_ne!(g, DirGraphLit!(V: ['A'], A: []));
+    assert_eq!(format!("{}", g), "DirGraph(V: Set(4), A: Set(5))");
+}
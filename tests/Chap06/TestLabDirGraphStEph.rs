@@ -0,0 +1,87 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+use verus_test::Chap05::SetStEph::SetStEph::*;
+use verus_test::Chap06::LabDirGraphStEph::LabDirGraphStEph::*;
+use verus_test::Types::Types::*;
+use verus_test::{LabDirGraphLit, SetLit};
+
+// A weighted digraph: s -1-> a, s -4-> b, a -2-> b, b -3-> t.
+fn weighted_digraph() -> LabDirGraphStEph<char, u32> {
+    LabDirGraphLit!(V: ['s', 'a', 'b', 't'], A: [('s', 'a', 1), ('s', 'b', 4), ('a', 'b', 2), ('b', 't', 3)])
+}
+
+#[test]
+fn test_empty_graph() {
+    let g: LabDirGraphStEph<u32, u32> = LabDirGraphLit!();
+    assert_eq!(g.sizeV(), 0);
+    assert_eq!(g.sizeA(), 0);
+    assert_eq!(g.get_arc_label(&1, &2), None);
+}
+
+#[test]
+fn test_from_sets() {
+    let v: SetStEph<u32> = SetLit![1, 2];
+    let a: SetStEph<LabEdge<u32, u32>> = SetLit![LabEdge(1, 2, 42)];
+    let g = LabDirGraphStEph::FromSets(v.clone(), a.clone());
+    assert_eq!(g.vertices(), &v);
+    assert_eq!(g.labeled_arcs(), &a);
+    assert_eq!(g.sizeV(), 2);
+    assert_eq!(g.sizeA(), 1);
+}
+
+#[test]
+fn test_arc_weights_are_directed() {
+    let g = weighted_digraph();
+    assert_eq!(g.get_arc_label(&'s', &'a'), Some(1));
+    assert_eq!(g.get_arc_label(&'a', &'b'), Some(2));
+    assert_eq!(g.get_arc_label(&'a', &'s'), None);
+    assert!(g.has_arc(&'b', &'t'));
+    assert!(!g.has_arc(&'t', &'b'));
+}
+
+#[test]
+fn test_neighbors() {
+    let g = weighted_digraph();
+    assert_eq!(g.out_neighbors(&'s'), SetLit!['a', 'b']);
+    assert_eq!(g.in_neighbors(&'b'), SetLit!['s', 'a']);
+    assert_eq!(g.out_neighbors(&'t'), SetLit![]);
+}
+
+#[test]
+fn test_add_vertex_and_arc() {
+    let mut g: LabDirGraphStEph<u32, u32> = LabDirGraphLit!();
+    g.add_vertex(7);
+    g.add_labeled_arc(1, 2, 10);
+    assert_eq!(g.vertices(), &SetLit![1, 2, 7]);
+    assert_eq!(g.get_arc_label(&1, &2), Some(10));
+    assert_eq!(g.sizeA(), 1);
+}
+
+#[test]
+fn test_add_arc_replaces_label() {
+    let mut g = weighted_digraph();
+    g.add_labeled_arc('a', 'b', 9);
+    assert_eq!(g.sizeA(), 4);
+    assert_eq!(g.get_arc_label(&'a', &'b'), Some(9));
+    assert!(!g.labeled_arcs().mem(&LabEdge('a', 'b', 2)));
+    g.add_labeled_arc('b', 'a', 6);
+    assert_eq!(g.sizeA(), 5);
+    assert_eq!(g.get_arc_label(&'a', &'b'), Some(9));
+}
+
+#[test]
+fn test_from_sets_keeps_one_label_per_arc() {
+    let g = LabDirGraphLit!(V: [1, 2], A: [(1, 2, 5), (1, 2, 8), (2, 1, 3)]);
+    assert_eq!(g.sizeA(), 2);
+    let label = g.get_arc_label(&1, &2);
+    assert!(label == Some(5) || label == Some(8));
+    assert_eq!(g.get_arc_label(&2, &1), Some(3));
+}
+
+#[test]
+fn test_clone_and_eq() {
+    let g = weighted_digraph();
+    let h = g.clone();
+    assert_eq!(g, h);
+    assert_ne!(g, LabDirGraphLit!(V: ['s'], A: []));
+    assert_eq!(format!("{}", g), "LabDirGraph(V: Set(4), A: Set(4))");
+}
@@ -0,0 +1,93 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+use verus_test::Chap05::SetStEph::SetStEph::*;
+use verus_test::Chap06::LabUnDirGraphStEph::LabUnDirGraphStEph::*;
+use verus_test::Types::Types::*;
+use verus_test::{LabUnDirGraphLit, SetLit};
+
+// A weighted undirected triangle with a tail: {1,2}:5, {2,3}:7, {1,3}:9, {3,4}:1.
+fn weighted_graph() -> LabUnDirGraphStEph<u32, u32> {
+    LabUnDirGraphLit!(V: [1, 2, 3, 4], E: [(1, 2, 5), (2, 3, 7), (1, 3, 9), (3, 4, 1)])
+}
+
+#[test]
+fn test_empty_graph() {
+    let g: LabUnDirGraphStEph<u32, u32> = LabUnDirGraphLit!();
+    assert_eq!(g.sizeV(), 0);
+    assert_eq!(g.sizeE(), 0);
+    assert!(!g.has_edge(&1, &2));
+}
+
+#[test]
+fn test_from_sets() {
+    let v: SetStEph<u32> = SetLit![1, 2];
+    let e: SetStEph<LabEdge<u32, u32>> = SetLit![LabEdge(1, 2, 3)];
+    let g = LabUnDirGraphStEph::FromSets(v.clone(), e.clone());
+    assert_eq!(g.vertices(), &v);
+    assert_eq!(g.labeled_edges(), &e);
+    assert_eq!(g.sizeE(), 1);
+}
+
+#[test]
+fn test_edge_weights_in_either_orientation() {
+    let g = weighted_graph();
+    assert_eq!(g.get_edge_label(&1, &2), Some(5));
+    assert_eq!(g.get_edge_label(&2, &1), Some(5));
+    assert_eq!(g.get_edge_label(&4, &3), Some(1));
+    assert_eq!(g.get_edge_label(&1, &4), None);
+    assert!(g.has_edge(&3, &2));
+    assert!(!g.has_edge(&2, &4));
+}
+
+#[test]
+fn test_neighbors_and_degree() {
+    let g = weighted_graph();
+    assert_eq!(g.Neighbors(&3), SetLit![1, 2, 4]);
+    assert_eq!(g.Neighbors(&4), SetLit![3]);
+    assert_eq!(g.Degree(&1), 2);
+    let total: usize = [1, 2, 3, 4].iter().map(|v| g.Degree(v)).sum();
+    assert_eq!(total, 2 * g.sizeE());
+}
+
+#[test]
+fn test_add_vertex_and_edge() {
+    let mut g: LabUnDirGraphStEph<char, u32> = LabUnDirGraphLit!();
+    g.add_vertex('x');
+    g.add_labeled_edge('a', 'b', 3);
+    assert_eq!(g.vertices(), &SetLit!['a', 'b', 'x']);
+    assert_eq!(g.get_edge_label(&'b', &'a'), Some(3));
+    assert_eq!(g.Degree(&'x'), 0);
+}
+
+#[test]
+fn test_both_orientations_stored_count_once() {
+    let g = LabUnDirGraphLit!(V: [1, 2], E: [(1, 2, 5), (2, 1, 5)]);
+    assert_eq!(g.sizeE(), 1);
+    assert_eq!(g.Degree(&1), 1);
+    assert_eq!(g.get_edge_label(&2, &1), Some(5));
+    assert_eq!(g, LabUnDirGraphLit!(V: [1, 2], E: [(2, 1, 5)]));
+    assert_ne!(g, LabUnDirGraphLit!(V: [1, 2], E: [(2, 1, 6)]));
+    let h = LabUnDirGraphLit!(V: [1, 2], E: [(1, 2, 5), (2, 1, 6)]);
+    assert_eq!(h.sizeE(), 1);
+}
+
+#[test]
+fn test_add_edge_replaces_label() {
+    let mut g = LabUnDirGraphLit!(V: [1, 2], E: [(1, 2, 5)]);
+    g.add_labeled_edge(2, 1, 5);
+    assert_eq!(g.sizeE(), 1);
+    g.add_labeled_edge(2, 1, 8);
+    assert_eq!(g.sizeE(), 1);
+    assert_eq!(g.get_edge_label(&1, &2), Some(8));
+    assert!(!g.labeled_edges().mem(&LabEdge(1, 2, 5)));
+    let total: usize = [1, 2].iter().map(|v| g.Degree(v)).sum();
+    assert_eq!(total, 2);
+}
+
+#[test]
+fn test_clone_and_eq() {
+    let g = weighted_graph();
+    let h = g.clone();
+    assert_eq!(g, h);
+    assert_ne!(g, LabUnDirGraphLit!(V: [1], E: []));
+    assert_eq!(format!("{}", g), "LabUnDirGraph(V: Set(4), E: Set(4))");
+}
@@ -0,0 +1,86 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+use verus_test::Chap05::SetStEph::SetStEph::*;
+use verus_test::Chap06::UnDirGraphStEph::UnDirGraphStEph::*;
+use verus_test::Types::Types::*;
+use verus_test::{SetLit, UnDirGraphLit};
+
+// A small undirected graph: {A, B}, {A, C}, {B, C}, {C, D}.
+fn apas_graph() -> UnDirGraphStEph<char> {
+    UnDirGraphLit!(V: ['A', 'B', 'C', 'D'], E: [('A', 'B'), ('A', 'C'), ('B', 'C'), ('C', 'D')])
+}
+
+#[test]
+fn test_empty_graph() {
+    let g: UnDirGraphStEph<u32> = UnDirGraphLit!();
+    assert_eq!(g.sizeV(), 0);
+    assert_eq!(g.sizeE(), 0);
+    assert_eq!(g.Degree(&1), 0);
+}
+
+#[test]
+fn test_from_sets_vertices_edges() {
+    let v: SetStEph<u32> = SetLit![1, 2, 3];
+    let e: SetStEph<Edge<u32>> = SetLit![Edge(1, 2), Edge(2, 3)];
+    let g = UnDirGraphStEph::FromSets(v.clone(), e.clone());
+    assert_eq!(g.vertices(), &v);
+    assert_eq!(g.edges(), &e);
+    assert_eq!(g.sizeV(), 3);
+    assert_eq!(g.sizeE(), 2);
+}
+
+#[test]
+fn test_neighbor_is_symmetric() {
+    let g = apas_graph();
+    assert!(g.Neighbor(&'A', &'B'));
+    assert!(g.Neighbor(&'B', &'A'));
+    assert!(g.Neighbor(&'D', &'C'));
+    assert!(!g.Neighbor(&'A', &'D'));
+}
+
+#[test]
+fn test_neighbors_and_degree() {
+    let g = apas_graph();
+    assert_eq!(g.Neighbors(&'A'), SetLit!['B', 'C']);
+    assert_eq!(g.Neighbors(&'C'), SetLit!['A', 'B', 'D']);
+    assert_eq!(g.Neighbors(&'D'), SetLit!['C']);
+    assert_eq!(g.Neighbors(&'Z'), SetLit![]);
+    assert_eq!(g.Degree(&'C'), 3);
+    // Handshake lemma: the degrees sum to twice the number of edges.
+    let total: usize = ['A', 'B', 'C', 'D'].iter().map(|v| g.Degree(v)).sum();
+    assert_eq!(total, 2 * g.sizeE());
+}
+
+#[test]
+fn test_both_orientations_stored_count_once() {
+    let g = UnDirGraphLit!(V: [1, 2], E: [(1, 2), (2, 1)]);
+    assert_eq!(g.sizeE(), 1);
+    assert_eq!(g.Neighbors(&1), SetLit![2]);
+    assert_eq!(g.Degree(&2), 1);
+    let total: usize = [1, 2].iter().map(|v| g.Degree(v)).sum();
+    assert_eq!(total, 2 * g.sizeE());
+}
+
+#[test]
+fn test_eq_ignores_orientation() {
+    let g = UnDirGraphLit!(V: [1, 2, 3], E: [(1, 2), (2, 3)]);
+    assert_eq!(g, UnDirGraphLit!(V: [1, 2, 3], E: [(2, 1), (3, 2)]));
+    assert_eq!(g, UnDirGraphLit!(V: [1, 2, 3], E: [(1, 2), (2, 1), (2, 3)]));
+    assert_ne!(g, UnDirGraphLit!(V: [1, 2, 3], E: [(1, 2), (1, 3)]));
+}
+
+#[test]
+fn test_self_loop_and_incident() {
+    let g = UnDirGraphLit!(V: [1, 2], E: [(1, 1), (1, 2)]);
+    assert_eq!(g.Neighbors(&1), SetLit![1, 2]);
+    assert!(g.Incident(&Edge(1, 2), &2));
+    assert!(!g.Incident(&Edge(1, 1), &2));
+}
+
+#[test]
+fn test_clone_and_eq() {
+    let g = apas_graph();
+    let h = g.clone();
+    assert_eq!(g, h);
+    assert_ne!(g, UnDirGraphLit!(V: ['A'], E: []));
+    assert_eq!(format!("{}", g), "UnDirGraph(V: Set(4), E: Set(4))");
+}
@@ -0,0 +1,187 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Tests for OrderedTableStEph ordered tables.
+
+use verus_test::Chap43::OrderedTableStEph::OrderedTableStEph::*;
+use verus_test::OrderedTableLit;
+use verus_test::Types::Types::*;
+use verus_test::vstdplus::total_order::total_order::TotalOrder;
+
+/// The keys of t in increasing order.
+fn keys<K: StT + TotalOrder, V: StT>(t: &OrderedTableStEph<K, V>) -> Vec<K> {
+    (0..t.size()).map(|i| t.select(i).unwrap().clone()).collect()
+}
+
+#[test]
+fn test_orderedtablelit_macro() {
+    let empty: OrderedTableStEph<i32, &str> = OrderedTableLit![];
+    assert_eq!(empty.size(), 0);
+
+    let t = OrderedTableLit![(3, "c"), (1, "a"), (2, "b")];
+    assert_eq!(t.size(), 3);
+    assert_eq!(keys(&t), vec![1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "OrderedTableLit!: duplicate key 1 maps to both")]
+fn test_orderedtablelit_rejects_duplicate_keys() {
+    let _t = OrderedTableLit![(1, 'a'), (2, 'b'), (1, 'c')];
+}
+
+#[test]
+fn test_find_insert_replace() {
+    let mut t: OrderedTableStEph<i32, &str> = OrderedTableStEph::empty();
+    t.insert(5, "five");
+    t.insert(1, "one");
+    t.insert(9, "nine");
+    assert_eq!(t.find(&1), Some(&"one"));
+    assert_eq!(t.find(&5), Some(&"five"));
+    assert_eq!(t.find(&4), None);
+
+    t.insert(5, "FIVE");
+    assert_eq!(t.size(), 3);
+    assert_eq!(t.find(&5), Some(&"FIVE"));
+}
+
+#[test]
+fn test_singleton() {
+    let t = OrderedTableStEph::singleton(7, 'x');
+    assert_eq!(t.size(), 1);
+    assert_eq!(t.find(&7), Some(&'x'));
+    assert_eq!(t.first(), Some(&7));
+    assert_eq!(t.last(), Some(&7));
+}
+
+#[test]
+fn test_delete() {
+    let mut t = OrderedTableLit![(1, 'a'), (2, 'b'), (3, 'c')];
+    assert_eq!(t.delete(&2), Some('b'));
+    assert_eq!(t.delete(&2), None);
+    assert_eq!(t.delete(&42), None);
+    assert_eq!(t, OrderedTableLit![(1, 'a'), (3, 'c')]);
+}
+
+#[test]
+fn test_first_last() {
+    let t = OrderedTableLit![(20, 'x'), (-5, 'y'), (7, 'z')];
+    assert_eq!(t.first(), Some(&-5));
+    assert_eq!(t.last(), Some(&20));
+
+    let empty: OrderedTableStEph<i32, char> = OrderedTableLit![];
+    assert_eq!(empty.first(), None);
+    assert_eq!(empty.last(), None);
+}
+
+#[test]
+fn test_next_prev() {
+    let t = OrderedTableLit![(10, 'a'), (20, 'b'), (30, 'c')];
+    assert_eq!(t.next(&10), Some(&20));
+    assert_eq!(t.next(&15), Some(&20));
+    assert_eq!(t.next(&5), Some(&10));
+    assert_eq!(t.next(&30), None);
+
+    assert_eq!(t.prev(&30), Some(&20));
+    assert_eq!(t.prev(&25), Some(&20));
+    assert_eq!(t.prev(&35), Some(&30));
+    assert_eq!(t.prev(&10), None);
+}
+
+#[test]
+fn test_rank_select() {
+    let t = OrderedTableLit![(10, 'a'), (20, 'b'), (30, 'c')];
+    assert_eq!(t.rank(&5), 0);
+    assert_eq!(t.rank(&10), 0);
+    assert_eq!(t.rank(&15), 1);
+    assert_eq!(t.rank(&30), 2);
+    assert_eq!(t.rank(&99), 3);
+
+    assert_eq!(t.select(0), Some(&10));
+    assert_eq!(t.select(2), Some(&30));
+    assert_eq!(t.select(3), None);
+
+    for i in 0..t.size() {
+        assert_eq!(t.rank(t.select(i).unwrap()), i);
+    }
+}
+
+#[test]
+fn test_split_present_key() {
+    let t = OrderedTableLit![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')];
+    let (left, found, right) = t.split(&3);
+    assert_eq!(left, OrderedTableLit![(1, 'a'), (2, 'b')]);
+    assert_eq!(found, Some('c'));
+    assert_eq!(right, OrderedTableLit![(4, 'd')]);
+}
+
+#[test]
+fn test_split_absent_key() {
+    let t = OrderedTableLit![(1, 'a'), (3, 'c'), (5, 'e')];
+    let (left, found, right) = t.split(&4);
+    assert_eq!(left, OrderedTableLit![(1, 'a'), (3, 'c')]);
+    assert_eq!(found, None);
+    assert_eq!(right, OrderedTableLit![(5, 'e')]);
+
+    let (below, _, above) = t.split(&0);
+    assert_eq!(below.size(), 0);
+    assert_eq!(above, t);
+}
+
+#[test]
+fn test_split_join_round_trip() {
+    let t = OrderedTableLit![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')];
+    let (left, _, right) = t.split(&2);
+    let joined = OrderedTableStEph::join(left, right);
+    assert_eq!(joined, OrderedTableLit![(1, 'a'), (3, 'c'), (4, 'd')]);
+
+    let empty: OrderedTableStEph<i32, char> = OrderedTableLit![];
+    assert_eq!(OrderedTableStEph::join(empty.clone(), t.clone()), t);
+    assert_eq!(OrderedTableStEph::join(t.clone(), empty), t);
+}
+
+#[test]
+fn test_get_range() {
+    let t = OrderedTableLit![(1, 'a'), (3, 'c'), (5, 'e'), (7, 'g'), (9, 'i')];
+    assert_eq!(t.get_range(&3, &7), OrderedTableLit![(3, 'c'), (5, 'e'), (7, 'g')]);
+    assert_eq!(t.get_range(&2, &6), OrderedTableLit![(3, 'c'), (5, 'e')]);
+    assert_eq!(t.get_range(&0, &100), t);
+    assert_eq!(t.get_range(&4, &4).size(), 0);
+    // An inverted range is empty.
+    assert_eq!(t.get_range(&7, &3).size(), 0);
+}
+
+#[test]
+fn test_debug_display() {
+    let t = OrderedTableLit![(2, "two"), (1, "one")];
+    assert_eq!(format!("{:?}", t), "OrderedTableStEph(1 -> \"one\", 2 -> \"two\")");
+    assert_eq!(format!("{}", t), "{1 -> one, 2 -> two}");
+}
+
+#[test]
+fn test_string_keys_are_lexicographic() {
+    let t = OrderedTableLit![("pear".to_string(), 3), ("apple".to_string(), 1), ("app".to_string(), 0), ("banana".to_string(), 2)];
+    assert_eq!(keys(&t), vec!["app", "apple", "banana", "pear"]);
+    assert_eq!(t.next(&"apple".to_string()), Some(&"banana".to_string()));
+
+    let s = OrderedTableLit![("b", 'b'), ("a", 'a'), ("ab", 'c')];
+    assert_eq!(s.first(), Some(&"a"));
+    assert_eq!(s.select(1), Some(&"ab"));
+}
+
+#[test]
+fn test_pair_and_triple_keys_are_lexicographic() {
+    let t = OrderedTableLit![(Pair(2, 'a'), 0), (Pair(1, 'z'), 1), (Pair(1, 'b'), 2)];
+    assert_eq!(t.first(), Some(&Pair(1, 'b')));
+    assert_eq!(t.last(), Some(&Pair(2, 'a')));
+    assert_eq!(t.rank(&Pair(1, 'c')), 1);
+
+    let u = OrderedTableLit![(Triple(1, true, 'x'), 0), (Triple(1, false, 'y'), 1), (Triple(0, true, 'z'), 2)];
+    assert_eq!(keys(&u), vec![Triple(0, true, 'z'), Triple(1, false, 'y'), Triple(1, true, 'x')]);
+}
+
+#[test]
+fn test_bool_and_char_keys() {
+    let t = OrderedTableLit![(true, 1), (false, 0)];
+    assert_eq!(t.first(), Some(&false));
+
+    let c = OrderedTableLit![('z', 26), ('a', 1), ('m', 13)];
+    assert_eq!(c.get_range(&'b', &'z'), OrderedTableLit![('m', 13), ('z', 26)]);
+}
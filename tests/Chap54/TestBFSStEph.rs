@@ -0,0 +1,89 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+use verus_test::Chap05::MappingStEph::MappingStEph::*;
+use verus_test::Chap05::SetStEph::SetStEph::*;
+use verus_test::Chap06::AdjTableGraphStEph::AdjTableGraphStEph::*;
+use verus_test::Chap06::CsrGraphStEph::CsrGraphStEph::*;
+use verus_test::Chap06::DirGraphStEph::DirGraphStEph::*;
+use verus_test::Chap06::GraphStEph::GraphStEph::GraphStEphTrait;
+use verus_test::Chap54::BFSStEph::BFSStEph::*;
+use verus_test::Types::Types::*;
+use verus_test::{DirGraphLit, SetLit};
+
+/// 0 -> 1 -> 3 -> 4, 0 -> 2 -> 3, 4 -> 1, and 5 -> 0 unreachable from 0.
+fn digraph() -> DirGraphStEph<N> {
+    DirGraphLit!(V: [0, 1, 2, 3, 4, 5], A: [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (4, 1), (5, 0)])
+}
+
+fn assert_shortest_path_tree<G: GraphStEphTrait<N>>(g: &G, dist: &MappingStEph<N, N>, parent: &MappingStEph<N, N>) {
+    for Pair(v, p) in parent.iter() {
+        assert!(g.has_arc(p, v));
+        let dp = dist.iter().find(|Pair(u, _)| u == p).map(|Pair(_, d)| *d).unwrap();
+        let dv = dist.iter().find(|Pair(u, _)| u == v).map(|Pair(_, d)| *d).unwrap();
+        assert_eq!(dp + 1, dv);
+    }
+}
+
+#[test]
+fn test_bfs_distances() {
+    let g = digraph();
+    let Pair(dist, _) = g.bfs(&0);
+    assert_eq!(dist.size(), 5);
+    assert!(dist.mem(&Pair(0, 0)));
+    assert!(dist.mem(&Pair(1, 1)));
+    assert!(dist.mem(&Pair(2, 1)));
+    assert!(dist.mem(&Pair(3, 2)));
+    assert!(dist.mem(&Pair(4, 3)));
+}
+
+#[test]
+fn test_bfs_unreachable_absent() {
+    let g = digraph();
+    let Pair(dist, parent) = g.bfs(&0);
+    assert_eq!(dist.domain(), SetLit![0, 1, 2, 3, 4]);
+    assert_eq!(parent.domain(), SetLit![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_bfs_parent_tree() {
+    let g = digraph();
+    let Pair(dist, parent) = g.bfs(&0);
+    assert!(parent.mem(&Pair(1, 0)));
+    assert!(parent.mem(&Pair(2, 0)));
+    assert!(parent.mem(&Pair(4, 3)));
+    assert!(parent.mem(&Pair(3, 1)) || parent.mem(&Pair(3, 2)));
+    assert_shortest_path_tree(&g, &dist, &parent);
+}
+
+#[test]
+fn test_bfs_from_sink() {
+    let g = DirGraphLit!(V: [0, 1], A: [(0, 1)]);
+    let Pair(dist, parent) = g.bfs(&1);
+    assert_eq!(dist.size(), 1);
+    assert!(dist.mem(&Pair(1, 0)));
+    assert_eq!(parent.size(), 0);
+}
+
+#[test]
+fn test_bfs_from_other_source() {
+    let g = digraph();
+    let Pair(dist, parent) = g.bfs(&5);
+    assert_eq!(dist.size(), 6);
+    assert!(dist.mem(&Pair(5, 0)));
+    assert!(dist.mem(&Pair(0, 1)));
+    assert!(dist.mem(&Pair(4, 4)));
+    assert_shortest_path_tree(&g, &dist, &parent);
+}
+
+#[test]
+fn test_bfs_agrees_across_representations() {
+    let d = digraph();
+    let table = AdjTableGraphStEph::FromDirGraph(&d);
+    let csr = CsrGraphStEph::from_adj_table(&table, 6);
+    let Pair(dist_d, _) = d.bfs(&0);
+    let Pair(dist_t, parent_t) = table.bfs(&0);
+    let Pair(dist_c, parent_c) = csr.bfs(&0);
+    assert_eq!(dist_d, dist_t);
+    assert_eq!(dist_d, dist_c);
+    assert_shortest_path_tree(&table, &dist_t, &parent_t);
+    assert_shortest_path_tree(&csr, &dist_c, &parent_c);
+}
@@ -0,0 +1,200 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Tests that the tuple-like types of Types::Types work as set and relation elements.
+
+use std::sync::{Arc, Mutex, RwLock};
+use verus_test::Chap05::MappingStEph::MappingStEph::*;
+use verus_test::Chap05::RelationStEph::RelationStEph::*;
+use verus_test::Chap05::SetStEph::SetStEph::*;
+use verus_test::Chap43::OrderedTableStEph::OrderedTableStEph::*;
+use verus_test::vstdplus::total_order::total_order::TotalOrder;
+use verus_test::{MappingLit, RelationLit, SetLit, StTRecord};
+use verus_test::Types::Types::*;
+
+StTRecord! {
+    pub struct Person { pub name: String, pub age: u32 }
+    axioms Person_axioms;
+}
+
+#[test]
+fn test_triple_in_set() {
+    let s = SetLit![Triple(1, 'a', true), Triple(2, 'b', false), Triple(1, 'a', true)];
+    assert_eq!(s.size(), 2);
+    assert!(s.mem(&Triple(1, 'a', true)));
+    assert!(!s.mem(&Triple(1, 'a', false)));
+    assert_eq!(Triple(1, 2, 3).clone(), Triple(1, 2, 3));
+}
+
+#[test]
+fn test_quadruple_in_set() {
+    let s = SetLit![Quadruple(1, 2, 3, 4), Quadruple(4, 3, 2, 1)];
+    assert_eq!(s.size(), 2);
+    assert!(s.mem(&Quadruple(4, 3, 2, 1)));
+    assert!(!s.mem(&Quadruple(1, 2, 3, 5)));
+}
+
+#[test]
+fn test_keyval_in_set() {
+    let s = SetLit![KeyVal { key: "a", val: 1 }, KeyVal { key: "b", val: 2 }];
+    assert_eq!(s.size(), 2);
+    assert!(s.mem(&KeyVal { key: "a", val: 1 }));
+    assert!(!s.mem(&KeyVal { key: "a", val: 2 }));
+}
+
+#[test]
+fn test_edge_in_set() {
+    let s = SetLit![Edge(1, 2), Edge(2, 3), Edge(1, 2)];
+    assert_eq!(s.size(), 2);
+    assert!(s.mem(&Edge(2, 3)));
+    // Edges are directed.
+    assert!(!s.mem(&Edge(2, 1)));
+}
+
+#[test]
+fn test_labedge_in_set() {
+    let s = SetLit![LabEdge(1, 2, "x"), LabEdge(1, 2, "y")];
+    assert_eq!(s.size(), 2);
+    assert!(s.mem(&LabEdge(1, 2, "y")));
+    assert!(!s.mem(&LabEdge(1, 2, "z")));
+}
+
+#[test]
+fn test_types_in_relations() {
+    let r = RelationLit![(Edge(1, 2), Triple(1, 2, 3)), (Edge(2, 3), Triple(2, 3, 4))];
+    assert_eq!(r.size(), 2);
+    assert!(r.mem(&Edge(1, 2), &Triple(1, 2, 3)));
+    assert!(!r.mem(&Edge(1, 2), &Triple(2, 3, 4)));
+    assert!(r.domain().mem(&Edge(2, 3)));
+
+    let labels = RelationLit![(KeyVal { key: 1, val: 'a' }, LabEdge(1, 2, 7)),
+                              (KeyVal { key: 2, val: 'b' }, LabEdge(2, 3, 8))];
+    assert_eq!(labels.range().size(), 2);
+    assert!(labels.range().mem(&LabEdge(2, 3, 8)));
+
+    let quads = RelationLit![(Quadruple(1, 1, 1, 1), 1), (Quadruple(2, 2, 2, 2), 2)];
+    assert!(quads.mem(&Quadruple(2, 2, 2, 2), &2));
+}
+
+#[test]
+fn test_types_display() {
+    assert_eq!(format!("{}", Triple(1, 2, 3)), "(1, 2, 3)");
+    assert_eq!(format!("{}", Quadruple(1, 2, 3, 4)), "(1, 2, 3, 4)");
+    assert_eq!(format!("{}", KeyVal { key: 1, val: 2 }), "{key: 1, val: 2}");
+    assert_eq!(format!("{}", Edge(1, 2)), "(1, 2)");
+    assert_eq!(format!("{}", LabEdge(1, 2, 3)), "(1, 2, 3)");
+}
+
+fn person(name: &str, age: u32) -> Person { Person { name: name.to_string(), age } }
+
+#[test]
+fn test_record_in_set() {
+    let s = SetLit![person("ann", 30), person("bob", 25), person("ann", 30)];
+    assert_eq!(s.size(), 2);
+    assert!(s.mem(&person("bob", 25)));
+    assert!(!s.mem(&person("bob", 26)));
+    assert_eq!(person("ann", 30).clone(), person("ann", 30));
+}
+
+#[test]
+fn test_record_total_order() {
+    use std::cmp::Ordering;
+    assert_eq!(TotalOrder::cmp(&person("ann", 30), &person("bob", 25)), Ordering::Less);
+    assert_eq!(TotalOrder::cmp(&person("ann", 30), &person("ann", 25)), Ordering::Greater);
+    assert_eq!(TotalOrder::cmp(&person("ann", 30), &person("ann", 30)), Ordering::Equal);
+}
+
+#[test]
+fn test_record_as_ordered_table_key() {
+    let mut t = OrderedTableStEph::<Person, u32>::empty();
+    t.insert(person("cat", 41), 3);
+    t.insert(person("ann", 30), 1);
+    t.insert(person("bob", 25), 2);
+    assert_eq!(t.size(), 3);
+    assert_eq!(t.find(&person("bob", 25)), Some(&2));
+    assert_eq!(t.first(), Some(&person("ann", 30)));
+    assert_eq!(t.last(), Some(&person("cat", 41)));
+}
+
+#[test]
+fn test_record_display() {
+    assert_eq!(format!("{}", person("ann", 30)), "Person { name: ann, age: 30 }");
+}
+
+// Round-trips a value through another thread using only the MtT interface.
+fn through_thread<T: MtT + 'static>(x: &T) -> T {
+    let copy = x.clone_mt();
+    std::thread::spawn(move || copy).join().unwrap()
+}
+
+#[test]
+fn test_mtt_value_types() {
+    assert_eq!(through_thread(&vec![1, 2, 3]), vec![1, 2, 3]);
+    assert_eq!(Vec::<u32>::new_mt(vec![4, 5]), vec![4, 5]);
+    assert_eq!(through_thread(&Some("x".to_string())), Some("x".to_string()));
+    assert_eq!(Option::<i32>::new_mt(Some(7)), Some(7));
+    assert_eq!(Option::<i32>::new_mt(None), None);
+    assert_eq!(through_thread(&Triple(1, 'a', true)), Triple(1, 'a', true));
+    assert_eq!(Triple::new_mt(Triple(1, 2, 3)), Triple(1, 2, 3));
+}
+
+#[test]
+fn test_mtt_locks_copy_contents() {
+    let rw = RwLock::new_mt(5u64);
+    let copy = through_thread(&rw);
+    *rw.write().unwrap() = 6;
+    assert_eq!(*copy.read().unwrap(), 5);
+    assert_eq!(*rw.read().unwrap(), 6);
+
+    let m = Mutex::new_mt("a".to_string());
+    let copy = m.clone_mt();
+    m.lock().unwrap().push('b');
+    assert_eq!(*copy.lock().unwrap(), "a");
+}
+
+#[test]
+fn test_mtt_locks_survive_poisoning() {
+    let m = Arc::new(Mutex::new_mt(3u32));
+    let held = Arc::clone(&m);
+    let _ = std::thread::spawn(move || {
+        let _guard = held.lock().unwrap();
+        panic!("poison the lock");
+    })
+    .join();
+    assert!(m.is_poisoned());
+    assert_eq!(*m.clone_mt().lock().unwrap(), 3);
+
+    let rw = Arc::new(RwLock::new_mt(4u32));
+    let held = Arc::clone(&rw);
+    let _ = std::thread::spawn(move || {
+        let _guard = held.write().unwrap();
+        panic!("poison the lock");
+    })
+    .join();
+    assert!(rw.is_poisoned());
+    assert_eq!(*rw.clone_mt().read().unwrap(), 4);
+}
+
+#[test]
+fn test_mtt_arc_shares() {
+    let a = Arc::new_mt(Pair(1, 2));
+    let b = through_thread(&a);
+    assert!(Arc::ptr_eq(&a, &b));
+    assert_eq!(*b, Pair(1, 2));
+}
+
+#[test]
+fn test_mtt_chap05_collections() {
+    let s: SetStEph<u32> = SetLit![1, 2, 3];
+    let s2 = through_thread(&s);
+    assert_eq!(s2.size(), 3);
+    assert!(s2.mem(&2));
+
+    let r: RelationStEph<u32, char> = RelationLit![(1, 'a'), (2, 'b')];
+    let r2 = through_thread(&r);
+    assert_eq!(r2.size(), 2);
+    assert!(r2.mem(&1, &'a'));
+
+    let m: MappingStEph<u32, char> = MappingLit![(1, 'a'), (2, 'b')];
+    let m2 = through_thread(&MappingStEph::new_mt(m));
+    assert_eq!(m2.size(), 2);
+    assert!(m2.mem(&Pair(2, 'b')));
+}
@@ -0,0 +1,74 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Tests for the NaN-aware totally ordered float keys.
+
+use std::cmp::Ordering;
+use verus_test::Chap03::InsertionSortStEph::InsertionSortStEph::*;
+use verus_test::Chap05::SetStEph::SetStEph::*;
+use verus_test::Chap43::OrderedTableStEph::OrderedTableStEph::*;
+use verus_test::SetLit;
+use verus_test::Types::Types::*;
+use verus_test::vstdplus::total_order::total_order::TotalOrder;
+
+fn f(x: f64) -> OrderedF64 { OrderedF64::new(x) }
+
+#[test]
+fn test_ordered_f64_order() {
+    assert_eq!(TotalOrder::cmp(&f(1.0), &f(2.0)), Ordering::Less);
+    assert_eq!(TotalOrder::cmp(&f(-1.0), &f(-2.0)), Ordering::Greater);
+    assert_eq!(TotalOrder::cmp(&f(f64::NEG_INFINITY), &f(-1e300)), Ordering::Less);
+    assert_eq!(TotalOrder::cmp(&f(f64::INFINITY), &f(f64::NAN)), Ordering::Less);
+    assert_eq!(TotalOrder::cmp(&f(f64::NAN), &f(-f64::NAN)), Ordering::Equal);
+    assert_eq!(TotalOrder::cmp(&f(-0.0), &f(0.0)), Ordering::Equal);
+    assert!(f(0.5) < f(0.75));
+    assert_eq!(f(-0.0), f(0.0));
+    assert_eq!(f(f64::NAN), f(f64::NAN));
+}
+
+#[test]
+fn test_ordered_f32_order() {
+    let g = OrderedF32::new;
+    assert_eq!(TotalOrder::cmp(&g(1.5), &g(-1.5)), Ordering::Greater);
+    assert_eq!(TotalOrder::cmp(&g(-3.0), &g(-2.0)), Ordering::Less);
+    assert_eq!(TotalOrder::cmp(&g(f32::NAN), &g(f32::MAX)), Ordering::Greater);
+    assert_eq!(g(-0.0), g(0.0));
+    assert_eq!(OrderedF32::from(2.5).get(), 2.5);
+}
+
+#[test]
+fn test_ordered_float_canonicalizes() {
+    assert_eq!(f(-0.0).get().to_bits(), 0.0f64.to_bits());
+    assert!(f(-f64::NAN).get().is_nan());
+    assert_eq!(format!("{}", f(1.25)), "1.25");
+    assert_eq!(format!("{:?}", f(1.25)), "OrderedF64(1.25)");
+}
+
+#[test]
+fn test_ordered_float_in_set() {
+    let s = SetLit![f(1.0), f(-0.0), f(0.0), f(f64::NAN), f(f64::NAN)];
+    assert_eq!(s.size(), 3);
+    assert!(s.mem(&f(0.0)));
+    assert!(s.mem(&f(-f64::NAN)));
+    assert!(!s.mem(&f(2.0)));
+}
+
+#[test]
+fn test_ordered_float_as_table_key() {
+    let mut t = OrderedTableStEph::<OrderedF64, char>::empty();
+    t.insert(f(3.5), 'c');
+    t.insert(f(f64::NAN), 'n');
+    t.insert(f(-1.0), 'a');
+    t.insert(f(0.0), 'b');
+    assert_eq!(t.size(), 4);
+    assert_eq!(t.first(), Some(&f(-1.0)));
+    assert_eq!(t.last(), Some(&f(f64::NAN)));
+    assert_eq!(t.find(&f(-0.0)), Some(&'b'));
+}
+
+#[test]
+fn test_ordered_float_sorts() {
+    let mut data = vec![f(2.0), f(f64::NAN), f(-5.0), f(0.0), f(f64::NEG_INFINITY), f(1e-9)];
+    OrderedF64::insSort(&mut data);
+    let got: Vec<f64> = data.iter().map(|x| x.get()).collect();
+    assert_eq!(got[..5], [f64::NEG_INFINITY, -5.0, 0.0, 1e-9, 2.0]);
+    assert!(got[5].is_nan());
+}
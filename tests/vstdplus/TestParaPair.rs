@@ -0,0 +1,70 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Tests for the bounded fork-join ParaPair!.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use verus_test::ParaPair;
+use verus_test::Types::Types::*;
+use verus_test::vstdplus::para_pair::para_pair::*;
+
+fn fib(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    let Pair(a, b) = ParaPair!(move || fib(n - 1), move || fib(n - 2));
+    a + b
+}
+
+fn sum(xs: &[u64]) -> u64 {
+    if xs.len() <= 4 {
+        return xs.iter().sum();
+    }
+    let (l, r) = xs.split_at(xs.len() / 2);
+    let Pair(a, b) = ParaPair!(|| sum(l), || sum(r));
+    a + b
+}
+
+#[test]
+fn test_para_pair_returns_both_results() {
+    let Pair(a, b) = ParaPair!(|| 6 * 7, || "right".to_string());
+    assert_eq!(a, 42);
+    assert_eq!(b, "right");
+}
+
+#[test]
+fn test_para_pair_borrows_from_caller() {
+    let data = vec![1u64, 2, 3, 4, 5, 6, 7, 8];
+    let (l, r) = data.split_at(4);
+    let Pair(a, b) = ParaPair!(|| l.iter().sum::<u64>(), || r.iter().sum::<u64>());
+    assert_eq!(a + b, 36);
+}
+
+#[test]
+fn test_para_pair_deep_recursion_stays_bounded() {
+    assert_eq!(fib(20), 6765);
+    let xs: Vec<u64> = (1..=10_000).collect();
+    assert_eq!(sum(&xs), 50_005_000);
+    assert!(helpers_in_use() <= thread_budget());
+}
+
+#[test]
+fn test_para_pair_never_exceeds_budget() {
+    static PEAK: AtomicUsize = AtomicUsize::new(0);
+    fn spread(depth: u32) -> u32 {
+        PEAK.fetch_max(helpers_in_use(), Ordering::AcqRel);
+        if depth == 0 {
+            return 1;
+        }
+        let Pair(a, b) = ParaPair!(move || spread(depth - 1), move || spread(depth - 1));
+        a + b
+    }
+    assert_eq!(spread(12), 4096);
+    assert!(PEAK.load(Ordering::Acquire) <= thread_budget());
+}
+
+#[test]
+fn test_para_pair_propagates_panic() {
+    let result = std::panic::catch_unwind(|| ParaPair!(|| -> u32 { panic!("left failed") }, || 1u32));
+    assert!(result.is_err());
+    let Pair(a, b) = ParaPair!(|| 1, || 2);
+    assert_eq!((a, b), (1, 2));
+}
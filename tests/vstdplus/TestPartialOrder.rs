@@ -0,0 +1,51 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Tests for the genuinely partial PartialOrder instances.
+
+use std::cmp::Ordering;
+use verus_test::Chap05::SetStEph::SetStEph::*;
+use verus_test::SetLit;
+use verus_test::Types::Types::*;
+use verus_test::vstdplus::partial_order::partial_order::*;
+
+#[test]
+fn test_pair_product_order() {
+    assert_eq!(Pair(1u32, 2u32).compare(&Pair(1, 2)), Some(Ordering::Equal));
+    assert_eq!(Pair(1u32, 2u32).compare(&Pair(3, 4)), Some(Ordering::Less));
+    assert_eq!(Pair(1u32, 2u32).compare(&Pair(1, 4)), Some(Ordering::Less));
+    assert_eq!(Pair(3u32, 4u32).compare(&Pair(3, 2)), Some(Ordering::Greater));
+    assert_eq!(Pair(1u32, 4u32).compare(&Pair(3, 2)), None);
+    assert_eq!(Pair(3u32, 2u32).compare(&Pair(1, 4)), None);
+}
+
+#[test]
+fn test_set_subset_order() {
+    let a: SetStEph<u32> = SetLit![1, 2];
+    let b: SetStEph<u32> = SetLit![1, 2, 3];
+    let c: SetStEph<u32> = SetLit![3, 4];
+    let e: SetStEph<u32> = SetLit![];
+    assert_eq!(a.compare(&a.clone()), Some(Ordering::Equal));
+    assert_eq!(a.compare(&b), Some(Ordering::Less));
+    assert_eq!(b.compare(&a), Some(Ordering::Greater));
+    assert_eq!(a.compare(&c), None);
+    assert_eq!(e.compare(&c), Some(Ordering::Less));
+}
+
+#[test]
+fn test_divides_order() {
+    assert_eq!(Divides(6u32).compare(&Divides(6)), Some(Ordering::Equal));
+    assert_eq!(Divides(2u32).compare(&Divides(6)), Some(Ordering::Less));
+    assert_eq!(Divides(12u32).compare(&Divides(4)), Some(Ordering::Greater));
+    assert_eq!(Divides(2u32).compare(&Divides(3)), None);
+    assert_eq!(Divides(1u8).compare(&Divides(255)), Some(Ordering::Less));
+    assert_eq!(Divides(0u64).compare(&Divides(0)), Some(Ordering::Equal));
+    assert_eq!(Divides(5u64).compare(&Divides(0)), Some(Ordering::Less));
+    assert_eq!(Divides(0usize).compare(&Divides(5)), Some(Ordering::Greater));
+    assert_eq!(Divides(10u128).compare(&Divides(15)), None);
+    assert_eq!(Divides(7u16).compare(&Divides(49)), Some(Ordering::Less));
+}
+
+#[test]
+fn test_integers_still_total() {
+    assert_eq!(3u32.compare(&5), Some(Ordering::Less));
+    assert_eq!(5i64.compare(&-5), Some(Ordering::Greater));
+}
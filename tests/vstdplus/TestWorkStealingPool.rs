@@ -0,0 +1,140 @@
+//! Copyright (C) 2025 Acar, Blelloch and Milnes from 'Algorithms Parallel and Sequential'.
+//! Stress tests for the work-stealing pool: nested joins, fixed-size deadlock freedom, par_for and par_reduce.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use verus_test::vstdplus::work_stealing_pool::work_stealing_pool::*;
+
+fn fib(pool: &Pool, n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    let (a, b) = pool.join(|| fib(pool, n - 1), || fib(pool, n - 2));
+    a + b
+}
+
+#[test]
+fn test_join_returns_both_results() {
+    let pool = Pool::new(2);
+    let (a, b) = pool.join(|| 6 * 7, || "right".to_string());
+    assert_eq!(a, 42);
+    assert_eq!(b, "right");
+}
+
+#[test]
+fn test_join_borrows_from_caller() {
+    let pool = Pool::new(2);
+    let data: Vec<u64> = (1..=100).collect();
+    let (l, r) = data.split_at(50);
+    let (a, b) = pool.join(|| l.iter().sum::<u64>(), || r.iter().sum::<u64>());
+    assert_eq!(a + b, 5050);
+}
+
+#[test]
+fn test_nested_joins_on_one_worker_do_not_deadlock() {
+    let pool = Pool::new(1);
+    assert_eq!(fib(&pool, 20), 6765);
+}
+
+#[test]
+fn test_nested_joins_on_fixed_size_pools() {
+    for workers in [2, 3, 4, 8] {
+        let pool = Pool::new(workers);
+        assert_eq!(pool.num_workers(), workers);
+        assert_eq!(fib(&pool, 22), 17711);
+    }
+}
+
+#[test]
+fn test_many_external_threads_share_a_small_pool() {
+    let pool = Pool::new(2);
+    std::thread::scope(|s| {
+        for t in 0..8u64 {
+            let pool = &pool;
+            s.spawn(move || {
+                for _ in 0..20 {
+                    assert_eq!(fib(pool, 12 + t % 3), [144, 233, 377][(t % 3) as usize]);
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn test_par_for_visits_every_index_once() {
+    let pool = Pool::new(4);
+    let counts: Vec<AtomicUsize> = (0..10_000).map(|_| AtomicUsize::new(0)).collect();
+    pool.par_for(0..counts.len(), |i| {
+        counts[i].fetch_add(1, Ordering::Relaxed);
+    });
+    assert!(counts.iter().all(|c| c.load(Ordering::Relaxed) == 1));
+    pool.par_for(5..5, |_| panic!("empty range runs nothing"));
+}
+
+#[test]
+fn test_par_reduce_sums_and_maxes() {
+    let pool = Pool::new(4);
+    let sum = pool.par_reduce(0..1_000_000, 0u64, |i| i as u64, |a, b| a + b);
+    assert_eq!(sum, 499_999_500_000);
+    let max = pool.par_reduce(0..1000, 0usize, |i| (i * 7919) % 1000, |a, b| *a.max(b));
+    assert_eq!(max, 999);
+    assert_eq!(pool.par_reduce(0..0, 17u64, |i| i as u64, |a, b| a + b), 17);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn test_reversed_ranges_are_empty() {
+    let pool = Pool::new(4);
+    pool.par_for(5..3, |_| panic!("reversed range runs nothing"));
+    assert_eq!(pool.par_reduce(5..3, 17u64, |i| i as u64, |a, b| a + b), 17);
+    par_for(5..3, |_| panic!("reversed range runs nothing"));
+    assert_eq!(par_reduce(5..3, 17u64, |i| i as u64, |a, b| a + b), 17);
+}
+
+#[test]
+fn test_par_reduce_nests_par_for() {
+    let pool = Pool::new(3);
+    let hits = AtomicUsize::new(0);
+    let total = pool.par_reduce(
+        0..64,
+        0usize,
+        |i| {
+            pool.par_for(0..i, |_| {
+                hits.fetch_add(1, Ordering::Relaxed);
+            });
+            i
+        },
+        |a, b| a + b,
+    );
+    assert_eq!(total, 64 * 63 / 2);
+    assert_eq!(hits.load(Ordering::Relaxed), 64 * 63 / 2);
+}
+
+#[test]
+fn test_join_propagates_panic_and_pool_survives() {
+    let pool = Pool::new(2);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        pool.join(|| 1u32, || -> u32 { panic!("right failed") })
+    }));
+    assert!(result.is_err());
+    assert_eq!(fib(&pool, 15), 610);
+}
+
+#[test]
+fn test_global_pool_functions() {
+    let (a, b) = join(|| 1, || 2);
+    assert_eq!((a, b), (1, 2));
+    let seen = AtomicUsize::new(0);
+    par_for(0..1000, |_| {
+        seen.fetch_add(1, Ordering::Relaxed);
+    });
+    assert_eq!(seen.load(Ordering::Relaxed), 1000);
+    assert_eq!(par_reduce(1..101, 0u64, |i| i as u64, |a, b| a + b), 5050);
+}
+
+#[test]
+fn test_pools_drop_cleanly() {
+    for _ in 0..50 {
+        let pool = Pool::new(4);
+        assert_eq!(fib(&pool, 10), 55);
+    }
+}